@@ -1,5 +1,7 @@
 //! 基于Websocket的远程NAVM虚拟机测试
 //! * ⚠️【2024-09-12 17:06:00】此处代码仅作研究用，不推荐用于实际使用
+// * 📌[`ws::Error`]本身体积较大，且由[`ws`]的回调签名决定，无法在此处缩减
+#![allow(clippy::result_large_err)]
 
 use std::{
     cell::RefCell,
@@ -3,13 +3,23 @@
 //! * 🚩【2024-04-04 03:03:58】现在移出所有与「启动配置」相关的逻辑到[`super::vm_config`]
 
 use crate::{
-    cli::{load_config_extern, read_config_extern, LaunchConfig},
+    cli::{
+        config_provenance, load_config_layers_extern, merge_config_layers, messages,
+        read_config_layers, BenchFormat, ConfigCommand, ConfigLayer, ConfigOverride, ConfigSource,
+        LaunchConfig, RuntimeConfig, SearchIndex, SourcedLayer, TestReportFormat,
+    },
     println_cli,
+    support::{
+        cin_search::path_walker::PathWalkerOptions, i18n::Locale, io::output_print::OutputFormat,
+    },
 };
-use clap::Parser;
+use anyhow::{anyhow, Result};
+use clap::{Args, Parser, Subcommand};
+use glob::Pattern;
 use std::{
     env::{current_dir, current_exe},
     path::PathBuf,
+    time::Duration,
 };
 
 /// 基于[`clap`]的命令行参数数据
@@ -28,8 +38,8 @@ pub struct CliArgs {
     // * 🚩【2024-04-01 13:07:18】具有最高加载优先级
     //   * 📌剩余的是和exe同目录的`json`文件
     // ! 📝此处的文档字符串会被用作`-h`的说明
-    /// Configuration file path in JSON (multiple supported by call it multiple times)
-    #[arg(short, long, value_name = "FILE")]
+    /// Configuration file path in JSON/HJSON/TOML/YAML (multiple supported by call it multiple times)
+    #[arg(short, long, value_name = "FILE", global = true)]
     pub config: Vec<PathBuf>,
 
     // 禁用默认配置
@@ -40,7 +50,7 @@ pub struct CliArgs {
     //   * 有　 ⇒ `true`
     // * 📄参考[`DEFAULT_CONFIG_KEYWORD`]
     /// Disable the default configuration file in the same directory as exe
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     pub disable_default: bool,
 
     // 快速启动
@@ -48,11 +58,223 @@ pub struct CliArgs {
     // * 🎯在明确「所加载的配置」时，快速启动虚拟机并进入终端
     // * 📄直接跳过「配置加载完毕！程序将在1s后启动。。。」
     /// Launch the virtual machine immediately without delay after loading the configuration
-    #[arg(long)]
+    #[arg(long, global = true)]
     pub quick_start: bool,
+
+    // 监视配置文件
+    // * 🎯在长时间运行中调整配置，而无需重启CLI
+    // * 🚩运行时配置项⇒原地替换；启动命令、转译器⇒重启虚拟机
+    /// Watch the loaded configuration files and apply changes while running
+    #[arg(short, long, global = true)]
+    pub watch: bool,
+
+    // 选中的命名档案
+    // * 🎯从配置的`profiles`中选出一套配置，覆盖于顶层配置之上
+    // * 🚩在所有配置文件合并完成后应用
+    /// Select a named profile from the `profiles` of loaded configurations
+    #[arg(short, long, value_name = "NAME", global = true)]
+    pub profile: Option<String>,
+
+    // 配置覆盖
+    // * 🎯在命令行中直接修改任意配置项，无需另写配置文件
+    // * ✨可重复使用，按使用顺序应用
+    // * 🚩具有最高优先级：在配置合并、档案选中之后应用
+    /// Override a config field, e.g. `websocket.port=8765` (multiple supported by call it multiple times)
+    #[arg(long = "set", value_name = "KEY=VALUE", global = true)]
+    pub overrides: Vec<ConfigOverride>,
+
+    // 详细日志
+    // * 🎯启动时展示「每个配置项来自何处」
+    /// Print where each config field comes from on startup
+    #[arg(short, long, global = true)]
+    pub verbose: bool,
+
+    // 界面语言
+    // * 🎯让提示、日志、报错信息以用户熟悉的语言呈现
+    // * 🚩缺省⇒依次使用配置中的`lang`、环境变量`LANG`，最后回退到中文
+    /// Language of CLI messages: `zh` or `en` (defaults to the config's `lang`, then `LANG`)
+    #[arg(long, value_name = "LANG", global = true)]
+    pub lang: Option<Locale>,
+
+    // 输出格式
+    // * 🎯便于其它程序（如`jq`）读取CLI输出，而无需解析彩色文本
+    // * 🚩`jsonl`⇒标准输出每行一条NAVM输出（JSON），CLI日志转至标准错误
+    /// Output format: `text` (colored) or `jsonl` (one JSON per NAVM output on stdout, logs as JSON on stderr)
+    #[arg(long, value_name = "FORMAT", default_value_t, global = true)]
+    pub output_format: OutputFormat,
+
+    // 子命令
+    // * 🚩缺省⇒按配置启动CIN并进入交互（同`run`）
+    #[command(subcommand)]
+    pub command: Option<CliCommand>,
     // ! 🚩【2024-04-02 11:36:18】目前除了「配置加载」外，莫将任何「NAVM实现特定，可以内置到『虚拟机配置』的字段放这儿」
 }
 
+/// 命令行子命令
+/// * 🎯区分「交互式启动」与各类非交互式工作流
+///   * 📌非交互式的子命令不会询问用户、清屏或等待
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    /// Launch the CIN from configuration and interact with it in the terminal (default)
+    Run {
+        #[command(flatten)]
+        walk: WalkArgs,
+    },
+    /// Run NAL files against the configured CIN without interaction, and exit non-zero if any fails
+    Test {
+        /// NAL files or directories to run, in order (defaults to the config's `preludeNAL`)
+        #[arg(value_name = "PATH")]
+        paths: Vec<PathBuf>,
+        /// Write a report with every expectation as a testcase into a file (JUnit XML, or TAP for `.tap` files)
+        #[arg(long, value_name = "FILE")]
+        report: Option<PathBuf>,
+        /// Format of the report: `junit`, `tap` or `json` (defaults by the report's extension)
+        #[arg(long, value_name = "FORMAT", requires = "report")]
+        report_format: Option<TestReportFormat>,
+    },
+    /// Run NAL files against several CINs, every combination in a child process, and print a comparison grid
+    Matrix {
+        /// CIN launch configuration file to compare (multiple supported by call it multiple times)
+        #[arg(long = "cin", value_name = "FILE", required = true)]
+        cins: Vec<PathBuf>,
+        /// NAL files or directories to run against every CIN
+        #[arg(value_name = "PATH", required = true)]
+        paths: Vec<PathBuf>,
+        /// Number of child processes running at the same time
+        #[arg(short, long, value_name = "N", default_value_t = 1)]
+        jobs: usize,
+    },
+    /// Run NAL files repeatedly and report time and cycles to satisfy each expectation (min/median/max)
+    Bench {
+        /// NAL files or directories to benchmark (defaults to the config's `preludeNAL`)
+        #[arg(value_name = "PATH")]
+        paths: Vec<PathBuf>,
+        /// Number of runs for each NAL file, each with a fresh CIN
+        #[arg(short = 'n', long, value_name = "N", default_value_t = 5)]
+        runs: usize,
+        /// Write the statistics into a file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+        /// Format of the statistics: `csv` or `json` (defaults by the output's extension, otherwise CSV)
+        #[arg(long, value_name = "FORMAT")]
+        format: Option<BenchFormat>,
+    },
+    /// Launch the configured CIN as a Websocket server, without terminal input
+    Serve,
+    /// Search launch configuration files and list them
+    Search {
+        /// Directory to start searching from (defaults to the working directory)
+        #[arg(value_name = "DIR")]
+        dir: Option<PathBuf>,
+        #[command(flatten)]
+        walk: WalkArgs,
+    },
+    /// Search installed CIN executables, list them by match score and generate a launch config for one
+    SearchCin {
+        /// Directory to start searching from (defaults to the working directory; the executable's directory is always searched)
+        #[arg(value_name = "DIR")]
+        dir: Option<PathBuf>,
+        /// Index of the CIN to generate a launch config for (asks in a terminal if omitted)
+        #[arg(short, long, value_name = "INDEX")]
+        select: Option<usize>,
+        /// Write the generated HJSON launch config into a file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+        /// Launch the selected CIN right away and interact with it, instead of generating a config
+        #[arg(short, long, conflicts_with = "output")]
+        launch: bool,
+        #[command(flatten)]
+        walk: WalkArgs,
+    },
+    /// Inspect launch configurations: show the merged config, print the JSON Schema or validate files
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+    /// List the names and aliases of all built-in translators
+    Translators,
+}
+
+/// 搜索时的路径遍历参数
+/// * 🎯限制`search`、`search-cin`的遍历范围与开销，并控制搜索索引缓存
+///   * 📌`run`在配置不全、自动搜索配置文件时亦使用
+/// * 🔗转换为[`PathWalkerOptions`]
+#[derive(Args, Debug, Clone, Default, PartialEq, Eq)]
+#[command(next_help_heading = "Search Options")]
+pub struct WalkArgs {
+    /// Maximum depth to descend below the start directory and each scanned ancestor
+    #[arg(long, value_name = "N")]
+    pub max_depth: Option<usize>,
+    /// Maximum number of ancestor directories to scan upwards (0 scans only the start directory)
+    #[arg(long, value_name = "N")]
+    pub max_ancestors: Option<usize>,
+    /// Only list paths matching the glob, against the full path or the file name (multiple supported)
+    #[arg(long, value_name = "GLOB")]
+    pub include: Vec<Pattern>,
+    /// Neither list nor descend into paths matching the glob (multiple supported)
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<Pattern>,
+    /// Also search paths ignored by `.gitignore` files
+    #[arg(long)]
+    pub no_gitignore: bool,
+    /// Descend into directories behind symbolic links (each directory is still visited once)
+    #[arg(long)]
+    pub follow_symlinks: bool,
+    /// Stop searching after checking this many paths
+    #[arg(long, value_name = "N")]
+    pub max_entries: Option<usize>,
+    /// Stop searching after this many seconds (fractions allowed)
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    pub time_limit: Option<Duration>,
+    /// Neither read nor write the search index cache in the user cache directory
+    #[arg(long)]
+    pub no_cache: bool,
+}
+
+/// 解析秒数（可含小数）
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    let seconds = s.parse::<f64>().map_err(|e| e.to_string())?;
+    Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
+}
+
+impl WalkArgs {
+    /// 打开搜索索引
+    /// * 🚩`--no-cache`⇒不读写缓存文件
+    pub fn search_index(&self) -> SearchIndex {
+        match self.no_cache {
+            true => SearchIndex::in_memory(),
+            false => SearchIndex::open_default(),
+        }
+    }
+}
+
+impl From<&WalkArgs> for PathWalkerOptions {
+    fn from(args: &WalkArgs) -> Self {
+        Self {
+            max_depth: args.max_depth,
+            max_ancestors: args.max_ancestors,
+            include: args.include.clone(),
+            exclude: args.exclude.clone(),
+            respect_gitignore: !args.no_gitignore,
+            follow_symlinks: args.follow_symlinks,
+            max_entries: args.max_entries,
+            time_limit: args.time_limit,
+        }
+    }
+}
+
+impl CliArgs {
+    /// 确定界面语言
+    /// * 🚩优先级：命令行参数`--lang` > 配置中的`lang` > 环境变量`LANG` > 中文
+    /// * 📌配置尚未加载时传入[`None`]
+    pub fn locale(&self, config: Option<&LaunchConfig>) -> Locale {
+        self.lang
+            .or_else(|| config.and_then(|config| config.lang))
+            .or_else(Locale::from_env)
+            .unwrap_or_default()
+    }
+}
+
 /// 默认的「启动配置」关键词
 /// * 🎯在「自动追加扩展名」的机制下，可以进行自动补全
 /// * 🚩【2024-04-04 05:28:45】目前仍然难以直接在[`PathBuf`]中直接追加字符串
@@ -64,16 +286,22 @@ pub const DEFAULT_CONFIG_KEYWORD: &str = "BabelNAR-launch";
 ///   * exe当前目录下 | 工作目录下
 ///   * `BabelNAR.launch.(h)json`
 pub fn try_load_default_config() -> Option<LaunchConfig> {
+    try_load_default_config_layers().map(merge_config_layers)
+}
+
+/// 获取「默认启动配置」文件的所有配置层
+/// * 🎯在[`try_load_default_config`]的基础上，保留各配置层的来源
+pub fn try_load_default_config_layers() -> Option<Vec<ConfigLayer>> {
     // 检查一个目录
     #[inline(always)]
-    fn in_one_root(root: PathBuf) -> Option<LaunchConfig> {
+    fn in_one_root(root: PathBuf) -> Option<Vec<ConfigLayer>> {
         // 计算路径：同目录下
         let path = match root.is_dir() {
             true => root.join(DEFAULT_CONFIG_KEYWORD),
             false => root.with_file_name(DEFAULT_CONFIG_KEYWORD),
         };
         // 尝试读取，静默失败
-        read_config_extern(&path).ok()
+        read_config_layers(&path).ok()
     }
     // 寻找第一个可用的配置文件
     [current_dir(), current_exe()]
@@ -89,35 +317,97 @@ pub fn try_load_default_config() -> Option<LaunchConfig> {
 
 /// 加载配置
 /// * 🚩按照一定优先级顺序进行覆盖（从先到后）
+///   * 命令行参数中的配置覆盖（`--set`）
 ///   * 命令行参数中指定的配置文件
 ///   * 默认配置文件路径 | 可以在`disable_default = true`的情况下传入任意字串作占位符
-pub fn load_config(args: &CliArgs) -> LaunchConfig {
+/// * ⚠️配置覆盖无法应用（未知键、类型不符）⇒报错
+pub fn load_config(args: &CliArgs) -> Result<LaunchConfig> {
+    Ok(load_config_sourced(args)?.0)
+}
+
+/// 加载配置，并记录各配置层的来源
+/// * 🚩逻辑同[`load_config`]
+/// * ⚙️返回(合并后的配置, 按优先级从高到低排列的配置层)
+///   * 🎯用于追踪「每个配置项来自何处」，参见[`config_provenance`]
+pub fn load_config_sourced(args: &CliArgs) -> Result<(LaunchConfig, Vec<SourcedLayer>)> {
     // 构建返回值 | 全`None`
     let mut result = LaunchConfig::new();
+    // 来自配置文件的配置层
+    let mut file_layers = vec![];
     // 尝试从命令行参数中读取再合并配置 | 仅提取出其中`Some`的项
-    args.config
-        // 尝试加载配置文件，对错误采取「警告并抛掉」的策略
+    // * 🚩对错误采取「警告并抛掉」的策略
+    for layers in args
+        .config
         .iter()
-        .map(PathBuf::as_ref)
-        .filter_map(load_config_extern)
+        .filter_map(|path| load_config_layers_extern(path))
+    {
         // 逐个从「命令行参数指定的配置文件」中合并
-        .for_each(|config| result.merge_from(&config));
+        result.merge_from(&merge_config_layers(layers.iter().cloned()));
+        file_layers.extend(SourcedLayer::from_file_layers(&layers, false));
+    }
     // 若未禁用，尝试读取再合并默认启动配置
+    // * 🚩读取失败⇒警告&无动作 | 避免多次空合并
     if !args.disable_default {
-        // * 🚩读取失败⇒警告&无动作 | 避免多次空合并
-        try_load_default_config().inspect(|config_extern| result.merge_from(config_extern));
+        if let Some(layers) = try_load_default_config_layers() {
+            result.merge_from(&merge_config_layers(layers.iter().cloned()));
+            file_layers.extend(SourcedLayer::from_file_layers(&layers, true));
+        }
+    }
+    // 若指定，选中命名档案 | 不存在⇒报错并列出可用的档案，不以顶层配置代替
+    let mut profile_layer = None;
+    if let Some(name) = &args.profile {
+        let config = result.with_profile(name)?;
+        println_cli!([Info] "{}", messages::profile_selected(name));
+        profile_layer = result
+            .profiles
+            .as_ref()
+            .and_then(|profiles| profiles.get(name))
+            .map(|profile| SourcedLayer::new(ConfigSource::Profile(name.clone()), profile));
+        result = config;
+    }
+    // 应用命令行中的配置覆盖 | 失败（未知键、类型不符）⇒报错，不以错误的配置启动
+    let mut override_layers = vec![];
+    for config_override in &args.overrides {
+        result
+            .apply_override(config_override)
+            .map_err(|e| anyhow!(messages::override_failed(config_override, &e)))?;
+        override_layers.push(SourcedLayer::from_override(config_override));
     }
     // 展示加载的配置 | 以便调试（以防其它地方意外插入别的配置）
     if result.is_empty() {
-        println_cli!([Log] "未加载任何外部配置");
+        println_cli!([Log] "{}", messages::no_config_loaded());
     } else {
         match serde_json::to_string(&result) {
-            Ok(json) => println_cli!([Log] "外部配置已加载：{json}",),
-            Err(e) => println_cli!([Warn] "展示加载的配置时出现预期之外的错误: {e}"),
+            Ok(json) => println_cli!([Log] "{}", messages::config_loaded(&json)),
+            Err(e) => println_cli!([Warn] "{}", messages::config_display_failed(&e)),
         }
     }
+    // 汇总配置层 | 按优先级从高到低：后应用的覆盖 > 先应用的覆盖 > 档案 > 配置文件
+    let layers = override_layers
+        .into_iter()
+        .rev()
+        .chain(profile_layer)
+        .chain(file_layers)
+        .collect();
     // 返回
-    result
+    Ok((result, layers))
+}
+
+/// 展示配置中每个配置项的来源
+/// * 🎯用于`config show`子命令与启动时的详细日志
+/// * 🚩尝试转换为运行时配置，以展示内置默认值；转换失败⇒仅展示启动配置
+pub fn show_config_provenance(
+    config: &LaunchConfig,
+    layers: &[SourcedLayer],
+    mut print_line: impl FnMut(String),
+) {
+    let runtime = RuntimeConfig::try_from(config.clone());
+    if let Err(e) = &runtime {
+        print_line(messages::provenance_runtime_unavailable(e));
+    }
+    for field in config_provenance(config, layers, runtime.as_ref().ok()) {
+        print_line(field.to_string());
+    }
 }
 
 /// 单元测试
@@ -125,7 +415,7 @@ pub fn load_config(args: &CliArgs) -> LaunchConfig {
 mod tests {
     use super::*;
     use crate::tests::*;
-    use nar_dev_utils::fail_tests;
+    use nar_dev_utils::{asserts, fail_tests};
 
     /// 测试/参数解析
     mod arg_parse {
@@ -195,6 +485,24 @@ mod tests {
             };
         }
 
+        /// 测试/界面语言
+        /// * 🎯`--lang`优先于配置中的`lang`
+        #[test]
+        fn test_locale() {
+            let parse = |args: &[&str]| CliArgs::parse_from([&["test.exe"], args].concat());
+            let config_en = LaunchConfig {
+                lang: Some(Locale::En),
+                ..Default::default()
+            };
+            asserts! {
+                parse(&["--lang", "en"]).lang => Some(Locale::En)
+                parse(&["--lang", "zh_CN.UTF-8"]).lang => Some(Locale::Zh)
+                parse(&["--lang", "zh"]).locale(Some(&config_en)) => Locale::Zh
+                parse(&[]).locale(Some(&config_en)) => Locale::En
+            }
+            assert!(CliArgs::try_parse_from(["test.exe", "--lang", "fr"]).is_err());
+        }
+
         // 失败解析
         fail_tests! {
             #[ignore = "【2024-06-12 23:47:41】会导致集成测试无法正常运行"]
@@ -223,7 +531,7 @@ mod tests {
         fn load(args: &[&str]) -> LaunchConfig {
             // 读取配置 | 自动填充第一个命令行参数作为「当前程序路径」
             let args = CliArgs::parse_from([&["test.exe"], args].concat());
-            let config = load_config(&args);
+            let config = load_config(&args).expect("配置加载失败");
             dbg!(config)
         }
 
@@ -249,6 +557,12 @@ mod tests {
                 => .push("cli")
                 => .push("executables")
             );
+            // 多配置合并后的「命名档案」测试配置
+            // * 📌合并后不保留配置路径、描述等字段
+            let profiles_loaded = manipulate!(
+                LaunchConfig::new()
+                => .merge_from(&read_config_extern(PROFILES_TEST.as_ref()).unwrap())
+            );
             // 成功测试
             test! {
                     // 单个配置文件
@@ -266,6 +580,7 @@ mod tests {
                                 "nars.jar".into()
                             ]),
                             current_dir: Some(expected_current_dir.clone()),
+                            ..Default::default()
                         }),
                         ..Default::default()
                     };
@@ -295,6 +610,7 @@ mod tests {
                                 "nars.jar".into()
                             ]),
                             current_dir: Some(expected_current_dir.clone()),
+                            ..Default::default()
                         }),
                         websocket: Some(LaunchConfigWebsocket {
                             host: "localhost".into(),
@@ -322,6 +638,7 @@ mod tests {
                                 "nars.jar".into()
                             ]),
                             current_dir: Some(expected_current_dir.clone()),
+                            ..Default::default()
                         }),
                         websocket: Some(LaunchConfigWebsocket {
                             host: "localhost".into(),
@@ -331,8 +648,52 @@ mod tests {
                         auto_restart: Some(false),
                         strict_mode: Some(true),
                         ..Default::default()
+                    };
+                    // 选中命名档案：覆盖于顶层配置之上
+                    [
+                        "-d"
+                        "-c" PROFILES_TEST
+                        "--profile" "strict"
+                    ] => profiles_loaded.with_profile("strict").unwrap();
+                    // 配置覆盖：优先级最高
+                    [
+                        "-d"
+                        "-c" WEBSOCKET
+                        "--set" "websocket.port=8765"
+                        "--set" "strictMode=true"
+                    ] => LaunchConfig {
+                        websocket: Some(LaunchConfigWebsocket {
+                            host: "localhost".into(),
+                            port: 8765,
+                        }),
+                        strict_mode: Some(true),
+                        ..Default::default()
                     }
             }
         }
+
+        /// 测试/无法应用的配置覆盖
+        /// * 🎯未知键、类型不符⇒报错，而非忽略后照常启动
+        #[test]
+        fn test_override_failed() {
+            for set in [
+                "strctMode=false",
+                "strictMode=maybe",
+                "websocket.port=\"8765\"",
+            ] {
+                let args = CliArgs::parse_from(["test.exe", "-d", "-c", WEBSOCKET, "--set", set]);
+                assert!(load_config(&args).is_err(), "{set}");
+            }
+        }
+
+        /// 测试/不存在的命名档案
+        /// * 🎯报错并列出可用的档案，而非以顶层配置启动
+        #[test]
+        fn test_unknown_profile() {
+            let args =
+                CliArgs::parse_from(["test.exe", "-d", "-c", PROFILES_TEST, "-p", "nonexistent"]);
+            let e = load_config(&args).expect_err("不存在的档案应该报错");
+            assert!(e.to_string().contains("serve, strict"));
+        }
     }
 }
@@ -0,0 +1,177 @@
+//! CIN自动发现
+//! * 🎯搜索本地已安装的CIN，并生成可直接使用的启动配置
+//!   * 📌用户无需手写`command`与`translators`
+//! * 🚩路径遍历：[`PathWalkerV1`]；路径识别：各CIN的[`CinPathBuilder`](crate::support::cin_search::path_builder::CinPathBuilder)
+//! * 🚩生成的配置以HJSON格式输出
+//!   * 📌内容实为JSON（JSON是HJSON的子集），并附带`#hjson`头部
+
+use crate::{
+    cli::{messages, LaunchConfig, LaunchConfigCommand, LaunchConfigTranslators, SearchIndex},
+    eprintln_cli, println_cli,
+    support::{
+        cin_search::{
+            impls_path_builder::CinSearchResult,
+            name_match::is_name_match,
+            path_builder::CinLaunchCommand,
+            path_walker::{PathWalkerOptions, PathWalkerV1},
+        },
+        i18n::LocalizedText,
+    },
+};
+use anyhow::Result;
+use serde_json::Value;
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+/// 从多个起始目录搜索CIN
+/// * 🚩逐个起始目录遍历，合并（去重）遍历到的路径，再统一识别
+/// * 📌结果按匹配度从高到低排序
+/// * 🚩遍历范围与开销由`walk_options`限制
+///   * 📌开销预算对每个起始目录分别计算
+/// * 🚩路径经由搜索索引识别：未修改的路径无需重新识别
+///   * 📌搜索结束后写回索引；写回失败仅作警告
+pub fn discover_cins(
+    starts: &[PathBuf],
+    walk_options: &PathWalkerOptions,
+    index: &mut SearchIndex,
+) -> Result<Vec<CinSearchResult>> {
+    // 深入条件 | 与配置搜索一致
+    fn deep_criterion(path: &Path) -> bool {
+        path.file_name()
+            .is_some_and(|name| name.to_str().is_some_and(|s| is_name_match("nars", s)))
+    }
+
+    let mut visited = HashSet::new();
+    let mut paths = vec![];
+    for start in starts {
+        let mut walker = PathWalkerV1::with_options(start, deep_criterion, walk_options.clone())?;
+        for path in walker.by_ref().flatten() {
+            if visited.insert(path.clone()) {
+                paths.push(path);
+            }
+        }
+        if walker.is_exhausted() {
+            println_cli!([Warn] "{}", messages::search_budget_exhausted());
+        }
+    }
+    let results = index.cin_results(&paths);
+    if let Err(e) = index.save() {
+        eprintln_cli!([Warn] "{}", messages::search_cache_save_failed(&e));
+    }
+    Ok(results)
+}
+
+/// 从搜索结果生成启动配置
+/// * 🚩转译器取自搜索结果
+pub fn cin_launch_config(result: &CinSearchResult) -> LaunchConfig {
+    LaunchConfig {
+        description: Some(LocalizedText::Plain(format!(
+            "{} ({})",
+            result.name,
+            result.path.display()
+        ))),
+        ..command_launch_config(&result.command, result.translators)
+    }
+}
+
+/// 从「启动命令 + 转译器名」生成启动配置
+/// * 🎯生成的启动配置与[`CinLauncher`](crate::support::cin_search::path_builder::CinLauncher)共用
+pub fn command_launch_config(command: &CinLaunchCommand, translators: &str) -> LaunchConfig {
+    LaunchConfig {
+        translators: Some(LaunchConfigTranslators::Same(translators.into())),
+        command: Some(LaunchConfigCommand {
+            cmd: command.cmd.clone(),
+            cmd_args: Some(command.cmd_args.clone()),
+            current_dir: command.current_dir.clone(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// 将启动配置格式化为HJSON文本
+/// * 🚩省略所有空值（未设置的配置项）
+pub fn launch_config_to_hjson(config: &LaunchConfig) -> Result<String> {
+    let mut value = serde_json::to_value(config)?;
+    remove_nulls(&mut value);
+    Ok(format!(
+        "#hjson\n// {}\n{}\n",
+        messages::cin_config_generated(),
+        serde_json::to_string_pretty(&value)?
+    ))
+}
+
+/// 递归移除对象中的空值
+fn remove_nulls(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, v| !v.is_null());
+            map.values_mut().for_each(remove_nulls);
+        }
+        Value::Array(array) => array.iter_mut().for_each(remove_nulls),
+        _ => {}
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cli::read_config_extern, tests::fixture::FixtureDir};
+    use nar_dev_utils::asserts;
+
+    /// 测试/从目录树中发现CIN
+    /// * 🎯深入名称匹配`nars`的目录，并识别其中的CIN
+    #[test]
+    fn test_discover_cins() {
+        let root = FixtureDir::with_empty_files("discover", &["my-nars/NAR.exe", "opennars.jar"]);
+        let nars_dir = root.join("my-nars");
+        let results = discover_cins(
+            &[root.to_path_buf(), nars_dir],
+            &PathWalkerOptions::default(),
+            &mut SearchIndex::in_memory(),
+        )
+        .expect("搜索失败");
+        let find = |name| {
+            results
+                .iter()
+                .filter(|result| result.name == name && result.path.starts_with(&root))
+                .count()
+        };
+        asserts! {
+            // 两个起始目录遍历到的路径被去重
+            find("ONA") => 1
+            find("OpenNARS") => 1
+        }
+    }
+
+    /// 测试/生成的启动配置
+    /// * 🎯生成的HJSON可被重新读取，且不含未设置的配置项
+    #[test]
+    fn test_launch_config_to_hjson() {
+        let result = CinSearchResult {
+            name: "ONA",
+            translators: "ONA",
+            path: "/opt/nars/NAR.exe".into(),
+            score: 10,
+            command: CinLaunchCommand {
+                cmd: "/opt/nars/NAR.exe".into(),
+                cmd_args: vec!["shell".into()],
+                current_dir: Some("/opt/nars".into()),
+            },
+        };
+        let config = cin_launch_config(&result);
+        let hjson = launch_config_to_hjson(&config).expect("格式化失败");
+        let dir = FixtureDir::new("discover_hjson");
+        let reread = read_config_extern(&dir.write("cin.hjson", &hjson)).expect("读取失败");
+        asserts! {
+            hjson.starts_with("#hjson\n") => true
+            hjson.contains("null") => false
+            reread.description => config.description
+            reread.translators => config.translators
+            reread.command => config.command
+        }
+    }
+}
@@ -0,0 +1,465 @@
+//! 命令行子命令的执行
+//! * 🎯将「交互式启动」与各类非交互式工作流分开
+//!   * 📄`run`：加载配置 ⇒（询问用户）⇒ 清屏等待 ⇒ 启动 ⇒ 交互
+//!     * 📌标准输入为管道⇒不询问用户、不清屏等待，读到EOF且CIN空闲后退出
+//!   * 📄`serve`：加载配置 ⇒ 启动 ⇒ 服务，全程不询问用户
+//!   * 📄`test`：加载配置 ⇒ 逐个NAL来源启动并执行 ⇒ 按结果退出，参见[`super::run_nal_tests`]
+//!   * 📄`bench`：加载配置 ⇒ 逐个NAL来源重复启动并执行 ⇒ 输出用时与推理步数的统计
+//!   * 📄`matrix`：每个「CIN配置 × NAL文件」组合各启动一个`test`子进程 ⇒ 打印对比表格
+//!   * 📄`search-cin`：搜索已安装的CIN ⇒ 按匹配度列出 ⇒ 为选中的CIN生成启动配置
+//! * 🚩`config`子命令参见[`super::config_main`]
+
+use crate::{
+    cli::{
+        cin_launch_config, collect_nal_sources, discover_cins, enter_working_dir, launch_by_config,
+        launch_config_to_hjson, load_config_sourced, loop_manage, messages,
+        polyfill_config_from_user, run_nal_bench, run_nal_tests, run_test_matrix, search_configs,
+        show_config_provenance, try_spawn_config_watcher, BenchFormat, CliArgs, LaunchConfig,
+        LaunchConfigPreludeNAL, LaunchConfigPreludeNALs, RuntimeConfig, RuntimeManager,
+        SourcedLayer, TestReportFormat, WalkArgs, SUPPORTED_CONFIG_EXTENSIONS, TRANSLATOR_DICT,
+    },
+    eprintln_cli, println_cli,
+    support::{
+        cin_search::impls_path_builder::CinSearchResult,
+        i18n::set_locale,
+        io::{output_print::reserve_stdout, readline_iter::ReadlineIter},
+    },
+};
+use anyhow::{anyhow, Result};
+use std::{
+    env::current_exe,
+    fs::write,
+    io::{stdin, IsTerminal},
+    path::{absolute, Path, PathBuf},
+    thread::sleep,
+    time::Duration,
+};
+
+/// 启动模式
+/// * 🎯决定「加载配置后如何启动、启动后如何结束」
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LaunchMode {
+    /// 交互式启动
+    /// * 🚩配置不全⇒询问用户；启动前清屏等待；启动后接受终端输入
+    Interactive,
+
+    /// Websocket服务
+    /// * 🚩不接受终端输入，须配置Websocket
+    Serve,
+
+    /// 管道输入
+    /// * 🎯标准输入不是终端（如`cat test.nal | babelnar`）时，逐行置入后自动退出
+    /// * 🚩配置不全⇒直接报错；不清屏等待
+    /// * 🚩读到EOF且CIN空闲后退出，参见[`LaunchConfig::pipe_grace_period`]
+    Pipe,
+}
+
+impl LaunchMode {
+    /// 按标准输入选择交互方式
+    /// * 🚩标准输入为终端⇒交互式；否则⇒管道输入
+    pub fn from_stdin() -> Self {
+        match stdin().is_terminal() {
+            true => Self::Interactive,
+            false => Self::Pipe,
+        }
+    }
+
+    /// 是否为交互式启动
+    pub fn is_interactive(&self) -> bool {
+        matches!(self, Self::Interactive)
+    }
+
+    /// 按启动模式调整启动配置
+    /// * 🚩交互式、管道输入⇒保持原样
+    /// * 🚩Websocket服务⇒关闭终端输入；未配置Websocket⇒报错
+    pub fn adjust_config(&self, config: &mut LaunchConfig) -> Result<()> {
+        match self {
+            Self::Interactive | Self::Pipe => {}
+            Self::Serve => {
+                if config.websocket.is_none() {
+                    return Err(anyhow!(messages::serve_without_websocket()));
+                }
+                config.user_input = Some(false);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 按启动模式加载配置、启动并管理虚拟机
+/// * 🚩非交互式⇒配置不全时直接报错，不询问用户、不清屏等待
+/// * 🚩配置不全时，按`walk`自动搜索配置文件
+pub fn launch_main(
+    args: &CliArgs,
+    cwd: Option<PathBuf>,
+    mode: LaunchMode,
+    walk: &WalkArgs,
+) -> Result<()> {
+    // 读取配置
+    let (mut config, layers) = load_config_for_command(args)?;
+
+    // 是否向用户展示「详细信息」 | 用于等待、提示等
+    let interactive = mode.is_interactive();
+    let user_verbose = interactive && config.user_input != Some(false);
+
+    // 用户填充配置项 | 需要用户输入、工作路径（🎯自动搜索）
+    match interactive {
+        true => polyfill_config_from_user(&mut config, cwd, &walk.into(), walk.search_index()),
+        false if config.need_polyfill() => return Err(anyhow!(messages::config_incomplete())),
+        false => {}
+    }
+    mode.adjust_config(&mut config)?;
+
+    // 清屏，预备启动 | 仅交互式
+    if interactive {
+        if !args.quick_start && user_verbose {
+            println_cli!([Info] "{}", messages::starting_soon());
+            sleep(Duration::from_secs(1));
+        }
+        let _ = clearscreen::clear()
+            .inspect_err(|e| eprintln_cli!([Warn] "{}", messages::clear_screen_failed(e)));
+    }
+
+    // 从配置项启动 | 复制一个新配置，不会附带任何非基础类型开销
+    let launch_config = config;
+    let (runtime, config) = match launch_by_config(launch_config.clone()) {
+        // 启动成功⇒返回
+        Ok((r, c)) => (r, c),
+        // 启动失败⇒打印错误信息，等待并退出
+        Err(e) => {
+            println_cli!([Error] "{}", messages::launch_failed(&e));
+            // 空配置/启用用户输入⇒延时提示
+            if user_verbose {
+                println_cli!([Info] "{}", messages::exiting_in(3));
+                sleep(Duration::from_secs(3));
+            }
+            return Err(e);
+        }
+    };
+
+    // 运行时交互、管理
+    let manager = RuntimeManager::new(runtime, config.clone());
+
+    // 监视配置文件 | 仅在显式启用时
+    if args.watch {
+        try_spawn_config_watcher(&manager, args, &layers, &launch_config);
+    }
+    let result = loop_manage(manager);
+
+    // 交互式且启用用户输入时延时提示
+    if user_verbose {
+        println_cli!([Info] "{}", messages::exiting_in(5));
+        sleep(Duration::from_secs(3));
+    }
+
+    // 返回结果
+    result
+}
+
+/// 读取配置，并按配置设置界面语言
+/// * 🚩`--verbose`⇒展示每个配置项的来源
+/// * ⚠️配置覆盖无法应用⇒报错
+fn load_config_for_command(args: &CliArgs) -> Result<(LaunchConfig, Vec<SourcedLayer>)> {
+    // 读取配置 | with 默认配置文件
+    let (config, layers) = load_config_sourced(args)?;
+
+    // 按配置更新界面语言 | 命令行参数仍然优先
+    set_locale(args.locale(Some(&config)));
+
+    // 详细日志：展示每个配置项的来源
+    if args.verbose {
+        println_cli!([Log] "{}", messages::provenance_header());
+        show_config_provenance(&config, &layers, |line| println_cli!([Log] "{line}"));
+    }
+    Ok((config, layers))
+}
+
+/// 无交互地执行NAL测试
+/// * 🚩NAL来源参见[`load_headless_nal_sources`]
+/// * 🚩每个来源各自启动CIN，遇到失败继续执行，最后打印汇总表
+/// * 🚩指定报告文件⇒将每条预期作为一个用例写入报告
+///   * 📌未指定格式⇒按扩展名推断，参见[`TestReportFormat::from_path`]
+/// * ⚠️有任何来源未通过⇒报错（非零退出码）
+pub fn test_main(
+    args: &CliArgs,
+    paths: &[PathBuf],
+    report_path: Option<&Path>,
+    report_format: Option<TestReportFormat>,
+) -> Result<()> {
+    // 读取配置、收集NAL来源
+    let (config, sources) = load_headless_nal_sources(args, paths)?;
+
+    // 报告路径 | ⚠️须在切换工作目录之前转换为绝对路径
+    let report_path = report_path.map(absolute).transpose()?;
+    enter_working_dir(&config)?;
+
+    // 执行并汇总
+    let report = run_nal_tests(&config, &sources, args.verbose);
+    report.print_summary();
+    if let Some(path) = report_path {
+        let format = report_format.unwrap_or_else(|| TestReportFormat::from_path(&path));
+        write(&path, report.render(format))?;
+        println_cli!([Info] "{}", messages::test_report_written(&format, &path));
+    }
+    report.result()
+}
+
+/// 读取用于无交互执行NAL的配置，并收集NAL来源
+/// * 🚩不询问用户：配置不全⇒报错
+/// * 🚩NAL来源：命令行中的文件/目录；缺省⇒配置中的预置NAL
+///   * 📌配置中的预置NAL不再额外执行
+/// * 🚩关闭用户输入与Websocket
+/// * ⚠️尚未切换工作目录：调用方须先处理好其它相对路径，再调用[`enter_working_dir`]
+fn load_headless_nal_sources(
+    args: &CliArgs,
+    paths: &[PathBuf],
+) -> Result<(RuntimeConfig, Vec<LaunchConfigPreludeNAL>)> {
+    // 读取配置 | 不询问用户
+    let (mut config, _) = load_config_for_command(args)?;
+    if config.need_polyfill() {
+        return Err(anyhow!(messages::config_incomplete()));
+    }
+
+    // 收集NAL来源 | ⚠️须在切换工作目录之前
+    let prelude_nal = config.prelude_nal.take();
+    let sources = match paths.is_empty() {
+        false => collect_nal_sources(paths)?,
+        true => {
+            let mut sources = vec![];
+            for source in prelude_nal
+                .into_iter()
+                .flat_map(LaunchConfigPreludeNALs::into_sources)
+            {
+                sources.extend(source.expand()?);
+            }
+            sources
+        }
+    };
+    if sources.is_empty() {
+        return Err(anyhow!(messages::no_nal_sources()));
+    }
+
+    // 转换为运行时配置 | 无用户输入、无Websocket
+    config.user_input = Some(false);
+    config.websocket = None;
+    let config = RuntimeConfig::try_from(config)?;
+    Ok((config, sources))
+}
+
+/// 执行NAL基准测试，并输出统计结果
+/// * 🚩NAL来源参见[`load_headless_nal_sources`]
+/// * 🚩每个来源重复执行`runs`次，统计每条预期的用时与推理步数
+/// * 🚩缺省⇒输出到标准输出；指定文件⇒写入文件，格式按扩展名推断
+///   * 📌输出到标准输出时，日志与进度均转至标准错误：可直接重定向得到干净的CSV/JSON
+pub fn bench_main(
+    args: &CliArgs,
+    paths: &[PathBuf],
+    runs: usize,
+    output: Option<&Path>,
+    format: Option<BenchFormat>,
+) -> Result<()> {
+    // 报告输出到标准输出⇒其它输出均转至标准错误
+    if output.is_none() {
+        reserve_stdout();
+    }
+
+    // 读取配置、收集NAL来源
+    let (config, sources) = load_headless_nal_sources(args, paths)?;
+
+    // 输出路径 | ⚠️须在切换工作目录之前转换为绝对路径
+    let output = output.map(absolute).transpose()?;
+    enter_working_dir(&config)?;
+
+    // 执行并输出
+    let report = run_nal_bench(&config, &sources, runs.max(1), args.verbose);
+    match output {
+        Some(path) => {
+            let format = format.unwrap_or_else(|| BenchFormat::from_path(&path));
+            write(&path, report.render(format))?;
+            println_cli!([Info] "{}", messages::bench_written(&format, &path));
+        }
+        None => print!("{}", report.render(format.unwrap_or_default())),
+    }
+    Ok(())
+}
+
+/// 执行跨CIN的测试矩阵，并打印对比表格
+/// * 🚩表格直接输出到标准输出，便于重定向到文档中
+/// * 📌矩阵用于对比：个别组合未通过不视作错误
+pub fn matrix_main(args: &CliArgs, cins: &[PathBuf], paths: &[PathBuf], jobs: usize) -> Result<()> {
+    let matrix = run_test_matrix(args, cins, paths, jobs)?;
+    println!("{}", matrix.render());
+    println_cli!([Info] "{}", messages::matrix_legend());
+    Ok(())
+}
+
+/// 搜索并列出配置文件
+/// * 🚩缺省⇒从当前工作目录开始搜索
+/// * 📌`--verbose`⇒展示详细搜索过程
+/// * 🚩遍历范围与开销、是否使用搜索索引缓存，均由命令行参数决定
+pub fn search_main(
+    args: &CliArgs,
+    cwd: Option<PathBuf>,
+    dir: Option<&Path>,
+    walk: &WalkArgs,
+) -> Result<()> {
+    let start = match (dir, cwd) {
+        (Some(dir), _) => dir.to_path_buf(),
+        (None, Some(cwd)) => cwd,
+        (None, None) => return Err(anyhow!(messages::search_dir_unknown())),
+    };
+    search_configs(
+        &start,
+        SUPPORTED_CONFIG_EXTENSIONS,
+        args.verbose,
+        &walk.into(),
+        &mut walk.search_index(),
+    )?;
+    Ok(())
+}
+
+/// 搜索已安装的CIN，并为选中的CIN生成启动配置
+/// * 🚩起始目录：指定目录（缺省为工作目录）与exe所在目录
+/// * 🚩选中方式：`--select`指定；否则在终端中询问用户，非终端下仅列出
+/// * 🚩生成的配置：写入`--output`指定的文件，或直接输出到标准输出
+/// * 🚩`--launch`：不输出配置，直接以生成的配置启动选中的CIN并进入交互
+pub fn search_cin_main(
+    cwd: Option<PathBuf>,
+    dir: Option<&Path>,
+    select: Option<usize>,
+    output: Option<&Path>,
+    launch: bool,
+    walk: &WalkArgs,
+) -> Result<()> {
+    // * 📌转换为绝对路径：生成的配置中的路径不应依赖于当前工作目录
+    let mut starts = match (dir, cwd) {
+        (Some(dir), _) => vec![absolute(dir)?],
+        (None, Some(cwd)) => vec![cwd],
+        (None, None) => vec![],
+    };
+    if let Some(exe_dir) = current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_owned))
+    {
+        starts.push(exe_dir);
+    }
+
+    // 搜索并列出
+    let results = discover_cins(&starts, &walk.into(), &mut walk.search_index())?;
+    if results.is_empty() {
+        println_cli!([Info] "{}", messages::no_cin_found());
+        return Ok(());
+    }
+    println_cli!([Info] "{}", messages::cin_search_summary(results.len()));
+    for (i, result) in results.iter().enumerate() {
+        println_cli!([Info] "【{i}】 {} ({}) {}", result.name, result.score, result.path.display());
+    }
+
+    // 选中
+    let selected = match select {
+        Some(i) => Some(
+            results
+                .get(i)
+                .ok_or_else(|| anyhow!(messages::index_out_of_range(i)))?,
+        ),
+        None if stdin().is_terminal() => ask_cin_index(&results),
+        None => None,
+    };
+    let Some(selected) = selected else {
+        return Ok(());
+    };
+
+    // 生成配置
+    let launch_config = cin_launch_config(selected);
+
+    // 直接启动 | 与「写入配置后`run -c`」经由同一条代码路径
+    if launch {
+        let (runtime, config) = launch_by_config(launch_config)?;
+        return loop_manage(RuntimeManager::new(runtime, config));
+    }
+
+    // 输出配置
+    let hjson = launch_config_to_hjson(&launch_config)?;
+    match output {
+        Some(path) => {
+            write(path, hjson)?;
+            println_cli!([Info] "{}", messages::cin_config_written(path));
+        }
+        None => print!("{hjson}"),
+    }
+    Ok(())
+}
+
+/// 询问用户要选中的CIN
+/// * 🚩留空、读到EOF⇒不选中
+fn ask_cin_index(results: &[CinSearchResult]) -> Option<&CinSearchResult> {
+    println_cli!([Info] "{}", messages::cin_select_hint());
+    for line in ReadlineIter::new(messages::cin_select_prompt()) {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                eprintln_cli!([Error] "{}", messages::invalid_input(&e));
+                continue;
+            }
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            return None;
+        }
+        match line.parse::<usize>() {
+            Ok(i) => match results.get(i) {
+                Some(result) => return Some(result),
+                None => eprintln_cli!([Error] "{}", messages::index_out_of_range(i)),
+            },
+            Err(e) => eprintln_cli!([Error] "{}", messages::invalid_input(&e)),
+        }
+    }
+    None
+}
+
+/// 列出所有内置转译器的名称及别名
+/// * 🚩直接输出到标准输出，每行一个，便于脚本处理
+///   * 📌格式：`名称`或`名称\t别名1, 别名2`
+pub fn translators_main() -> Result<()> {
+    for (name, aliases, ..) in TRANSLATOR_DICT {
+        match aliases.is_empty() {
+            true => println!("{name}"),
+            false => println!("{name}\t{}", aliases.join(", ")),
+        }
+    }
+    Ok(())
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::LaunchConfigWebsocket;
+
+    /// 测试/按启动模式调整配置
+    /// * 🎯交互式、管道输入：保持原样
+    /// * 🎯服务模式：关闭终端输入，且须配置Websocket
+    #[test]
+    fn test_adjust_config() {
+        let base = LaunchConfig {
+            websocket: Some(LaunchConfigWebsocket {
+                host: "localhost".into(),
+                port: 8080,
+            }),
+            ..Default::default()
+        };
+
+        let mut config = base.clone();
+        LaunchMode::Interactive.adjust_config(&mut config).unwrap();
+        assert_eq!(config, base);
+        LaunchMode::Pipe.adjust_config(&mut config).unwrap();
+        assert_eq!(config, base);
+
+        let mut config = base.clone();
+        LaunchMode::Serve.adjust_config(&mut config).unwrap();
+        assert_eq!(config.user_input, Some(false));
+        assert!(LaunchMode::Serve
+            .adjust_config(&mut LaunchConfig::default())
+            .is_err());
+    }
+}
@@ -0,0 +1,373 @@
+//! 启动配置中的变量插值
+//! * 🎯让同一份配置文件在不同机器上无需手动修改即可使用
+//! * ✨支持的语法
+//!   * `${NAME}`：替换为变量`NAME`的值
+//!   * `${NAME:-default}`：变量`NAME`未设置或为空时，替换为`default`
+//!   * `$${`：转义为`${`（单独的`$$`不转义）
+//! * ✨支持的变量
+//!   * `CONFIG_DIR`：配置文件所在目录（绝对路径）
+//!   * `CWD`：当前工作目录
+//!   * 其它：从环境变量中读取
+//! * 📌仅对「字符串类」配置项插值：命令、命令参数、路径、环境变量值、主机地址等
+//!   * ⚠️预置NAL的**纯文本**不参与插值：避免与NAL内容本身冲突
+
+use crate::{
+    cli::{
+        messages, LaunchConfig, LaunchConfigPreludeNAL, LaunchConfigPreludeNALs,
+        LaunchConfigTranslators,
+    },
+    support::i18n::LocalizedText,
+};
+use anyhow::{anyhow, Result};
+use std::{
+    collections::HashMap,
+    env::{current_dir, var},
+    path::{Path, PathBuf},
+};
+
+/// 内置变量名/配置文件所在目录
+pub const VAR_CONFIG_DIR: &str = "CONFIG_DIR";
+
+/// 内置变量名/当前工作目录
+pub const VAR_CWD: &str = "CWD";
+
+/// 插值上下文
+/// * 🎯提供变量查找：内置变量优先，其次环境变量
+#[derive(Debug, Clone, Default)]
+pub struct InterpolateContext {
+    /// 内置变量
+    /// * 📄`CONFIG_DIR`、`CWD`
+    vars: HashMap<String, String>,
+
+    /// 是否回退到环境变量
+    use_env: bool,
+}
+
+impl InterpolateContext {
+    /// 构造函数
+    /// * 🚩不含任何变量，也不读取环境变量
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 从配置文件路径构造
+    /// * 🚩`CONFIG_DIR`⇒配置文件所在目录（尽可能绝对化）
+    /// * 🚩`CWD`⇒当前工作目录
+    /// * 🚩启用环境变量
+    pub fn from_config_path(config_path: &Path) -> Self {
+        let mut context = Self::new().with_env();
+        if let Some(dir) = config_path.parent() {
+            let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+            context = context.with_var(VAR_CONFIG_DIR, path_to_string(&dir));
+        }
+        if let Ok(cwd) = current_dir() {
+            context = context.with_var(VAR_CWD, path_to_string(&cwd));
+        }
+        context
+    }
+
+    /// 添加内置变量
+    pub fn with_var(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.vars.insert(name.into(), value.into());
+        self
+    }
+
+    /// 启用环境变量
+    pub fn with_env(mut self) -> Self {
+        self.use_env = true;
+        self
+    }
+
+    /// 查找变量
+    /// * 🚩内置变量 > 环境变量
+    pub fn lookup(&self, name: &str) -> Option<String> {
+        match self.vars.get(name) {
+            Some(value) => Some(value.clone()),
+            None if self.use_env => var(name).ok(),
+            None => None,
+        }
+    }
+
+    /// 对字符串插值
+    /// * 🚩无法解析的变量会被记录到`unresolved`中，并保留原文
+    ///   * 🎯一次性报告所有无法解析的变量
+    /// * 📌未闭合的`${`同样视作「无法解析」
+    pub fn interpolate(&self, source: &str, unresolved: &mut Vec<String>) -> String {
+        let mut result = String::with_capacity(source.len());
+        let mut rest = source;
+        while let Some(i) = rest.find('$') {
+            result += &rest[..i];
+            rest = &rest[i..];
+            // `$${` ⇒ `${` | 单独的`$$`原样保留
+            if let Some(tail) = rest.strip_prefix("$${") {
+                result += "${";
+                rest = tail;
+                continue;
+            }
+            // 非`${`⇒原样保留
+            let Some(tail) = rest.strip_prefix("${") else {
+                result.push('$');
+                rest = &rest[1..];
+                continue;
+            };
+            // 寻找闭合括号
+            let Some(end) = find_closing_brace(tail) else {
+                unresolved.push(rest.to_string());
+                result += rest;
+                rest = "";
+                break;
+            };
+            let expr = &tail[..end];
+            // 分离「变量名」与「默认值」
+            let (name, default) = match expr.split_once(":-") {
+                Some((name, default)) => (name, Some(default)),
+                None => (expr, None),
+            };
+            let value = match (self.lookup(name), default) {
+                // 有值且非空 / 无默认值时的有值
+                (Some(value), None) => Some(value),
+                (Some(value), Some(..)) if !value.is_empty() => Some(value),
+                // 其余情况使用默认值 | 默认值中允许嵌套插值
+                (_, Some(default)) => Some(self.interpolate(default, unresolved)),
+                (None, None) => None,
+            };
+            match value {
+                Some(value) => result += &value,
+                None => {
+                    unresolved.push(name.to_string());
+                    result += &rest[..end + 3];
+                }
+            }
+            rest = &tail[end + 1..];
+        }
+        result + rest
+    }
+
+    /// 对路径插值
+    /// * ⚠️非UTF-8路径保持原样
+    pub fn interpolate_path(&self, path: &mut PathBuf, unresolved: &mut Vec<String>) {
+        if let Some(s) = path.to_str() {
+            *path = self.interpolate(s, unresolved).into();
+        }
+    }
+
+    /// 对字符串原地插值
+    #[inline]
+    pub fn interpolate_string(&self, s: &mut String, unresolved: &mut Vec<String>) {
+        *s = self.interpolate(s, unresolved);
+    }
+}
+
+/// 寻找与`${`配对的`}`
+/// * 🎯支持默认值中的嵌套插值，如`${A:-${B}}`
+fn find_closing_brace(s: &str) -> Option<usize> {
+    let mut depth = 0_usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some(i),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// 路径⇒字符串
+/// * 🚩有损转换：仅用于插值
+fn path_to_string(path: &Path) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+/// 启动配置的变量插值
+impl LaunchConfig {
+    /// 对配置中的所有「字符串类」配置项插值
+    /// * 🚩遇到无法解析的变量时，汇总后统一报错
+    pub fn interpolate_vars(&mut self, context: &InterpolateContext) -> Result<()> {
+        let mut unresolved = vec![];
+        self.interpolate_vars_collecting(context, &mut unresolved);
+        match unresolved.is_empty() {
+            true => Ok(()),
+            false => Err(anyhow!(messages::unresolved_vars(&unresolved.join(", ")))),
+        }
+    }
+
+    /// 对配置中的所有「字符串类」配置项插值，并收集无法解析的变量
+    fn interpolate_vars_collecting(
+        &mut self,
+        context: &InterpolateContext,
+        unresolved: &mut Vec<String>,
+    ) {
+        // 描述 | 各语言的文本
+        for text in self
+            .description
+            .iter_mut()
+            .flat_map(LocalizedText::texts_mut)
+        {
+            context.interpolate_string(text, unresolved);
+        }
+        // 继承的配置文件
+        for path in self.extends.iter_mut().flatten() {
+            context.interpolate_path(path, unresolved);
+        }
+        // 转译器
+        match &mut self.translators {
+            Some(LaunchConfigTranslators::Same(name)) => {
+                context.interpolate_string(name, unresolved)
+            }
+            Some(LaunchConfigTranslators::Separated { input, output }) => {
+                context.interpolate_string(input, unresolved);
+                context.interpolate_string(output, unresolved);
+            }
+            // 自定义转译器不参与插值：避免与正则表达式中的`$`冲突
+            Some(LaunchConfigTranslators::Custom(..)) | None => {}
+        }
+        // 启动命令
+        if let Some(command) = &mut self.command {
+            context.interpolate_string(&mut command.cmd, unresolved);
+            for arg in command.cmd_args.iter_mut().flatten() {
+                context.interpolate_string(arg, unresolved);
+            }
+            if let Some(path) = &mut command.current_dir {
+                context.interpolate_path(path, unresolved);
+            }
+            for value in command.env.iter_mut().flat_map(|env| env.values_mut()) {
+                context.interpolate_string(value, unresolved);
+            }
+        }
+        // Websocket
+        if let Some(websocket) = &mut self.websocket {
+            context.interpolate_string(&mut websocket.host, unresolved);
+        }
+        // 预置NAL | 仅文件路径、通配符
+        for source in self
+            .prelude_nal
+            .iter_mut()
+            .flat_map(LaunchConfigPreludeNALs::sources_mut)
+        {
+            match source {
+                LaunchConfigPreludeNAL::File(path) => context.interpolate_path(path, unresolved),
+                LaunchConfigPreludeNAL::Glob(pattern) => {
+                    context.interpolate_string(pattern, unresolved)
+                }
+                LaunchConfigPreludeNAL::Text(..) => {}
+            }
+        }
+        // 命名档案 | 递归插值
+        for profile in self.profiles.iter_mut().flat_map(|p| p.values_mut()) {
+            profile.interpolate_vars_collecting(context, unresolved);
+        }
+    }
+
+    /// 变量插值，但基于所有权
+    /// * 📌总体逻辑：[`Self`]→[`Self`]
+    pub fn interpolated(mut self, context: &InterpolateContext) -> Result<Self> {
+        self.interpolate_vars(context)?;
+        Ok(self)
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cli::read_config_extern, tests::config_paths::INTERPOLATE_TEST};
+    use nar_dev_utils::{asserts, macro_once};
+
+    /// 测试/字符串插值
+    #[test]
+    fn test_interpolate() {
+        let context = InterpolateContext::new()
+            .with_var("A", "a")
+            .with_var("EMPTY", "")
+            .with_var(VAR_CONFIG_DIR, "/root/config");
+        macro_once! {
+            /// 成功的插值
+            macro test($($source:literal => $expected:literal)*) {
+                $(
+                    let mut unresolved = vec![];
+                    let result = context.interpolate($source, &mut unresolved);
+                    assert!(unresolved.is_empty(), "{:?}中有无法解析的变量：{unresolved:?}", $source);
+                    assert_eq!(result, $expected);
+                )*
+            }
+            "" => ""
+            "plain" => "plain"
+            "${A}" => "a"
+            "x${A}y${A}z" => "xayaz"
+            "${CONFIG_DIR}/nars.jar" => "/root/config/nars.jar"
+            // 默认值
+            "${UNSET:-java}" => "java"
+            "${EMPTY:-java}" => "java"
+            "${A:-java}" => "a"
+            "${UNSET:-}" => ""
+            "${UNSET:-${CONFIG_DIR}/a}" => "/root/config/a"
+            "${A:-${UNSET}}" => "a"
+            // 空值（无默认值）
+            "[${EMPTY}]" => "[]"
+            // 转义与非插值的`$`
+            "$${A}" => "${A}"
+            "$$" => "$$"
+            "a$$b" => "a$$b"
+            "$1 $ $A" => "$1 $ $A"
+            "中文${A}路径" => "中文a路径"
+        }
+        // 无法解析的变量
+        let mut unresolved = vec![];
+        let result = context.interpolate("${X}/${A}/${Y", &mut unresolved);
+        asserts! {
+            result => "${X}/a/${Y"
+            unresolved => vec!["X".to_string(), "${Y".to_string()]
+        }
+    }
+
+    /// 测试/配置插值
+    /// * 🎯汇总所有无法解析的变量
+    #[test]
+    fn test_interpolate_config() {
+        let context = InterpolateContext::new().with_var("CMD", "java");
+        let mut config = LaunchConfig::from_json_str(
+            r#"{
+                command: {
+                    cmd: "${CMD}"
+                    cmdArgs: ["-jar", "${JAR}"]
+                }
+                websocket: {
+                    host: "${HOST}"
+                    port: 8080
+                }
+            }"#,
+        )
+        .expect("配置解析失败");
+        let e = config
+            .interpolate_vars(&context)
+            .expect_err("应该有无法解析的变量");
+        let message = e.to_string();
+        asserts! {
+            message.contains("JAR"),
+            message.contains("HOST"),
+            config.command.unwrap().cmd => "java"
+        }
+    }
+
+    /// 测试/读取时插值
+    /// * 🎯`${CONFIG_DIR}`应为配置文件所在目录
+    #[test]
+    fn test_read_interpolated() {
+        let config = read_config_extern(Path::new(INTERPOLATE_TEST)).expect("配置读取失败");
+        let config_dir = Path::new(INTERPOLATE_TEST)
+            .parent()
+            .unwrap()
+            .canonicalize()
+            .unwrap();
+        let command = config.command.expect("缺少启动命令");
+        asserts! {
+            command.cmd => "java"
+            command.cmd_args => Some(vec![
+                "-jar".into(),
+                path_to_string(&config_dir.join("nars.jar")),
+            ])
+            command.current_dir => Some(config_dir)
+        }
+    }
+}
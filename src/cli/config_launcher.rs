@@ -2,11 +2,18 @@
 
 use crate::{
     cli::{
-        read_config_extern, search_configs, LaunchConfig, LaunchConfigCommand,
-        LaunchConfigTranslators, RuntimeConfig, SUPPORTED_CONFIG_EXTENSIONS,
+        custom_translators, messages, read_config_extern, search_configs, LaunchConfig,
+        LaunchConfigCommand, LaunchConfigTranslators, RuntimeConfig, SearchIndex,
+        SUPPORTED_CONFIG_EXTENSIONS,
     },
     eprintln_cli, println_cli,
-    support::{cin_search::name_match::name_match, io::readline_iter::ReadlineIter},
+    support::{
+        cin_search::{
+            name_match::{name_distance, name_match, normalize_name},
+            path_walker::PathWalkerOptions,
+        },
+        io::readline_iter::ReadlineIter,
+    },
 };
 use anyhow::{anyhow, Result};
 use babel_nar::{
@@ -15,26 +22,38 @@ use babel_nar::{
     },
     runtimes::{
         api::{InputTranslator, IoTranslators},
-        CommandVm, OutputTranslator,
+        CommandVm, CommandVmRuntime, OutputTranslator,
     },
 };
 use nar_dev_utils::pipe;
-use navm::{
-    cmd::Cmd,
-    output::Output,
-    vm::{VmLauncher, VmRuntime},
+use navm::{cmd::Cmd, output::Output, vm::VmLauncher};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
 };
-use std::path::{Path, PathBuf};
 
 /// （若缺省）要求用户手动填充配置项
-pub fn polyfill_config_from_user(config: &mut LaunchConfig, cwd: Option<impl AsRef<Path>>) {
+/// * 🚩自动搜索配置文件时，按`options`限制遍历范围与开销
+pub fn polyfill_config_from_user(
+    config: &mut LaunchConfig,
+    cwd: Option<impl AsRef<Path>>,
+    options: &PathWalkerOptions,
+    mut index: SearchIndex,
+) {
     if config.need_polyfill() {
         // * 先搜索已有的文件 | 不开启
-        let search = |verbose| {
+        // * 🚩搜索索引在多次搜索间复用：重复搜索时无需重新解析配置文件
+        let mut search = |verbose| {
             // 执行搜索
-            let searched_configs = cwd
-                .as_ref()
-                .map(|p| search_configs(p.as_ref(), SUPPORTED_CONFIG_EXTENSIONS, verbose));
+            let searched_configs = cwd.as_ref().map(|p| {
+                search_configs(
+                    p.as_ref(),
+                    SUPPORTED_CONFIG_EXTENSIONS,
+                    verbose,
+                    options,
+                    &mut index,
+                )
+            });
             // 转换为数组并返回
             match searched_configs {
                 Some(Ok(v)) => v.into_iter().collect(),
@@ -44,45 +63,78 @@ pub fn polyfill_config_from_user(config: &mut LaunchConfig, cwd: Option<impl AsR
         // 第一次搜索
         let mut searched_configs = search(false);
         // * 🚩【2024-04-03 19:33:20】目前是要求输入配置文件位置
-        const HINT: &str = "现在需要输入配置文件位置。\n    示例：「BabelNAR.launch.json」\n    若搜索到已有配置文件，可输入其在方括号内的索引，如「0」\n    可直接按下回车，以查看详细搜索过程";
-        const PROMPT: &str = "配置文件位置: ";
+        let hint = messages::config_input_hint();
+        let prompt = messages::config_input_prompt();
         // 提示（不会频繁打印）
-        println_cli!([Info] "{}", HINT);
-        for line in ReadlineIter::new(PROMPT) {
+        println_cli!([Info] "{}", hint);
+        for line in ReadlineIter::new(&prompt) {
             // 检验输入
             let line = match line {
                 Err(e) => {
-                    eprintln_cli!([Error] "输入无效：{e}");
+                    eprintln_cli!([Error] "{}", messages::invalid_input(&e));
                     continue;
                 }
                 Ok(l) => l,
             }; // ! 不能直接加`.trim()`，临时变量会被抛掉
             let line = line.trim();
             if let Ok(i) = line.parse::<usize>() {
-                if i < searched_configs.len() {
-                    println_cli!([Info] "已选择搜索到的第「{i}」个配置：{:?}", searched_configs[i])
+                let Some(searched) = searched_configs.get(i) else {
+                    eprintln_cli!([Error] "{}", messages::index_out_of_range(i));
+                    continue;
+                };
+                // 重新读取选中的配置 | 按当前的工作目录与环境变量插值
+                match searched.load() {
+                    Ok(selected) => {
+                        println_cli!([Info] "{}", messages::searched_config_selected(i, &searched.path));
+                        // 返回结果
+                        *config = selected;
+                        break;
+                    }
+                    Err(e) => {
+                        eprintln_cli!([Error] "{}", messages::config_file_read_failed(&searched.path, &e));
+                        continue;
+                    }
+                }
+            }
+            // 「索引:档案名」⇒选中搜索到的配置中的命名档案
+            // * 📌索引须为数字，以免与Windows盘符（如`C:`）混淆
+            if let Some((Ok(i), name)) = line
+                .split_once(':')
+                .map(|(i, name)| (i.trim().parse::<usize>(), name.trim()))
+            {
+                let selected = match searched_configs.get(i) {
+                    Some(searched) => searched.load_profile(name),
+                    None => Err(anyhow!(messages::index_out_of_range(i))),
+                };
+                match selected {
+                    Ok(selected) => {
+                        println_cli!([Info] "{}", messages::searched_profile_selected(i, name));
+                        *config = selected;
+                        break;
+                    }
+                    Err(e) => {
+                        eprintln_cli!([Error] "{e}");
+                        continue;
+                    }
                 }
-                // 返回结果
-                *config = searched_configs[i].clone();
-                break;
             }
             // 输入为空⇒详细搜索配置⇒重新回到循环
             if line.is_empty() {
                 searched_configs = search(true);
-                println_cli!([Info] "{}", HINT);
+                println_cli!([Info] "{}", hint);
                 continue;
             }
             // 检验路径
             let path = PathBuf::from(line);
             if !path.is_file() {
-                eprintln_cli!([Error] "文件「{path:?}」不存在");
+                eprintln_cli!([Error] "{}", messages::file_not_found(&path));
                 continue;
             }
             // 读取配置文件
             let content = match read_config_extern(&path) {
                 Ok(config) => config,
                 Err(e) => {
-                    eprintln_cli!([Error] "配置文件「{path:?}」读取失败：{e}");
+                    eprintln_cli!([Error] "{}", messages::config_file_read_failed(&path, &e));
                     continue;
                 }
             };
@@ -102,14 +154,12 @@ pub fn polyfill_config_from_user(config: &mut LaunchConfig, cwd: Option<impl AsR
 ///   * ❗错误类型不一致，无法统一返回
 pub fn launch_by_config(
     config: impl TryInto<RuntimeConfig, Error = anyhow::Error>,
-) -> Result<(impl VmRuntime, RuntimeConfig)> {
+) -> Result<(CommandVmRuntime, RuntimeConfig)> {
     // 转换启动配置
     let config: RuntimeConfig = config.try_into()?;
 
-    // * 🚩【2024-04-07 10:13:51】目前通过「设置exe工作路径」切换到启动环境中
-    if let Some(path) = &config.command.current_dir {
-        std::env::set_current_dir(path)?;
-    }
+    // 切换到启动环境中
+    enter_working_dir(&config)?;
 
     // 生成虚拟机
     let runtime = launch_by_runtime_config(&config)?;
@@ -118,13 +168,40 @@ pub fn launch_by_config(
     Ok((runtime, config))
 }
 
+/// 切换到启动命令的工作目录
+/// * 🚩【2024-04-07 10:13:51】目前通过「设置exe工作路径」切换到启动环境中
+/// * ⚠️之后所有的相对路径都将基于新的工作目录
+pub fn enter_working_dir(config: &RuntimeConfig) -> Result<()> {
+    if let Some(path) = &config.command.current_dir {
+        std::env::set_current_dir(path)?;
+    }
+    Ok(())
+}
+
 /// 根据「运行时启动参数」启动虚拟机
 /// * 🚩生成、配置、启动虚拟机
 /// * 🎯在「初次启动」与「二次重启」中共用代码
-pub fn launch_by_runtime_config(config: &RuntimeConfig) -> Result<impl VmRuntime> {
+/// * 📌返回具体的虚拟机类型：便于在「配置热重载」中原地替换
+pub fn launch_by_runtime_config(config: &RuntimeConfig) -> Result<CommandVmRuntime> {
+    launch_by_runtime_config_in(config, None)
+}
+
+/// 根据「运行时启动参数」重新启动虚拟机
+/// * 🎯在「配置热重载」「自动重启」中启动新的虚拟机
+/// * 🚩工作目录设置在启动命令上，而非切换整个进程的工作目录
+///   * 📌[`std::env::set_current_dir`]对整个进程生效，会影响其它线程
+pub fn relaunch_by_runtime_config(config: &RuntimeConfig) -> Result<CommandVmRuntime> {
+    launch_by_runtime_config_in(config, config.command.current_dir.as_deref())
+}
+
+/// 根据「运行时启动参数」启动虚拟机，并（可选地）指定子进程的工作目录
+fn launch_by_runtime_config_in(
+    config: &RuntimeConfig,
+    current_dir: Option<&Path>,
+) -> Result<CommandVmRuntime> {
     // 生成虚拟机
     let config_command = &config.command;
-    let mut vm = load_command_vm(config_command)?;
+    let mut vm = load_command_vm_in(config_command, current_dir)?;
 
     // 配置虚拟机
     // * 🚩【2024-04-04 03:17:43】现在「转译器」成了必选项，所以必定会有配置
@@ -138,13 +215,20 @@ pub fn launch_by_runtime_config(config: &RuntimeConfig) -> Result<impl VmRuntime
 /// 从「启动参数/启动命令」启动「命令行虚拟机」
 /// * ❓需要用到「具体启动器实现」吗
 pub fn load_command_vm(config: &LaunchConfigCommand) -> Result<CommandVm> {
+    // ! 🚩【2024-04-07 12:35:41】不能再设置工作目录：已在[`launch_by_config`]处设置
+    // * 否则会导致「目录名称无效」
+    load_command_vm_in(config, None)
+}
+
+/// 从「启动参数/启动命令」启动「命令行虚拟机」，并（可选地）指定子进程的工作目录
+fn load_command_vm_in(
+    config: &LaunchConfigCommand,
+    current_dir: Option<&Path>,
+) -> Result<CommandVm> {
     // 构造指令
-    let command = generate_command(
+    let mut command = generate_command(
         &config.cmd,
-        // ! 🚩【2024-04-07 12:35:41】不能再设置工作目录：已在[`launch_by_config`]处设置
-        // * 否则会导致「目录名称无效」
-        // config.current_dir.as_ref(),
-        None::<&str>,
+        current_dir,
         // 🚩获取其内部数组的引用，或使用一个空数组作迭代器（无法简化成[`unwrap_or`]）
         match &config.cmd_args {
             Some(v) => v.iter(),
@@ -152,12 +236,28 @@ pub fn load_command_vm(config: &LaunchConfigCommand) -> Result<CommandVm> {
             None => [].iter(),
         },
     );
+    // 设置环境变量
+    apply_command_env(&mut command, config);
     // 构造虚拟机
     let vm = command.into();
     // 返回
     Ok(vm)
 }
 
+/// 将启动命令中的环境变量配置应用到子进程命令上
+/// * 🚩顺序：清空继承的环境变量 ⇒ 移除指定的环境变量 ⇒ 设置环境变量
+pub fn apply_command_env(command: &mut Command, config: &LaunchConfigCommand) {
+    if config.clear_env == Some(true) {
+        command.env_clear();
+    }
+    for name in config.env_remove.iter().flatten() {
+        command.env_remove(name);
+    }
+    if let Some(env) = &config.env {
+        command.envs(env);
+    }
+}
+
 /// 从「启动参数/输入输出转译器」配置「命令行虚拟机」
 /// * 🚩【2024-04-02 01:03:54】此处暂时需要**硬编码**现有的CIN实现
 /// * 🏗️后续可能支持定义自定义转译器（long-term）
@@ -167,26 +267,24 @@ pub fn config_launcher_translators(
     vm: &mut CommandVm,
     config: &LaunchConfigTranslators,
 ) -> Result<()> {
-    Ok(pipe! {
+    pipe! {
         // 获取转译器
         get_translator_by_name(config) => {?}#
         // 设置转译器
         => [vm.translators](_)
-        // 返回成功
-    })
+    };
+    // 返回成功
+    Ok(())
 }
 
 /// 从「转译器名」检索「输入输出转译器」
 /// * 🚩继续分派到「输入转译器检索」与「输出转译器检索」
+/// * 🚩自定义转译器⇒直接从配置构建，参见[`custom_translators`]
 pub fn get_translator_by_name(config: &LaunchConfigTranslators) -> Result<IoTranslators> {
-    let name_i = match config {
-        LaunchConfigTranslators::Same(input) | LaunchConfigTranslators::Separated { input, .. } => {
-            input
-        }
-    };
-    let name_o = match config {
-        LaunchConfigTranslators::Same(output)
-        | LaunchConfigTranslators::Separated { output, .. } => output,
+    let (name_i, name_o) = match config {
+        LaunchConfigTranslators::Same(name) => (name, name),
+        LaunchConfigTranslators::Separated { input, output } => (input, output),
+        LaunchConfigTranslators::Custom(custom) => return custom_translators(custom),
     };
     Ok(IoTranslators {
         input_translator: get_input_translator_by_name(name_i.as_str())?,
@@ -195,65 +293,151 @@ pub fn get_translator_by_name(config: &LaunchConfigTranslators) -> Result<IoTran
 }
 
 /// 输入转译器的索引字典类型
-/// * 📌结构：`[(转译器名, 输入转译器, 输出转译器)]`
-pub type TranslatorDict<'a> = &'a [(
+/// * 📌结构：`[(转译器名, 别名, 输入转译器, 输出转译器)]`
+pub type TranslatorDict<'a> = &'a [TranslatorEntry<'a>];
+
+/// 转译器字典中的一项
+/// * 📌结构：`(转译器名, 别名, 输入转译器, 输出转译器)`
+pub type TranslatorEntry<'a> = (
     &'a str,
+    &'a [&'a str],
     fn(Cmd) -> Result<String>,
     fn(String) -> Result<Output>,
-)];
+);
 
 /// 输入转译器的索引字典
 /// * 🚩静态存储映射，后续遍历可有序可无序
+/// * 📌名称与别名均参与匹配，参见[`resolve_translator`]
 pub const TRANSLATOR_DICT: TranslatorDict = &[
-    ("Native", native::input_translate, native::output_translate),
+    (
+        "Native",
+        &[],
+        native::input_translate,
+        native::output_translate,
+    ),
     (
         "OpenNARS",
+        &[],
         opennars::input_translate,
         opennars::output_translate,
     ),
-    ("ONA", ona::input_translate, ona::output_translate),
+    ("ONA", &[], ona::input_translate, ona::output_translate),
     (
         "NARS-Python",
+        &["NARSPython"],
         nars_python::input_translate,
         nars_python::output_translate,
     ),
     (
-        "NARSPython",
-        nars_python::input_translate,
-        nars_python::output_translate,
+        "PyNARS",
+        &[],
+        pynars::input_translate,
+        pynars::output_translate,
     ),
-    ("PyNARS", pynars::input_translate, pynars::output_translate),
     (
         "OpenJunars",
+        &[],
         openjunars::input_translate,
         openjunars::output_translate,
     ),
     (
         "CXinJS",
+        &["CXinNARS"],
         cxin_js::input_translate,
         cxin_js::output_translate,
     ),
 ];
 
+/// 「你是否想要……」中最多列出的名称数
+const MAX_TRANSLATOR_SUGGESTIONS: usize = 3;
+
+/// 转译器名与查询的匹配度
+/// * 🚩取名称与所有别名中的最高者
+/// * 🚩忽略大小写与分隔符后相等⇒视作「忽略大小写的完全相等」
+///   * 📄"cxin_js"匹配"CXinJS"，"nars_python"匹配"NARS-Python"
+fn translator_match(entry: &TranslatorEntry, query: &str) -> usize {
+    let (name, aliases, ..) = entry;
+    let query_normalized = normalize_name(query);
+    std::iter::once(name)
+        .chain(aliases.iter())
+        .map(|candidate| match name_match(candidate, query) {
+            0 if normalize_name(candidate) == query_normalized => 5,
+            score => score,
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+/// 根据名字查找转译器
+/// * 🚩按[`translator_match`]选取匹配度最高的一项
+/// * ⚠️严格：以下情况均返回错误，而非随意选取一项
+///   * 没有任何匹配⇒附带按编辑距离排序的「你是否想要……」
+///   * 多项匹配度相同⇒列出这些项
+pub fn resolve_translator(query: &str) -> Result<&'static TranslatorEntry<'static>> {
+    let best_score = TRANSLATOR_DICT
+        .iter()
+        .map(|entry| translator_match(entry, query))
+        .max()
+        .unwrap_or_default();
+    // 没有任何匹配
+    if best_score == 0 {
+        let hint = match translator_suggestions(query).as_slice() {
+            [] => messages::translator_list_hint(),
+            names => messages::translator_did_you_mean(&names.join(", ")),
+        };
+        return Err(anyhow!(messages::translator_not_found(query, &hint)));
+    }
+    // 匹配度最高的所有项
+    let best = TRANSLATOR_DICT
+        .iter()
+        .filter(|entry| translator_match(entry, query) == best_score)
+        .collect::<Vec<_>>();
+    match best.as_slice() {
+        [entry] => Ok(entry),
+        entries => {
+            let names = entries.iter().map(|(name, ..)| *name).collect::<Vec<_>>();
+            Err(anyhow!(messages::translator_ambiguous(
+                query,
+                &names.join(", ")
+            )))
+        }
+    }
+}
+
+/// 与查询最接近的转译器名
+/// * 🚩按名称与别名中最小的编辑距离排序，只保留足够接近的
+///   * 📌「足够接近」：距离不超过查询长度的三分之一（至少为1）
+fn translator_suggestions(query: &str) -> Vec<&'static str> {
+    let max_distance = (normalize_name(query).chars().count() / 3).max(1);
+    let mut suggestions = TRANSLATOR_DICT
+        .iter()
+        .filter_map(|(name, aliases, ..)| {
+            let distance = std::iter::once(name)
+                .chain(aliases.iter())
+                .map(|candidate| name_distance(candidate, query))
+                .min()?;
+            (distance <= max_distance).then_some((distance, *name))
+        })
+        .collect::<Vec<_>>();
+    suggestions.sort_by_key(|(distance, _)| *distance);
+    suggestions
+        .into_iter()
+        .take(MAX_TRANSLATOR_SUGGESTIONS)
+        .map(|(_, name)| name)
+        .collect()
+}
+
 /// 根据名字查找「输入转译器」
+/// * 🔗查找规则参见[`resolve_translator`]
 pub fn get_input_translator_by_name(cin_name: &str) -> Result<Box<InputTranslator>> {
-    // 根据「匹配度」的最大值选取
-    let translator = TRANSLATOR_DICT
-        .iter()
-        .max_by_key(|(name, _, _)| name_match(name, cin_name))
-        .ok_or_else(|| anyhow!("未找到输入转译器"))?
-        .1; // 输入转译器
+    let translator = resolve_translator(cin_name)?.2; // 输入转译器
     Ok(Box::new(translator))
 }
 
 /// 根据名字查找「输出转译器」
+/// * 🔗查找规则参见[`resolve_translator`]
 pub fn get_output_translator_by_name(cin_name: &str) -> Result<Box<OutputTranslator>> {
-    // 根据「匹配度」的最大值选取
-    let translator = TRANSLATOR_DICT
-        .iter()
-        .max_by_key(|(name, _, _)| name_match(name, cin_name))
-        .ok_or_else(|| anyhow!("未找到输出转译器"))?
-        .2; // 输出转译器
+    let translator = resolve_translator(cin_name)?.3; // 输出转译器
     Ok(Box::new(translator))
 }
 
@@ -279,4 +463,53 @@ mod tests {
             "opennars"; "ona"; "nars-python"; "narsPython"; "pynars"; "openjunars"; "cxinJS"
         ];
     }
+
+    /// 测试「严格的转译器查找」
+    /// * 🎯无匹配、有歧义时报错，并给出提示
+    #[test]
+    fn test_resolve_translator() {
+        let name = |query| resolve_translator(query).map(|(name, ..)| *name).ok();
+        let error = |query| resolve_translator(query).unwrap_err().to_string();
+        asserts! {
+            // 名称、别名，忽略大小写与分隔符
+            name("opennars") => Some("OpenNARS")
+            name("OpenNARS") => Some("OpenNARS")
+            name("cxin_js") => Some("CXinJS")
+            name("cxin-nars") => Some("CXinJS")
+            name("nars_python") => Some("NARS-Python")
+            name("NARSPython") => Some("NARS-Python")
+            // 拼写错误⇒不再落到任意一项，而是给出建议
+            name("opnenars") => None
+            error("opnenars").contains("OpenNARS") => true
+            error("opnenars").contains("PyNARS") => false
+            error("pynras").contains("PyNARS") => true
+            // 毫不相干⇒没有建议
+            error("xyz").contains("ONA") => false
+            // 歧义⇒列出同等匹配的各项
+            name("NARS") => None
+            error("NARS").contains("OpenNARS") => true
+            error("NARS").contains("PyNARS") => true
+        }
+    }
+
+    /// 测试「启动命令的环境变量」
+    /// * 🚩仅能观察到显式设置/移除的环境变量
+    #[test]
+    fn test_apply_command_env() {
+        let config = LaunchConfigCommand {
+            cmd: "java".into(),
+            env: Some([("JAVA_OPTS".into(), "-Xmx1024m".into())].into()),
+            env_remove: Some(vec!["http_proxy".into()]),
+            ..Default::default()
+        };
+        let mut command = Command::new(&config.cmd);
+        apply_command_env(&mut command, &config);
+        let envs = command
+            .get_envs()
+            .map(|(name, value)| (name.to_str().unwrap(), value.and_then(|v| v.to_str())))
+            .collect::<Vec<_>>();
+        asserts! {
+            envs => [("JAVA_OPTS", Some("-Xmx1024m")), ("http_proxy", None)]
+        }
+    }
 }
@@ -0,0 +1,227 @@
+//! 命令行中的配置覆盖
+//! * 🎯无需另写配置文件，即可在命令行中修改任意配置项
+//!   * 📄`--set websocket.port=8765 --set strictMode=true`
+//! * ✨语法：`键路径=值`
+//!   * 键路径：以`.`分隔的配置键，与配置文件中的键名一致（camelCase）
+//!   * 值：按HJSON解析，解析失败则视作字符串
+//!     * 📄`8765`⇒数字、`true`⇒布尔值、`["a", "b"]`⇒数组、`localhost`⇒字符串
+//!     * 💡如需强制为字符串，可加引号：`description="123"`
+//! * 📌优先级最高：在所有配置文件合并（以及选中命名档案）之后应用
+//! * 🚩应用时会对照配置结构检查：未知键、类型错误均会报错
+
+use crate::cli::{deserialize_tracked, error_path, messages, LaunchConfig};
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Value};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// 单条配置覆盖
+/// * 🚩由`键路径=值`解析而来
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigOverride {
+    /// 键路径
+    /// * 📄`["websocket", "port"]`
+    pub keys: Vec<String>,
+
+    /// 要设置的值
+    pub value: Value,
+}
+
+impl ConfigOverride {
+    /// 以`.`连接的键路径
+    pub fn key_path(&self) -> String {
+        self.keys.join(".")
+    }
+
+    /// 将值设置到（序列化后的）配置中
+    /// * 🚩沿键路径深入，缺失/为空的中间层级自动创建为对象
+    /// * ⚠️中间层级不是对象⇒报错
+    pub(crate) fn set_in(&self, root: &mut Value) -> Result<()> {
+        let (last, parents) = self
+            .keys
+            .split_last()
+            .ok_or_else(|| anyhow!(messages::empty_key_path()))?;
+        let mut current = root;
+        for key in parents {
+            // 空值⇒替换为空对象
+            if current.is_null() {
+                *current = Value::Object(Map::new());
+            }
+            current = match current {
+                Value::Object(map) => map.entry(key.clone()).or_insert(Value::Null),
+                _ => return Err(anyhow!(messages::parent_not_object(key))),
+            };
+        }
+        if current.is_null() {
+            *current = Value::Object(Map::new());
+        }
+        match current {
+            Value::Object(map) => {
+                map.insert(last.clone(), self.value.clone());
+                Ok(())
+            }
+            _ => Err(anyhow!(messages::parent_not_object(last))),
+        }
+    }
+}
+
+impl FromStr for ConfigOverride {
+    type Err = String;
+
+    /// 从`键路径=值`解析
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key_path, raw_value) = s
+            .split_once('=')
+            .ok_or_else(|| messages::override_missing_eq(s))?;
+        let keys = key_path
+            .trim()
+            .split('.')
+            .map(str::trim)
+            .map(String::from)
+            .collect::<Vec<_>>();
+        if keys.iter().any(String::is_empty) {
+            return Err(messages::override_invalid_key_path(s));
+        }
+        // 值：HJSON⇒字符串
+        let raw_value = raw_value.trim();
+        let value = deser_hjson::from_str::<Value>(raw_value)
+            .unwrap_or_else(|_| Value::String(raw_value.into()));
+        Ok(Self { keys, value })
+    }
+}
+
+impl Display for ConfigOverride {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.key_path(), self.value)
+    }
+}
+
+/// 启动配置的命令行覆盖
+impl LaunchConfig {
+    /// 应用单条配置覆盖
+    /// * 🚩序列化⇒设置值⇒反序列化，并对照配置结构检查
+    ///   * 📌未知键、类型错误⇒报错，且不修改自身
+    /// * 📌配置路径不参与序列化，原样保留
+    pub fn apply_override(&mut self, config_override: &ConfigOverride) -> Result<()> {
+        let mut value = serde_json::to_value(&*self)?;
+        config_override.set_in(&mut value)?;
+        // 对照配置结构检查
+        let mut unknown_keys = vec![];
+        let mut config =
+            deserialize_tracked(value, &mut unknown_keys).map_err(|e| {
+                match error_path(e.path()) {
+                    Some(path) => anyhow!(messages::field_type_error(&path, e.inner())),
+                    None => anyhow!("{}", e.inner()),
+                }
+            })?;
+        if !unknown_keys.is_empty() {
+            return Err(anyhow!(messages::unknown_fields(&unknown_keys.join(", "))));
+        }
+        // 保留配置路径
+        config.config_path = self.config_path.take();
+        *self = config;
+        Ok(())
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{LaunchConfigCommand, LaunchConfigTranslators, LaunchConfigWebsocket};
+    use nar_dev_utils::{asserts, macro_once};
+    use serde_json::json;
+
+    /// 测试/解析
+    #[test]
+    fn test_parse() {
+        macro_once! {
+            /// 成功的解析
+            macro test($($s:literal => [$($key:literal),*] = $value:expr)*) {
+                $(
+                    let parsed = $s.parse::<ConfigOverride>().expect("解析失败");
+                    asserts! {
+                        parsed.keys => [$($key),*]
+                        parsed.value => $value
+                    }
+                )*
+            }
+            "websocket.port=8765" => ["websocket", "port"] = json!(8765)
+            "strictMode=true" => ["strictMode"] = json!(true)
+            "translators=opennars" => ["translators"] = json!("opennars")
+            "websocket.host=127.0.0.1" => ["websocket", "host"] = json!("127.0.0.1")
+            r#"command.cmdArgs=["-jar", "nars.jar"]"# => ["command", "cmdArgs"] = json!(["-jar", "nars.jar"])
+            "description=\"123\"" => ["description"] = json!("123")
+            " shortFloatEpoch = 0.01 " => ["shortFloatEpoch"] = json!(0.01)
+            // 值中可以含有`=`
+            "command.cmd=a=b" => ["command", "cmd"] = json!("a=b")
+        }
+        // 失败的解析
+        for s in ["strictMode", "=true", "websocket..port=1", "websocket.=1"] {
+            assert!(s.parse::<ConfigOverride>().is_err(), "{s:?}不应解析成功");
+        }
+    }
+
+    /// 测试/应用
+    #[test]
+    fn test_apply() {
+        let mut config = LaunchConfig {
+            translators: Some(LaunchConfigTranslators::Same("ona".into())),
+            command: Some(LaunchConfigCommand {
+                cmd: "java".into(),
+                ..Default::default()
+            }),
+            websocket: Some(LaunchConfigWebsocket {
+                host: "localhost".into(),
+                port: 8080,
+            }),
+            config_path: Some("root".into()),
+            ..Default::default()
+        };
+        // * 🚩按顺序逐条应用，同命令行`--set`
+        for s in [
+            "websocket.port=8765",
+            "strictMode=true",
+            "translators=opennars",
+            r#"command.cmdArgs=["-jar", "nars.jar"]"#,
+        ] {
+            let config_override = s.parse::<ConfigOverride>().unwrap();
+            if let Err(e) = config.apply_override(&config_override) {
+                panic!("{s:?}应用失败：{e}");
+            }
+        }
+        asserts! {
+            config.websocket => Some(LaunchConfigWebsocket { host: "localhost".into(), port: 8765 })
+            config.strict_mode => Some(true)
+            config.translators => Some(LaunchConfigTranslators::Same("opennars".into()))
+            config.command.as_ref().unwrap().cmd_args => Some(vec!["-jar".into(), "nars.jar".into()])
+            config.config_path => Some("root".into())
+        }
+    }
+
+    /// 测试/应用失败
+    /// * 🎯未知键、类型错误、缺少必要字段均报错，且不修改原配置
+    #[test]
+    fn test_apply_fail() {
+        let original = LaunchConfig::new();
+        for (s, expected) in [
+            ("strctMode=true", "strctMode"),
+            ("websocket.port=abc", "websocket.port"),
+            ("websocket.port=70000", "websocket.port"),
+            ("strictMode=yes", "strictMode"),
+            ("websocket.port=8765", "host"),
+        ] {
+            let mut config = original.clone();
+            let e = config
+                .apply_override(&s.parse().unwrap())
+                .expect_err("应用应该失败");
+            assert!(
+                e.to_string().contains(expected),
+                "{s:?}的报错 {e} 中应含有{expected:?}"
+            );
+            assert_eq!(config, original);
+        }
+    }
+}
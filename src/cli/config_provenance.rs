@@ -0,0 +1,438 @@
+//! 配置项的来源追踪
+//! * 🎯在多个配置文件（及命令行覆盖）合并后，说明「每个配置项来自何处」
+//!   * 📌生效值来自哪一个配置文件/命令行覆盖
+//!   * 📌哪些值被更高优先级的来源遮蔽
+//! * 🚩以「配置层」为单位记录来源：越靠前，优先级越高
+//!   * 📄`--set` > 命名档案 > `-c`指定的配置文件（含其继承的配置） > 默认配置文件 > 内置默认值
+
+use crate::cli::{
+    ConfigLayer, ConfigOverride, LaunchConfig, LaunchConfigCommand, LaunchConfigWebsocket,
+    PreludeNALMerge, RuntimeConfig,
+};
+use serde_json::Value;
+use std::{
+    fmt::{Display, Formatter},
+    path::PathBuf,
+};
+
+/// 配置的来源
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// 命令行中的配置覆盖（`--set`）
+    Override(String),
+    /// 选中的命名档案
+    Profile(String),
+    /// 命令行参数指定的配置文件
+    File(PathBuf),
+    /// 被继承的配置文件
+    Extended(PathBuf),
+    /// 默认配置文件
+    DefaultFile(PathBuf),
+    /// 内置默认值
+    /// * 📌仅在转换为「运行时配置」时出现
+    BuiltinDefault,
+}
+
+impl Display for ConfigSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Override(o) => write!(f, "--set {o}"),
+            Self::Profile(name) => write!(f, "profile {name:?}"),
+            Self::File(path) => write!(f, "file {path:?}"),
+            Self::Extended(path) => write!(f, "extended file {path:?}"),
+            Self::DefaultFile(path) => write!(f, "default file {path:?}"),
+            Self::BuiltinDefault => write!(f, "built-in default"),
+        }
+    }
+}
+
+/// 带来源的配置层
+/// * 🚩存储序列化后的（部分）配置，便于按键路径查找
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourcedLayer {
+    /// 来源
+    pub source: ConfigSource,
+    /// 该层中的（部分）配置
+    pub value: Value,
+}
+
+impl SourcedLayer {
+    /// 从启动配置构造
+    pub fn new(source: ConfigSource, config: &LaunchConfig) -> Self {
+        Self {
+            source,
+            value: serde_json::to_value(config).unwrap_or_default(),
+        }
+    }
+
+    /// 从「命令行配置覆盖」构造
+    /// * 🚩仅含被覆盖的键
+    pub fn from_override(config_override: &ConfigOverride) -> Self {
+        let mut value = Value::Null;
+        // * 📌键路径已在解析时检查，此处不会失败
+        let _ = config_override.set_in(&mut value);
+        Self {
+            source: ConfigSource::Override(config_override.to_string()),
+            value,
+        }
+    }
+
+    /// 从一个配置文件的所有配置层构造
+    /// * 🚩第一层为配置文件自身，其余为其继承的配置文件
+    /// * 📌`is_default`：是否为默认配置文件
+    pub fn from_file_layers(layers: &[ConfigLayer], is_default: bool) -> Vec<Self> {
+        layers
+            .iter()
+            .enumerate()
+            .map(|(i, layer)| {
+                let path = layer.path.clone();
+                let source = match (i, is_default) {
+                    (0, true) => ConfigSource::DefaultFile(path),
+                    (0, false) => ConfigSource::File(path),
+                    _ => ConfigSource::Extended(path),
+                };
+                Self::new(source, &layer.config)
+            })
+            .collect()
+    }
+}
+
+/// 不参与合并的配置项
+/// * 📌合并时保留自身数据/在读取时已展开/仅用于选择档案，不存在「来源」一说
+const UNMERGED_FIELDS: &[&str] = &["description", "extends", "profiles"];
+
+/// 按子项追踪来源的配置项
+/// * 📌`command`：合并时递归深入，参见[`LaunchConfig::merge_from`]
+/// * 📌`websocket`：合并时整体替换，但命令行覆盖可只修改其中一项
+const RECURSIVE_FIELDS: &[&str] = &["command", "websocket"];
+
+/// 预置NAL的键
+/// * 📌追加模式下，多个配置层的来源共同构成生效值，参见[`prelude_nal_provenance`]
+const PRELUDE_NAL: &str = "preludeNAL";
+
+/// 获取序列化后对象的所有键
+fn keys_of(value: impl serde::Serialize) -> Vec<String> {
+    match serde_json::to_value(value) {
+        Ok(Value::Object(map)) => map.keys().cloned().collect(),
+        _ => vec![],
+    }
+}
+
+/// 所有需追踪来源的键路径
+/// * 🚩从序列化后的空配置中获取，避免新增字段时遗漏
+pub fn provenance_field_paths() -> Vec<String> {
+    // 各子项的键 | 含所有可选键
+    let sub_keys = |key: &str| match key {
+        "command" => keys_of(LaunchConfigCommand {
+            cmd: "_".into(),
+            ..Default::default()
+        }),
+        "websocket" => keys_of(LaunchConfigWebsocket::default()),
+        _ => vec![],
+    };
+    keys_of(LaunchConfig::new())
+        .into_iter()
+        .filter(|key| !UNMERGED_FIELDS.contains(&key.as_str()))
+        .flat_map(|key| match RECURSIVE_FIELDS.contains(&key.as_str()) {
+            true => sub_keys(&key)
+                .iter()
+                .map(|sub| format!("{key}.{sub}"))
+                .collect(),
+            false => vec![key],
+        })
+        .collect()
+}
+
+/// 按键路径查找非空值
+/// * 📄`command.cmd` ⇒ `/command/cmd`
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    value
+        .pointer(&format!("/{}", path.replace('.', "/")))
+        .filter(|v| !v.is_null())
+}
+
+/// 单个配置项的来源
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldProvenance {
+    /// 键路径
+    /// * 📄`command.cmd`
+    pub path: String,
+    /// 生效值
+    /// * 🚩未设置⇒[`None`]
+    pub value: Option<Value>,
+    /// 生效值的来源
+    /// * 🚩未设置⇒[`None`]
+    pub source: Option<ConfigSource>,
+    /// 一并生效的值及其来源
+    /// * 🎯追加模式下的预置NAL：较低优先级的来源并入生效值，而非被遮蔽
+    /// * 📌按优先级从高到低排列
+    pub appended: Vec<(ConfigSource, Value)>,
+    /// 被遮蔽的值及其来源
+    /// * 📌按优先级从高到低排列
+    pub shadowed: Vec<(ConfigSource, Value)>,
+}
+
+impl Display for FieldProvenance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            path,
+            value,
+            source,
+            appended,
+            shadowed,
+        } = self;
+        match (value, source) {
+            (Some(value), Some(source)) => write!(f, "{path} = {value}  ← {source}")?,
+            (Some(value), None) => write!(f, "{path} = {value}")?,
+            (None, _) => write!(f, "{path} = (unset)")?,
+        }
+        for (source, value) in appended {
+            write!(f, "\n    (appended) {value}  ← {source}")?;
+        }
+        for (source, value) in shadowed {
+            write!(f, "\n    (shadowed) {value}  ← {source}")?;
+        }
+        Ok(())
+    }
+}
+
+/// 计算合并后配置中每个配置项的来源
+/// * 📌`layers`：按优先级从高到低排列的配置层
+/// * 📌`runtime`：若提供，未设置的配置项将以其中的值作为「内置默认值」
+pub fn config_provenance(
+    config: &LaunchConfig,
+    layers: &[SourcedLayer],
+    runtime: Option<&RuntimeConfig>,
+) -> Vec<FieldProvenance> {
+    let merged = serde_json::to_value(config).unwrap_or_default();
+    let runtime = runtime.and_then(|runtime| serde_json::to_value(runtime).ok());
+    provenance_field_paths()
+        .into_iter()
+        .map(|path| {
+            // 所有设置了该项的配置层 | 预置NAL⇒按合并方式区分「追加」与「遮蔽」
+            let (winner, appended, shadowed) = match path == PRELUDE_NAL {
+                true => prelude_nal_provenance(layers),
+                false => {
+                    let mut candidates = layers
+                        .iter()
+                        .filter(|layer| lookup(&layer.value, &path).is_some());
+                    (candidates.next(), vec![], candidates.collect())
+                }
+            };
+            let sourced_values = |layers: Vec<&SourcedLayer>| {
+                layers
+                    .into_iter()
+                    .filter_map(|layer| {
+                        Some((layer.source.clone(), lookup(&layer.value, &path)?.clone()))
+                    })
+                    .collect()
+            };
+            let (appended, shadowed) = (sourced_values(appended), sourced_values(shadowed));
+            // 生效值：合并后配置 ⇒ 运行时默认值
+            let (value, source) = match (lookup(&merged, &path), winner) {
+                (Some(value), winner) => (
+                    Some(value.clone()),
+                    winner.map(|layer| layer.source.clone()),
+                ),
+                (None, _) => match runtime.as_ref().and_then(|r| lookup(r, &path)) {
+                    Some(value) => (Some(value.clone()), Some(ConfigSource::BuiltinDefault)),
+                    None => (None, None),
+                },
+            };
+            FieldProvenance {
+                path,
+                value,
+                source,
+                appended,
+                shadowed,
+            }
+        })
+        .collect()
+}
+
+/// 计算预置NAL的来源
+/// * 🚩模拟[`LaunchConfig::merge_from`]：已合并部分为追加模式时，被合并者的来源并入生效值
+///   * 📌合并方式取自优先级更高的配置层
+///   * 📌命令行覆盖在合并之后应用，整体替换，不参与追加
+/// * ⚙️返回(生效值的来源, 追加的来源, 被遮蔽的来源)
+fn prelude_nal_provenance(
+    layers: &[SourcedLayer],
+) -> (
+    Option<&SourcedLayer>,
+    Vec<&SourcedLayer>,
+    Vec<&SourcedLayer>,
+) {
+    let mut winner = None::<&SourcedLayer>;
+    let (mut appended, mut shadowed) = (vec![], vec![]);
+    // 已合并部分的合并方式
+    let mut merge = None::<PreludeNALMerge>;
+    for layer in layers {
+        let is_override = matches!(layer.source, ConfigSource::Override(..));
+        if lookup(&layer.value, PRELUDE_NAL).is_some() {
+            match winner {
+                None => winner = Some(layer),
+                Some(winner) if matches!(winner.source, ConfigSource::Override(..)) => {
+                    shadowed.push(layer)
+                }
+                Some(..) => match merge.unwrap_or_default() {
+                    PreludeNALMerge::Append => appended.push(layer),
+                    PreludeNALMerge::Replace => shadowed.push(layer),
+                },
+            }
+        }
+        if !is_override && merge.is_none() {
+            merge = lookup(&layer.value, "preludeNALMerge")
+                .and_then(|v| serde_json::from_value(v.clone()).ok());
+        }
+    }
+    (winner, appended, shadowed)
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{LaunchConfigPreludeNAL, LaunchConfigTranslators};
+    use nar_dev_utils::asserts;
+    use serde_json::json;
+
+    /// 测试/键路径
+    /// * 🎯含递归合并的子项，不含不参与合并的项
+    #[test]
+    fn test_field_paths() {
+        let paths = provenance_field_paths();
+        for path in [
+            "translators",
+            "command.cmd",
+            "command.cmdArgs",
+            "websocket.host",
+            "websocket.port",
+            "preludeNAL",
+        ] {
+            assert!(paths.contains(&path.into()), "缺少键路径{path:?}");
+        }
+        for path in [
+            "command",
+            "websocket",
+            "description",
+            "extends",
+            "profiles",
+            "configPath",
+        ] {
+            assert!(!paths.contains(&path.into()), "不应含有键路径{path:?}");
+        }
+    }
+
+    /// 测试/来源追踪
+    #[test]
+    fn test_provenance() {
+        let high = LaunchConfig {
+            translators: Some(LaunchConfigTranslators::Same("opennars".into())),
+            ..Default::default()
+        };
+        let low = LaunchConfig {
+            translators: Some(LaunchConfigTranslators::Same("ona".into())),
+            command: Some(LaunchConfigCommand {
+                cmd: "java".into(),
+                ..Default::default()
+            }),
+            websocket: Some(LaunchConfigWebsocket {
+                host: "localhost".into(),
+                port: 8080,
+            }),
+            ..Default::default()
+        };
+        let config_override: ConfigOverride = "websocket.port=8765".parse().unwrap();
+        // 合并
+        let mut config = high.clone();
+        config.merge_from(&low);
+        config.apply_override(&config_override).unwrap();
+        let runtime = RuntimeConfig::try_from(config.clone()).unwrap();
+        let layers = [
+            SourcedLayer::from_override(&config_override),
+            SourcedLayer::new(ConfigSource::File("high".into()), &high),
+            SourcedLayer::new(ConfigSource::DefaultFile("low".into()), &low),
+        ];
+        let provenance = config_provenance(&config, &layers, Some(&runtime));
+        let get = |path: &str| {
+            provenance
+                .iter()
+                .find(|p| p.path == path)
+                .unwrap_or_else(|| panic!("缺少键路径{path:?}"))
+        };
+        asserts! {
+            // 被遮蔽
+            get("translators").value => Some(json!("opennars"))
+            get("translators").source => Some(ConfigSource::File("high".into()))
+            get("translators").shadowed => [(ConfigSource::DefaultFile("low".into()), json!("ona"))]
+            // 递归合并的子项
+            get("command.cmd").source => Some(ConfigSource::DefaultFile("low".into()))
+            get("command.cmdArgs").value => None
+            // 命令行覆盖：仅覆盖其中一项
+            get("websocket.port").value => Some(json!(8765))
+            get("websocket.port").source => Some(ConfigSource::Override("websocket.port=8765".into()))
+            get("websocket.port").shadowed => [(ConfigSource::DefaultFile("low".into()), json!(8080))]
+            get("websocket.host").value => Some(json!("localhost"))
+            get("websocket.host").source => Some(ConfigSource::DefaultFile("low".into()))
+            get("websocket.host").shadowed => []
+            // 内置默认值
+            get("strictMode").value => Some(json!(false))
+            get("strictMode").source => Some(ConfigSource::BuiltinDefault)
+        }
+    }
+
+    /// 测试/预置NAL的来源
+    /// * 🎯追加模式下，较低优先级的来源并入生效值；替换模式下被遮蔽
+    #[test]
+    fn test_prelude_nal_provenance() {
+        let nal = |text: &str| Some(LaunchConfigPreludeNAL::Text(text.into()).into());
+        let high = LaunchConfig {
+            prelude_nal: nal("high"),
+            prelude_nal_merge: Some(PreludeNALMerge::Append),
+            ..Default::default()
+        };
+        let middle = LaunchConfig {
+            prelude_nal: nal("middle"),
+            ..Default::default()
+        };
+        let low = LaunchConfig {
+            prelude_nal: nal("low"),
+            ..Default::default()
+        };
+        let mut config = high.clone();
+        config.merge_from(&middle);
+        config.merge_from(&low);
+        let layers = [
+            SourcedLayer::new(ConfigSource::File("high".into()), &high),
+            SourcedLayer::new(ConfigSource::Extended("middle".into()), &middle),
+            SourcedLayer::new(ConfigSource::Extended("low".into()), &low),
+        ];
+        let provenance = config_provenance(&config, &layers, None);
+        let prelude_nal = provenance
+            .iter()
+            .find(|p| p.path == "preludeNAL")
+            .expect("缺少预置NAL");
+        asserts! {
+            prelude_nal.source => Some(ConfigSource::File("high".into()))
+            prelude_nal.appended.len() => 2
+            prelude_nal.shadowed => []
+        }
+        // 命令行覆盖⇒整体替换，其余均被遮蔽
+        let config_override: ConfigOverride = r#"preludeNAL={"text": "override"}"#.parse().unwrap();
+        config.apply_override(&config_override).unwrap();
+        let layers = [SourcedLayer::from_override(&config_override)]
+            .into_iter()
+            .chain(layers)
+            .collect::<Vec<_>>();
+        let provenance = config_provenance(&config, &layers, None);
+        let prelude_nal = provenance
+            .iter()
+            .find(|p| p.path == "preludeNAL")
+            .expect("缺少预置NAL");
+        asserts! {
+            prelude_nal.source => Some(ConfigSource::Override(config_override.to_string()))
+            prelude_nal.appended => []
+            prelude_nal.shadowed.len() => 3
+        }
+    }
+}
@@ -1,17 +1,28 @@
 //! CIN自动搜索
 
-use crate::cli::{read_config_extern, LaunchConfig};
-use crate::println_cli;
-use crate::support::cin_search::{name_match::is_name_match, path_walker::PathWalkerV1};
+use crate::cli::{messages, SearchIndex, SearchedConfig};
+use crate::support::cin_search::{
+    name_match::is_name_match,
+    path_walker::{PathWalkerOptions, PathWalkerV1},
+};
+use crate::support::i18n::LocalizedText;
+use crate::{eprintln_cli, println_cli};
 use anyhow::Result;
-use nar_dev_utils::ToDebug;
 use std::path::{Path, PathBuf};
 
+/// 从起始目录搜索配置文件，并列出其中有效且非空的配置
+/// * 🚩遍历范围与开销由`walk_options`限制
+///   * 📌超出开销预算⇒提前结束，并提示用户
+/// * 🚩配置文件经由搜索索引读取：未修改的文件无需重新解析
+///   * 📌搜索结束后写回索引；写回失败仅作警告
+/// * 📌返回配置的摘要：选中后再重新读取完整配置，参见[`SearchedConfig::load`]
 pub fn search_configs<S: AsRef<str>>(
     start: &Path,
     allowed_extension_names: impl IntoIterator<Item = S>,
     verbose: bool,
-) -> Result<impl IntoIterator<Item = LaunchConfig>> {
+    walk_options: &PathWalkerOptions,
+    index: &mut SearchIndex,
+) -> Result<impl IntoIterator<Item = SearchedConfig>> {
     // 允许的扩展名
     let extension_names = allowed_extension_names.into_iter().collect::<Vec<_>>();
     // 深入条件
@@ -21,7 +32,7 @@ pub fn search_configs<S: AsRef<str>>(
     }
 
     // 构建遍历者，加上条件
-    let walker = PathWalkerV1::new(start, deep_criterion).unwrap();
+    let mut walker = PathWalkerV1::with_options(start, deep_criterion, walk_options.clone())?;
 
     let is_extension_match = |path: &PathBuf| {
         path.extension().is_some_and(|ext| {
@@ -37,15 +48,15 @@ pub fn search_configs<S: AsRef<str>>(
     let mut c = 0;
     let mut c_valid = 0;
     let mut valid_non_empty_configs = vec![];
-    for path in walker.flatten().filter(is_extension_match) {
+    for path in walker.by_ref().flatten().filter(is_extension_match) {
         if verbose {
-            println_cli!([Log] "正在搜索 {path:?}");
+            println_cli!([Log] "{}", messages::searching(&path));
         }
-        if let Ok(config) = read_config_extern(&path) {
+        if let Some(config) = index.read_config(&path) {
             c_valid += 1;
-            if !config.is_empty() {
+            if !config.is_empty {
                 if verbose {
-                    println_cli!([Info] "搜索到配置文件：{config:?}");
+                    println_cli!([Info] "{}", messages::config_found(&config));
                 }
                 valid_non_empty_configs.push(config);
             }
@@ -53,20 +64,37 @@ pub fn search_configs<S: AsRef<str>>(
         c += 1;
     }
 
+    if let Err(e) = index.save() {
+        eprintln_cli!([Warn] "{}", messages::search_cache_save_failed(&e));
+    }
+
     // 输出搜索结果
+    if walker.is_exhausted() {
+        println_cli!([Warn] "{}", messages::search_budget_exhausted());
+    }
     println_cli!(
         [Info]
-        "一共搜索了{c}个文件，其中 {c_valid} 个文件符合条件，{} 个非空",
-        &valid_non_empty_configs.len()
+        "{}",
+        messages::search_summary(c, c_valid, valid_non_empty_configs.len())
     );
     match valid_non_empty_configs.is_empty() {
-        true => println_cli!([Info] "未搜索到任何有效配置。"),
+        true => println_cli!([Info] "{}", messages::no_valid_config()),
         false => {
-            println_cli!([Info] "已搜索到以下有效配置：");
+            println_cli!([Info] "{}", messages::valid_configs_header());
             for (i, config) in valid_non_empty_configs.iter().enumerate() {
                 // TODO: 后续或许在其中添加描述信息？
-                let information = config.description.clone().unwrap_or(config.to_debug());
+                let information = match &config.description {
+                    Some(description) => description.to_string(),
+                    None => config.path.display().to_string(),
+                };
                 println_cli!([Info] "【{i}】 {information}");
+                // 命名档案 | 以「索引:档案名」的形式展示
+                for (name, description) in &config.profiles {
+                    let information = description
+                        .as_ref()
+                        .map_or(name.as_str(), LocalizedText::get);
+                    println_cli!([Info] "    【{i}:{name}】 {information}");
+                }
             }
         }
     }
@@ -87,6 +115,13 @@ mod tests {
         // 测试`config`目录下的文件
         let start = ARG_PARSE_TEST;
         // * 📌起始目录即项目根目录
-        search_configs(&PathBuf::from(start), ["json", "hjson"], true).expect("搜索出错");
+        search_configs(
+            &PathBuf::from(start),
+            ["json", "hjson"],
+            true,
+            &PathWalkerOptions::default(),
+            &mut SearchIndex::in_memory(),
+        )
+        .expect("搜索出错");
     }
 }
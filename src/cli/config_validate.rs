@@ -0,0 +1,679 @@
+//! 启动配置的JSON Schema生成与校验
+//! * 🎯为启动配置提供「机器可读」的格式描述，替代[`super::vm_config`]模块文档中的TypeScript示意
+//! * 🎯在启动之前发现配置中的问题，替代「静默返回空配置」的回退行为
+//!   * 📌未知键：拼写错误等（加载时会被静默忽略）
+//!   * 📌类型错误：如`port: "8080"`
+//!   * 📌无效值：如负数的`shortFloatEpoch`
+//! * 🚩所有问题均附带「文件:行:列」位置
+//!   * ⚠️对「未知键」「无效值」的定位基于文本搜索，仅为近似位置
+
+use crate::{
+    cli::{
+        check_epoch, get_translator_by_name, load_config_sourced, messages, show_config_provenance,
+        try_complete_path, CliArgs, ConfigFormat, InterpolateContext, LaunchConfig,
+        LaunchConfigPreludeNAL,
+    },
+    println_cli,
+};
+use anyhow::{anyhow, Result};
+use schemars::{schema_for, Schema};
+use std::{
+    fmt::{Display, Formatter},
+    fs::{read_to_string, write},
+    path::{Path, PathBuf},
+};
+
+/// 生成启动配置的JSON Schema
+/// * 🚩由[`LaunchConfig`]及其字段类型自动派生
+pub fn launch_config_schema() -> Schema {
+    schema_for!(LaunchConfig)
+}
+
+/// 生成启动配置的JSON Schema（格式化的JSON文本）
+pub fn launch_config_schema_json() -> Result<String> {
+    Ok(serde_json::to_string_pretty(&launch_config_schema())?)
+}
+
+/// 配置问题的种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigIssueKind {
+    /// 语法错误：文件本身无法被解析
+    Syntax,
+    /// 未知键：不属于启动配置的键
+    UnknownKey,
+    /// 类型错误：键存在，但值的类型不对
+    WrongType,
+    /// 无效值：类型正确，但值不合法
+    InvalidValue,
+}
+
+impl Display for ConfigIssueKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Syntax => "syntax error",
+            Self::UnknownKey => "unknown key",
+            Self::WrongType => "wrong type",
+            Self::InvalidValue => "invalid value",
+        })
+    }
+}
+
+/// 单个配置问题
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// 问题种类
+    pub kind: ConfigIssueKind,
+    /// 出问题的键路径
+    /// * 📄`command.cmdArgs[0]`
+    pub path: Option<String>,
+    /// 行号（从1开始）
+    pub line: Option<usize>,
+    /// 列号（从1开始）
+    pub column: Option<usize>,
+    /// 问题描述
+    pub message: String,
+}
+
+impl ConfigIssue {
+    /// 构造函数
+    /// * 🚩位置留空，后续由[`ConfigIssue::locate`]或[`ConfigIssue::at`]补充
+    pub fn new(kind: ConfigIssueKind, path: Option<String>, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            path,
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// 指定位置
+    pub fn at(mut self, location: Option<(usize, usize)>) -> Self {
+        if let Some((line, column)) = location {
+            self.line = Some(line);
+            self.column = Some(column);
+        }
+        self
+    }
+
+    /// 根据键路径在源码中（近似）定位
+    /// * 🚩已有位置⇒不变
+    pub fn locate(self, content: &str) -> Self {
+        if self.line.is_some() {
+            return self;
+        }
+        let location = self
+            .path
+            .as_deref()
+            .and_then(|path| locate_key(content, path));
+        self.at(location)
+    }
+
+    /// 展示为「文件:行:列: [种类] 路径: 描述」的形式
+    pub fn display_in<'a>(&'a self, file: &'a Path) -> impl Display + 'a {
+        ConfigIssueDisplay { issue: self, file }
+    }
+}
+
+/// 配置问题的展示
+/// * 🎯附带文件路径
+struct ConfigIssueDisplay<'a> {
+    issue: &'a ConfigIssue,
+    file: &'a Path,
+}
+
+impl Display for ConfigIssueDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let ConfigIssue {
+            kind,
+            path,
+            line,
+            column,
+            message,
+        } = self.issue;
+        write!(f, "{}", self.file.display())?;
+        if let Some(line) = line {
+            write!(f, ":{line}")?;
+            if let Some(column) = column {
+                write!(f, ":{column}")?;
+            }
+        }
+        write!(f, ": [{kind}] ")?;
+        if let Some(path) = path {
+            write!(f, "{path}: ")?;
+        }
+        write!(f, "{message}")
+    }
+}
+
+/// 校验配置文件
+/// * 🚩自动补全扩展名，并根据扩展名确定格式
+/// * ⚠️文件读取失败⇒[`Err`]
+/// * 📌返回所有发现的问题；无问题⇒空数组
+pub fn validate_config_file(path: &Path) -> Result<Vec<ConfigIssue>> {
+    let path = try_complete_path(path);
+    let content = read_to_string(&path)?;
+    let format = ConfigFormat::from_path(&path);
+    Ok(validate_config_str(&content, format, Some(&path)))
+}
+
+/// 校验配置文本
+/// * 🚩语法⇒键与类型⇒值，逐层检查
+///   * 📌语法或类型出错时，不再进行后续检查
+/// * 📌`config_path`用于`${CONFIG_DIR}`等变量的插值检查
+pub fn validate_config_str(
+    content: &str,
+    format: ConfigFormat,
+    config_path: Option<&Path>,
+) -> Vec<ConfigIssue> {
+    let mut unknown_keys = vec![];
+    // 解析 & 检查未知键
+    let parsed = match format {
+        ConfigFormat::Hjson => parse_hjson(content, &mut unknown_keys),
+        ConfigFormat::Toml => parse_toml(content, &mut unknown_keys),
+        ConfigFormat::Yaml => parse_yaml(content, &mut unknown_keys),
+    };
+    // 未知键
+    let mut issues = unknown_keys
+        .into_iter()
+        .map(|path| {
+            let message = format!("`{}` is not a launch config key", last_key(&path));
+            ConfigIssue::new(ConfigIssueKind::UnknownKey, Some(path), message)
+        })
+        .collect::<Vec<_>>();
+    // 语法错误、类型错误
+    let config = match parsed {
+        Ok(config) => config,
+        Err(issue) => {
+            issues.push(issue);
+            return locate_all(issues, content);
+        }
+    };
+    // 无效值
+    issues.extend(check_values(config, config_path));
+    locate_all(issues, content)
+}
+
+/// 批量定位
+fn locate_all(issues: Vec<ConfigIssue>, content: &str) -> Vec<ConfigIssue> {
+    issues
+        .into_iter()
+        .map(|issue| issue.locate(content))
+        .collect()
+}
+
+/// 工具函数/带「未知键检测」与「出错路径」的反序列化
+pub(crate) fn deserialize_tracked<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+    unknown_keys: &mut Vec<String>,
+) -> Result<LaunchConfig, serde_path_to_error::Error<D::Error>> {
+    let mut callback = |path: serde_ignored::Path| unknown_keys.push(ignored_path_to_string(&path));
+    let deserializer = serde_ignored::Deserializer::new(deserializer, &mut callback);
+    serde_path_to_error::deserialize(deserializer)
+}
+
+/// 解析HJSON/JSON
+/// * 🚩先解析为中间值以检查语法，再从中间值反序列化以检查键与类型
+///   * 📝[`deser_hjson`]未公开其反序列化器，无法直接包装
+///   * 📌使用[`serde_norway::Value`]作中间值：可无损保留`Infinity`等非有限浮点数
+fn parse_hjson(content: &str, unknown_keys: &mut Vec<String>) -> Result<LaunchConfig, ConfigIssue> {
+    // 语法
+    let value = deser_hjson::from_str::<serde_norway::Value>(content).map_err(|e| match e {
+        deser_hjson::Error::Syntax {
+            line, col, code, ..
+        } => ConfigIssue::new(ConfigIssueKind::Syntax, None, format!("{code:?}"))
+            .at(Some((line, col))),
+        e => ConfigIssue::new(ConfigIssueKind::Syntax, None, e.to_string()),
+    })?;
+    // 键与类型 | 位置由键路径近似确定
+    deserialize_tracked(value, unknown_keys).map_err(|e| {
+        ConfigIssue::new(
+            ConfigIssueKind::WrongType,
+            error_path(e.path()),
+            e.inner().to_string(),
+        )
+    })
+}
+
+/// 解析TOML
+fn parse_toml(content: &str, unknown_keys: &mut Vec<String>) -> Result<LaunchConfig, ConfigIssue> {
+    // 语法
+    let deserializer = toml::Deserializer::parse(content).map_err(|e| {
+        ConfigIssue::new(ConfigIssueKind::Syntax, None, e.message())
+            .at(e.span().map(|span| line_column(content, span.start)))
+    })?;
+    // 键与类型
+    deserialize_tracked(deserializer, unknown_keys).map_err(|e| {
+        ConfigIssue::new(
+            ConfigIssueKind::WrongType,
+            error_path(e.path()),
+            e.inner().message(),
+        )
+        .at(e
+            .inner()
+            .span()
+            .map(|span| line_column(content, span.start)))
+    })
+}
+
+/// 解析YAML
+fn parse_yaml(content: &str, unknown_keys: &mut Vec<String>) -> Result<LaunchConfig, ConfigIssue> {
+    /// YAML错误⇒行列号
+    /// * 📝[`serde_norway`]的行号从1开始、列号从0开始
+    fn location(e: &serde_norway::Error) -> Option<(usize, usize)> {
+        e.location().map(|l| (l.line(), l.column() + 1))
+    }
+    // 语法
+    serde_norway::from_str::<serde_norway::Value>(content).map_err(|e| {
+        ConfigIssue::new(ConfigIssueKind::Syntax, None, e.to_string()).at(location(&e))
+    })?;
+    // 键与类型
+    deserialize_tracked(serde_norway::Deserializer::from_str(content), unknown_keys).map_err(|e| {
+        ConfigIssue::new(
+            ConfigIssueKind::WrongType,
+            error_path(e.path()),
+            e.inner().to_string(),
+        )
+        .at(location(e.inner()))
+    })
+}
+
+/// 检查值的有效性
+/// * 🚩仅检查「类型正确但值非法」的情况
+fn check_values(config: LaunchConfig, config_path: Option<&Path>) -> Vec<ConfigIssue> {
+    let mut issues = vec![];
+    // 顶层配置与各命名档案
+    check_fields(&config, "", &mut issues);
+    for (name, profile) in config.profiles.iter().flatten() {
+        check_fields(profile, &format!("profiles.{name}."), &mut issues);
+    }
+    // 启动命令：非空
+    // * 🚩档案中可省略，由顶层配置补全；有继承的配置⇒可由被继承者补全，不作检查
+    let blank_cmd = |config: &LaunchConfig| {
+        config
+            .command
+            .as_ref()
+            .is_some_and(|command| command.cmd.trim().is_empty())
+    };
+    if config.extends.is_none() && blank_cmd(&config) {
+        issues.push(ConfigIssue::new(
+            ConfigIssueKind::InvalidValue,
+            Some("command.cmd".into()),
+            "command must not be empty",
+        ));
+    }
+    let top_cmd_missing = config
+        .command
+        .as_ref()
+        .is_none_or(|command| command.cmd.trim().is_empty());
+    for (name, profile) in config.profiles.iter().flatten() {
+        if config.extends.is_none() && top_cmd_missing && blank_cmd(profile) {
+            issues.push(ConfigIssue::new(
+                ConfigIssueKind::InvalidValue,
+                Some(format!("profiles.{name}.command.cmd")),
+                "command must not be empty",
+            ));
+        }
+    }
+    let mut invalid = |path: &str, message: String| {
+        issues.push(ConfigIssue::new(
+            ConfigIssueKind::InvalidValue,
+            Some(path.into()),
+            message,
+        ))
+    };
+    // 继承的配置文件：应存在
+    if let (Some(extends), Some(config_path)) = (&config.extends, config_path) {
+        let root = config_path.parent().unwrap_or(Path::new("."));
+        for (i, parent) in extends.iter().enumerate() {
+            if !try_complete_path(&root.join(parent)).is_file() {
+                invalid(
+                    &format!("extends[{i}]"),
+                    format!("extended config {parent:?} not found"),
+                );
+            }
+        }
+    }
+    // 变量插值：所有变量应可解析
+    let context = match config_path {
+        Some(path) => InterpolateContext::from_config_path(path),
+        None => InterpolateContext::new().with_env(),
+    };
+    let mut config = config;
+    if let Err(e) = config.interpolate_vars(&context) {
+        issues.push(ConfigIssue::new(
+            ConfigIssueKind::InvalidValue,
+            None,
+            e.to_string(),
+        ));
+    }
+    issues
+}
+
+/// 检查单个（顶层/档案）配置中字段的有效性
+/// * 📌`prefix`：键路径前缀，如`profiles.strict.`
+fn check_fields(config: &LaunchConfig, prefix: &str, issues: &mut Vec<ConfigIssue>) {
+    let mut invalid = |key: &str, message: String| {
+        issues.push(ConfigIssue::new(
+            ConfigIssueKind::InvalidValue,
+            Some(format!("{prefix}{key}")),
+            message,
+        ))
+    };
+    // 短浮点精度：有限非负
+    if let Some(epoch) = config.short_float_epoch {
+        if !check_epoch(epoch) {
+            invalid(
+                "shortFloatEpoch",
+                format!("epoch must be finite and non-negative, got {epoch}"),
+            );
+        }
+    }
+    // 管道输入的宽限期：有限非负
+    if let Some(seconds) = config.pipe_grace_period {
+        if !check_epoch(seconds) {
+            invalid(
+                "pipeGracePeriod",
+                format!("grace period must be finite and non-negative, got {seconds}"),
+            );
+        }
+    }
+    // 转译器：名称可解析，自定义转译器的正则规则有效
+    // * 🚩与启动时相同的构建流程：未知/有歧义的名称、无效的正则、`EXE`规则缺少`operator`捕获组
+    if let Some(translators) = &config.translators {
+        if let Err(e) = get_translator_by_name(translators) {
+            invalid("translators", e.to_string());
+        }
+    }
+    if let Some(command) = &config.command {
+        // 环境变量名：非空且不含`=`
+        let env_names = command.env.iter().flat_map(|env| env.keys());
+        for name in env_names.chain(command.env_remove.iter().flatten()) {
+            if name.is_empty() || name.contains('=') {
+                invalid(
+                    "command.env",
+                    format!("invalid environment variable name {name:?}"),
+                );
+            }
+        }
+    }
+    // 预置NAL：通配符有效
+    for source in config.prelude_nal.iter().flat_map(|p| p.sources()) {
+        if let LaunchConfigPreludeNAL::Glob(pattern) = source {
+            if let Err(e) = glob::Pattern::new(pattern) {
+                invalid(
+                    "preludeNAL",
+                    format!("invalid glob pattern {pattern:?}: {e}"),
+                );
+            }
+        }
+    }
+}
+
+/// [`serde_ignored`]路径⇒字符串
+/// * 🚩跳过`Option`、newtype等不对应键的层级
+/// * 📄`command.cmdArgs[0]`
+fn ignored_path_to_string(path: &serde_ignored::Path) -> String {
+    use serde_ignored::Path::*;
+    match path {
+        Root => String::new(),
+        Seq { parent, index } => format!("{}[{index}]", ignored_path_to_string(parent)),
+        Map { parent, key } => match ignored_path_to_string(parent) {
+            parent if parent.is_empty() => key.clone(),
+            parent => format!("{parent}.{key}"),
+        },
+        Some { parent } | NewtypeStruct { parent } | NewtypeVariant { parent } => {
+            ignored_path_to_string(parent)
+        }
+    }
+}
+
+/// [`serde_path_to_error`]路径⇒字符串
+/// * 🚩根路径⇒[`None`]
+pub(crate) fn error_path(path: &serde_path_to_error::Path) -> Option<String> {
+    use serde_path_to_error::Segment::*;
+    let mut result = String::new();
+    for segment in path.iter() {
+        match segment {
+            Seq { index } => result += &format!("[{index}]"),
+            Map { key } | Enum { variant: key } => {
+                if !result.is_empty() {
+                    result.push('.');
+                }
+                result += key;
+            }
+            Unknown => {}
+        }
+    }
+    match result.is_empty() {
+        true => None,
+        false => Some(result),
+    }
+}
+
+/// 路径中的最后一个键
+/// * 📄`command.cmdArgs[0]` ⇒ `cmdArgs`
+fn last_key(path: &str) -> &str {
+    path_keys(path).last().unwrap_or(path)
+}
+
+/// 路径中的所有键（去除数组下标）
+fn path_keys(path: &str) -> impl Iterator<Item = &str> {
+    path.split('.')
+        .map(|segment| segment.split('[').next().unwrap_or_default())
+        .filter(|key| !key.is_empty())
+}
+
+/// 字节偏移⇒行列号（均从1开始）
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// 在源码中（近似）定位键路径
+/// * 🚩按路径中的键依次向后搜索「作为键出现」的位置
+///   * 📌「作为键出现」：前面不是标识符字符，后面（去除引号、空白后）是`:`、`=`、`.`或`]`
+/// * ⚠️仅为近似定位：不理解具体格式的嵌套结构
+fn locate_key(content: &str, path: &str) -> Option<(usize, usize)> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
+    let mut from = 0;
+    let mut found = None;
+    for key in path_keys(path) {
+        let position = content[from..]
+            .match_indices(key)
+            .map(|(i, _)| from + i)
+            .find(|&i| {
+                let before_ok = !content[..i].chars().next_back().is_some_and(is_ident);
+                let after = content[i + key.len()..]
+                    .trim_start_matches(['"', '\''])
+                    .trim_start();
+                before_ok && after.starts_with([':', '=', '.', ']'])
+            })?;
+        found = Some(position);
+        from = position + key.len();
+    }
+    found.map(|offset| line_column(content, offset))
+}
+
+/// `config`子命令的入口
+/// * 🚩`show`：按命令行参数加载配置，展示每个配置项的来源
+/// * 🚩`schema`：输出JSON Schema到标准输出/文件
+/// * 🚩`validate`：校验配置文件，有问题⇒报错（非零退出码）
+pub fn config_main(command: &ConfigCommand, args: &CliArgs) -> Result<()> {
+    match command {
+        ConfigCommand::Show => {
+            let (config, layers) = load_config_sourced(args)?;
+            show_config_provenance(&config, &layers, |line| println!("{line}"));
+            Ok(())
+        }
+        ConfigCommand::Schema { output } => {
+            let schema = launch_config_schema_json()?;
+            match output {
+                Some(path) => {
+                    write(path, schema + "\n")?;
+                    println_cli!([Info] "{}", messages::schema_written(path));
+                }
+                // * 🚩直接输出到标准输出，便于重定向
+                None => println!("{schema}"),
+            }
+            Ok(())
+        }
+        ConfigCommand::Validate { files } => {
+            let mut n_issues = 0;
+            for file in files {
+                let issues = validate_config_file(file)
+                    .map_err(|e| anyhow!(messages::config_file_unreadable(file, &e)))?;
+                for issue in &issues {
+                    println_cli!([Error] "{}", issue.display_in(file));
+                }
+                if issues.is_empty() {
+                    println_cli!([Info] "{}", messages::config_file_valid(file));
+                }
+                n_issues += issues.len();
+            }
+            match n_issues {
+                0 => Ok(()),
+                n => Err(anyhow!(messages::validation_failed(n))),
+            }
+        }
+    }
+}
+
+/// `config`子命令
+/// * 🎯查看、检查启动配置的格式
+#[derive(clap::Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum ConfigCommand {
+    /// Show the effective config and where each field comes from (honors `-c`, `-d`, `--profile` and `--set`)
+    Show,
+    /// Print the JSON Schema of launch configurations
+    Schema {
+        /// Write the schema into a file instead of stdout
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// Validate launch configuration files, reporting unknown keys, wrong types and invalid values
+    Validate {
+        /// Configuration files to validate
+        #[arg(value_name = "FILE", required = true)]
+        files: Vec<PathBuf>,
+    },
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::config_paths::*;
+    use nar_dev_utils::asserts;
+
+    /// 测试/JSON Schema
+    /// * 🎯包含各字段与子类型
+    #[test]
+    fn test_schema() {
+        let schema = launch_config_schema_json().expect("Schema生成失败");
+        for key in [
+            "translators",
+            "command",
+            "cmdArgs",
+            "websocket",
+            "preludeNAL",
+            "inputMode",
+            "shortFloatEpoch",
+        ] {
+            assert!(schema.contains(key), "Schema中缺少{key:?}");
+        }
+        // 不应包含被跳过的字段
+        assert!(!schema.contains("configPath"));
+    }
+
+    /// 测试/校验通过
+    #[test]
+    fn test_validate_ok() {
+        for path in [
+            ARG_PARSE_TEST,
+            ARG_PARSE_TEST_TOML,
+            ARG_PARSE_TEST_YAML,
+            EXTENDS_TEST,
+            PROFILES_TEST,
+            PRELUDE_LIST_TEST,
+            WEBSOCKET,
+            PRELUDE_TEST,
+        ] {
+            let issues = validate_config_file(Path::new(path)).expect("文件读取失败");
+            assert!(issues.is_empty(), "{path:?}中出现非预期的问题：{issues:#?}");
+        }
+    }
+
+    /// 测试/各类问题
+    #[test]
+    fn test_validate_issues() {
+        let issues = validate_config_file(Path::new(VALIDATE_TEST)).expect("文件读取失败");
+        dbg!(&issues);
+        let kinds_paths = issues
+            .iter()
+            .map(|issue| (issue.kind, issue.path.as_deref(), issue.line))
+            .collect::<Vec<_>>();
+        asserts! {
+            kinds_paths => [
+                (ConfigIssueKind::UnknownKey, Some("strctMode"), Some(5)),
+                (ConfigIssueKind::UnknownKey, Some("command.cwd"), Some(9)),
+                (ConfigIssueKind::InvalidValue, Some("shortFloatEpoch"), Some(11)),
+            ]
+        }
+        // 类型错误
+        let issues = validate_config_str(
+            "{\n  websocket: {\n    host: localhost\n    port: \"8080\"\n  }\n}",
+            ConfigFormat::Hjson,
+            None,
+        );
+        asserts! {
+            issues.len() => 1
+            issues[0].kind => ConfigIssueKind::WrongType
+            issues[0].path.as_deref() => Some("websocket.port")
+        }
+        // 转译器：无法解析的名称、无效的自定义规则
+        for translators in [
+            r#""opnenars""#,
+            r#"{ input: {}, output: [{ pattern: "(", type: "OUT" }] }"#,
+            r#"{ input: {}, output: [{ pattern: "^EXE: (.*)$", type: "EXE" }] }"#,
+        ] {
+            let issues = validate_config_str(
+                &format!("{{\n  translators: {translators}\n}}"),
+                ConfigFormat::Hjson,
+                None,
+            );
+            asserts! {
+                issues.len() => 1
+                issues[0].kind => ConfigIssueKind::InvalidValue
+                issues[0].path.as_deref() => Some("translators")
+            }
+        }
+        // 语法错误
+        let issues = validate_config_str("a = [", ConfigFormat::Toml, None);
+        asserts! {
+            issues.len() => 1
+            issues[0].kind => ConfigIssueKind::Syntax
+            issues[0].line => Some(1)
+        }
+        let issues = validate_config_str("command:\n  cmd: [java\n", ConfigFormat::Yaml, None);
+        asserts! {
+            issues.len() => 1
+            issues[0].kind => ConfigIssueKind::Syntax
+        }
+    }
+
+    /// 测试/近似定位
+    #[test]
+    fn test_locate_key() {
+        let content = "[command]\ncmd = \"java\"\n\n[websocket]\nhost = \"localhost\"\n";
+        asserts! {
+            locate_key(content, "command") => Some((1, 2))
+            locate_key(content, "command.cmd") => Some((2, 1))
+            locate_key(content, "websocket.host") => Some((5, 1))
+            locate_key(content, "websocket.port") => None
+        }
+    }
+}
@@ -0,0 +1,402 @@
+//! 启动配置的热重载
+//! * 🎯在长时间运行中调整`shortFloatEpoch`、`strictMode`、预置NAL等配置，而无需重启CLI
+//! * 🚩轮询所加载配置文件（含继承的配置文件）的修改时间，有变更⇒重新加载配置
+//!   * 📌仅影响运行时行为的配置项⇒原地替换，各线程在下次使用时生效
+//!   * 📌启动命令、转译器⇒终止并按新配置重启虚拟机
+//!   * 📌预置NAL变更、虚拟机重启⇒重新执行预置NAL
+//!   * ⚠️Websocket地址变更⇒仅提示，需重新启动CLI方可生效
+//! * 🚩所有变更都会逐项记录到日志中
+//! * 📌需通过`--watch`显式启用
+
+use crate::{
+    cli::{
+        load_config_sourced, messages, CliArgs, ConfigSource, LaunchConfig, RuntimeConfig,
+        RuntimeManager, SourcedLayer,
+    },
+    println_cli,
+};
+use anyhow::Result;
+use babel_nar::runtimes::CommandVmRuntime;
+use nar_dev_utils::OptionBoost;
+use navm::vm::{VmRuntime, VmStatus};
+use serde_json::Value;
+use std::{
+    fmt::{Display, Formatter},
+    fs::metadata,
+    ops::ControlFlow::{Break, Continue},
+    path::{Path, PathBuf},
+    sync::{Arc, PoisonError, RwLock},
+    thread::{self, sleep, JoinHandle},
+    time::{Duration, SystemTime},
+};
+
+/// 轮询配置文件的间隔
+pub const WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 变更后需要重启虚拟机的配置项
+/// * 📌这些配置项只在启动虚拟机时使用
+const RESTART_FIELDS: &[&str] = &["translators", "command"];
+
+/// 变更后需要重新启动CLI的配置项
+/// * 📌Websocket服务器已绑定地址，无法原地替换
+const RELAUNCH_FIELDS: &[&str] = &["websocket"];
+
+/// 可原地替换的运行时配置
+/// * 🎯配置热重载：在多线程间共享，并可在运行时整体替换
+/// * 🚩各线程在使用时获取最新配置的快照（[`Arc`]拷贝）
+#[derive(Debug, Clone)]
+pub struct SharedConfig(Arc<RwLock<Arc<RuntimeConfig>>>);
+
+impl SharedConfig {
+    /// 构造函数
+    pub fn new(config: RuntimeConfig) -> Self {
+        Self(Arc::new(RwLock::new(Arc::new(config))))
+    }
+
+    /// 获取当前配置的快照
+    /// * 📌即便锁已「中毒」，配置本身仍然完整，照常读取
+    pub fn get(&self) -> Arc<RuntimeConfig> {
+        self.0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// 替换为新配置
+    pub fn set(&self, config: RuntimeConfig) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(config);
+    }
+}
+
+/// 配置文件监视器
+/// * 🚩记录每个文件的修改时间，轮询时与之比较
+#[derive(Debug, Clone, Default)]
+pub struct ConfigWatcher {
+    /// 被监视的文件及其上次的修改时间
+    /// * 📌文件不存在⇒[`None`]
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl ConfigWatcher {
+    /// 构造函数
+    /// * 🚩立即记录各文件当前的修改时间
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        let mut files = Vec::<(PathBuf, Option<SystemTime>)>::new();
+        for path in paths {
+            // 去重 | 如「菱形继承」中被多次继承的配置文件
+            if files.iter().all(|(p, _)| *p != path) {
+                let modified = modified_time(&path);
+                files.push((path, modified));
+            }
+        }
+        Self { files }
+    }
+
+    /// 从带来源的配置层构造
+    /// * 🚩仅监视配置文件，忽略命令行覆盖、命名档案等
+    pub fn from_layers(layers: &[SourcedLayer]) -> Self {
+        Self::new(layers.iter().filter_map(|layer| match &layer.source {
+            ConfigSource::File(path)
+            | ConfigSource::Extended(path)
+            | ConfigSource::DefaultFile(path) => Some(path.clone()),
+            _ => None,
+        }))
+    }
+
+    /// 被监视的文件
+    pub fn files(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|(path, _)| path.as_path())
+    }
+
+    /// 是否未监视任何文件
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// 轮询一次
+    /// * 🚩返回自上次轮询以来被修改（含创建、删除）的文件，并更新记录
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let mut changed = vec![];
+        for (path, last_modified) in self.files.iter_mut() {
+            let modified = modified_time(path);
+            if modified != *last_modified {
+                *last_modified = modified;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+}
+
+/// 获取文件的修改时间
+/// * 🚩文件不存在/无法获取⇒[`None`]
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// 单个配置项的变更
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    /// 配置项名
+    /// * 📄`shortFloatEpoch`
+    pub field: String,
+    /// 旧值
+    /// * 🚩未设置⇒[`None`]
+    pub old: Option<Value>,
+    /// 新值
+    /// * 🚩未设置⇒[`None`]
+    pub new: Option<Value>,
+}
+
+impl ConfigChange {
+    /// 是否需要重启虚拟机
+    pub fn needs_restart(&self) -> bool {
+        RESTART_FIELDS.contains(&self.field.as_str())
+    }
+
+    /// 是否需要重新启动CLI
+    pub fn needs_relaunch(&self) -> bool {
+        RELAUNCH_FIELDS.contains(&self.field.as_str())
+    }
+}
+
+impl Display for ConfigChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let show = |value: &Option<Value>| match value {
+            Some(value) => value.to_string(),
+            None => "(unset)".into(),
+        };
+        write!(
+            f,
+            "{}: {} → {}",
+            self.field,
+            show(&self.old),
+            show(&self.new)
+        )
+    }
+}
+
+/// 比较两个运行时配置，得到所有变更的配置项
+/// * 🚩按序列化后的顶层键逐个比较
+/// * 📌配置路径不参与序列化，也不参与比较
+pub fn diff_runtime_config(old: &RuntimeConfig, new: &RuntimeConfig) -> Vec<ConfigChange> {
+    let to_map = |config: &RuntimeConfig| match serde_json::to_value(config) {
+        Ok(Value::Object(map)) => map,
+        _ => Default::default(),
+    };
+    let (old, new) = (to_map(old), to_map(new));
+    let mut fields = old.keys().chain(new.keys()).collect::<Vec<_>>();
+    fields.sort();
+    fields.dedup();
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let (old, new) = (old.get(field), new.get(field));
+            let not_null = |v: Option<&Value>| v.filter(|v| !v.is_null()).cloned();
+            (old != new).then(|| ConfigChange {
+                field: field.clone(),
+                old: not_null(old),
+                new: not_null(new),
+            })
+        })
+        .collect()
+}
+
+/// 监视配置文件所需的虚拟机管理者
+/// * 🚩需要具体的虚拟机类型，以便按新配置重启（参见[`RuntimeManager::relaunch`]）
+impl RuntimeManager<CommandVmRuntime> {
+    /// 应用重新加载的配置
+    /// * 🚩逐项记录变更 ⇒ 原地替换/重启虚拟机 ⇒（按需）重新执行预置NAL
+    /// * 📌返回所有变更
+    pub fn apply_reloaded_config(&mut self, config: RuntimeConfig) -> Vec<ConfigChange> {
+        let changes = diff_runtime_config(&self.config.get(), &config);
+        if changes.is_empty() {
+            println_cli!([Info] "{}", messages::config_unchanged());
+            return changes;
+        }
+        for change in &changes {
+            println_cli!([Info] "{}", messages::config_change(change));
+        }
+        if changes.iter().any(ConfigChange::needs_relaunch) {
+            println_cli!([Warn] "{}", messages::websocket_needs_relaunch());
+        }
+        // 启动命令、转译器变更⇒重启虚拟机；否则原地替换
+        let restarted = changes.iter().any(ConfigChange::needs_restart);
+        match restarted {
+            true => {
+                println_cli!([Info] "{}", messages::restarting_vm());
+                match self.relaunch(config) {
+                    Ok(()) => println_cli!([Info] "{}", messages::vm_restarted()),
+                    Err(e) => {
+                        println_cli!([Error] "{}", messages::vm_restart_failed(&e));
+                        return changes;
+                    }
+                }
+            }
+            false => self.config.set(config),
+        }
+        // 重启、预置NAL变更⇒重新执行预置NAL
+        if restarted || changes.iter().any(|change| change.field == "preludeNAL") {
+            if let Break(Err(e)) | Continue(Err(e)) = self.prelude_nal() {
+                println_cli!([Error] "{}", messages::prelude_rerun_failed(&e));
+            }
+        }
+        changes
+    }
+}
+
+/// 生成「监视配置文件」子线程
+/// * 🚩每隔[`WATCH_INTERVAL`]轮询一次；有变更⇒调用`reload`重新加载配置并应用
+///   * 📌重新加载失败⇒报错并保留原配置
+/// * 🚩虚拟机终止（且未在重启中）⇒线程结束
+pub fn spawn_config_watcher(
+    manager: &RuntimeManager<CommandVmRuntime>,
+    mut watcher: ConfigWatcher,
+    reload: impl Fn() -> Result<RuntimeConfig> + Send + 'static,
+) -> JoinHandle<()> {
+    let mut manager = manager.clone();
+    println_cli!([Info] "{}", messages::watching_header());
+    for path in watcher.files() {
+        println_cli!([Info] "  {}", path.display());
+    }
+    thread::spawn(move || loop {
+        sleep(WATCH_INTERVAL);
+        // 虚拟机已终止⇒结束
+        let terminated = match manager.runtime.lock() {
+            Ok(runtime) => matches!(runtime.status(), VmStatus::Terminated(..)),
+            Err(..) => true,
+        };
+        if terminated {
+            break;
+        }
+        // 轮询
+        let changed = watcher.poll();
+        if changed.is_empty() {
+            continue;
+        }
+        for path in &changed {
+            println_cli!([Info] "{}", messages::config_file_changed(&path.display()));
+        }
+        match reload() {
+            Ok(config) => {
+                manager.apply_reloaded_config(config);
+            }
+            Err(e) => println_cli!([Error] "{}", messages::reload_failed(&e)),
+        }
+    })
+}
+
+/// 按命令行参数启动配置监视
+/// * 🚩重新加载时，使用与启动时相同的命令行参数（`-c`、`--profile`、`--set`等）
+///   * 📌配置文件中缺失的转译器、启动命令⇒沿用启动时的配置（如由用户手动填充）
+/// * ⚠️没有可监视的配置文件⇒警告并返回[`None`]
+pub fn try_spawn_config_watcher(
+    manager: &RuntimeManager<CommandVmRuntime>,
+    args: &CliArgs,
+    layers: &[SourcedLayer],
+    launch_config: &LaunchConfig,
+) -> Option<JoinHandle<()>> {
+    let watcher = ConfigWatcher::from_layers(layers);
+    if watcher.is_empty() {
+        println_cli!([Warn] "{}", messages::nothing_to_watch());
+        return None;
+    }
+    let args = args.clone();
+    let launch_config = launch_config.clone();
+    let reload = move || {
+        let (mut config, _) = load_config_sourced(&args)?;
+        config
+            .translators
+            .coalesce_clone(&launch_config.translators);
+        config.command.coalesce_clone(&launch_config.command);
+        RuntimeConfig::try_from(config)
+    };
+    Some(spawn_config_watcher(manager, watcher, reload))
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cli::{LaunchConfigCommand, LaunchConfigTranslators},
+        tests::fixture::FixtureDir,
+    };
+    use nar_dev_utils::asserts;
+    use serde_json::json;
+    use std::fs::{remove_file, File};
+
+    /// 测试/比较运行时配置
+    #[test]
+    fn test_diff() {
+        let old = RuntimeConfig {
+            config_path: "a".into(),
+            translators: LaunchConfigTranslators::Same("opennars".into()),
+            command: LaunchConfigCommand {
+                cmd: "java".into(),
+                ..Default::default()
+            },
+            websocket: None,
+            prelude_nal: vec![],
+            user_input: true,
+            input_mode: Default::default(),
+            auto_restart: false,
+            strict_mode: false,
+            short_float_epoch: 0.0,
+            pipe_grace_period: 1.0,
+        };
+        // 仅配置路径不同⇒无变更
+        let same = RuntimeConfig {
+            config_path: "b".into(),
+            ..old.clone()
+        };
+        assert!(diff_runtime_config(&old, &same).is_empty());
+        // 运行时配置项
+        let new = RuntimeConfig {
+            strict_mode: true,
+            short_float_epoch: 0.01,
+            ..old.clone()
+        };
+        let changes = diff_runtime_config(&old, &new);
+        asserts! {
+            !changes.iter().any(ConfigChange::needs_restart),
+            changes.len() => 2
+            changes[0].to_string() => "shortFloatEpoch: 0.0 → 0.01"
+            changes[1].new => Some(json!(true))
+        }
+        // 启动命令
+        let new = RuntimeConfig {
+            translators: LaunchConfigTranslators::Same("ona".into()),
+            ..old.clone()
+        };
+        let changes = diff_runtime_config(&old, &new);
+        assert!(changes.iter().all(ConfigChange::needs_restart));
+    }
+
+    /// 测试/监视文件
+    /// * 🎯修改、删除、重新创建均能检测到
+    #[test]
+    fn test_watcher() {
+        let dir = FixtureDir::new("watch");
+        let path = dir.write("config.hjson", "{}");
+        let mut watcher = ConfigWatcher::new([path.clone(), path.clone()]);
+        asserts! {
+            watcher.files().count() => 1
+            watcher.poll() => Vec::<PathBuf>::new()
+        }
+        // 修改
+        let later = SystemTime::now() + Duration::from_secs(10);
+        File::options()
+            .write(true)
+            .open(&path)
+            .and_then(|f| f.set_modified(later))
+            .unwrap();
+        asserts! {
+            watcher.poll() => [path.as_path()]
+            watcher.poll() => Vec::<PathBuf>::new()
+        }
+        // 删除
+        remove_file(&path).unwrap();
+        assert_eq!(watcher.poll(), [path.as_path()]);
+    }
+}
@@ -0,0 +1,263 @@
+//! 自定义转译器
+//! * 🎯无需重新编译，即可在配置中接入实验性的NARS实现
+//! * 🚩输入：按NAVM指令的头部（`NSE`、`CYC`等）选取模板
+//!   * `{head}`⇒指令头部；`{tail}`⇒指令参数（如Narsese、周期数）
+//!   * 📌没有对应模板⇒不支持该指令（同内置转译器）
+//! * 🚩输出：按顺序逐条尝试正则规则，取第一条匹配的
+//!   * 📌命名捕获组：`content`、`narsese`、`operator`、`params`
+//!   * 📌都不匹配⇒`OTHER`
+//! * 📌正则表达式在启动时编译：写错的规则在启动时即报错
+
+use crate::cli::{messages, LaunchConfigCustomTranslator, LaunchConfigOutputRule};
+use anyhow::{anyhow, Result};
+use babel_nar::runtimes::{IoTranslators, TranslateError};
+use narsese::{
+    conversion::string::impl_lexical::format_instances::FORMAT_ASCII,
+    lexical::{Narsese, Term},
+};
+use navm::{
+    cmd::Cmd,
+    output::{type_names, Operation, Output},
+};
+use regex::{Captures, Regex};
+use std::collections::BTreeMap;
+
+/// 从配置构建自定义的输入输出转译器
+/// * ⚠️正则表达式无效、`EXE`规则缺少`operator`捕获组⇒返回错误
+pub fn custom_translators(config: &LaunchConfigCustomTranslator) -> Result<IoTranslators> {
+    let templates = config.input.clone();
+    let rules = config
+        .output
+        .iter()
+        .map(OutputRule::try_from)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(IoTranslators {
+        input_translator: Box::new(move |cmd| input_translate(&templates, cmd)),
+        output_translator: Box::new(move |line| output_translate(&rules, line)),
+    })
+}
+
+/// 输入转译
+/// * 🚩按指令头部选取模板，再填入头部与参数
+fn input_translate(templates: &BTreeMap<String, String>, cmd: Cmd) -> Result<String> {
+    match templates.get(cmd.head()) {
+        Some(template) => Ok(template
+            .replace("{head}", cmd.head())
+            .replace("{tail}", &cmd.tail())),
+        None => Err(TranslateError::UnsupportedInput(cmd).into()),
+    }
+}
+
+/// 输出转译
+/// * 🚩取第一条匹配的规则；都不匹配⇒`OTHER`
+/// * 📌先去掉行尾换行符：进程输出的每行都带有换行，会使`$`锚点失配
+fn output_translate(rules: &[OutputRule], line: String) -> Result<Output> {
+    let line = line.trim_end_matches(['\r', '\n']);
+    for rule in rules {
+        if let Some(captures) = rule.regex.captures(line) {
+            return rule.output(&captures, line);
+        }
+    }
+    Ok(Output::OTHER {
+        content: line.to_string(),
+    })
+}
+
+/// 编译后的输出规则
+struct OutputRule {
+    /// 正则表达式
+    regex: Regex,
+    /// NAVM输出类型 | 已转为大写
+    output_type: String,
+}
+
+impl TryFrom<&LaunchConfigOutputRule> for OutputRule {
+    type Error = anyhow::Error;
+
+    fn try_from(rule: &LaunchConfigOutputRule) -> Result<Self> {
+        let regex = Regex::new(&rule.pattern)
+            .map_err(|e| anyhow!(messages::custom_translator_invalid_regex(&rule.pattern, &e)))?;
+        let output_type = rule.output_type.to_uppercase();
+        // `EXE`必须能提取出操作名
+        if output_type == type_names::EXE
+            && !regex.capture_names().any(|name| name == Some("operator"))
+        {
+            return Err(anyhow!(messages::custom_translator_no_operator(
+                &rule.pattern
+            )));
+        }
+        Ok(Self { regex, output_type })
+    }
+}
+
+impl OutputRule {
+    /// 从匹配结果构造NAVM输出
+    /// * 🚩输出类型的对应关系同[`Output::try_from_json_struct`]
+    fn output(&self, captures: &Captures, line: &str) -> Result<Output> {
+        use type_names::*;
+        let content = captures
+            .name("content")
+            .map_or(line, |m| m.as_str())
+            .to_string();
+        // Narsese解析失败⇒忽略，仍保留原始内容
+        let narsese = captures
+            .name("narsese")
+            .and_then(|m| FORMAT_ASCII.parse(m.as_str().trim()).ok());
+        let output = match self.output_type.as_str() {
+            IN => Output::IN { content, narsese },
+            OUT => Output::OUT {
+                content_raw: content,
+                narsese,
+            },
+            ERROR => Output::ERROR {
+                description: content,
+            },
+            ANSWER => Output::ANSWER {
+                content_raw: content,
+                narsese,
+            },
+            ACHIEVED => Output::ACHIEVED {
+                content_raw: content,
+                narsese,
+            },
+            EXE => Output::EXE {
+                content_raw: content,
+                operation: parse_operation(captures)?,
+            },
+            INFO => Output::INFO { message: content },
+            COMMENT => Output::COMMENT { content },
+            TERMINATED => Output::TERMINATED {
+                description: content,
+            },
+            OTHER => Output::OTHER { content },
+            _ => Output::UNCLASSIFIED {
+                r#type: self.output_type.clone(),
+                content,
+                narsese,
+            },
+        };
+        Ok(output)
+    }
+}
+
+/// 从匹配结果中解析NARS操作
+/// * 🚩操作名：捕获组`operator`，去掉可能的`^`前缀
+/// * 🚩操作参数：捕获组`params`，作为乘积`(*, 参数)`的组分解析
+///   * 🎯正确处理参数中的复合词项，如`{SELF}, (/,^left,a,_)`
+fn parse_operation(captures: &Captures) -> Result<Operation> {
+    let operator_name = captures
+        .name("operator")
+        .map_or("", |m| m.as_str())
+        .trim()
+        .trim_start_matches('^');
+    let params = match captures.name("params").map(|m| m.as_str().trim()) {
+        None | Some("") => vec![],
+        Some(params) => parse_params(params)?,
+    };
+    Ok(Operation::new(operator_name, params))
+}
+
+/// 解析以逗号分隔的词项
+fn parse_params(params: &str) -> Result<Vec<Term>> {
+    let parsed = FORMAT_ASCII
+        .parse(&format!("(*, {params})"))
+        .map_err(|e| anyhow!(messages::custom_translator_invalid_params(params, &e)))?;
+    match parsed {
+        Narsese::Term(Term::Compound { terms, .. }) => Ok(terms),
+        _ => Err(anyhow!(messages::custom_translator_invalid_params(
+            params,
+            &"not a term list"
+        ))),
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::LaunchConfigTranslators;
+    use nar_dev_utils::asserts;
+    use navm::cmd::Cmd;
+
+    /// 测试用的自定义转译器配置
+    /// * 🚩以HJSON声明，同时检验配置的反序列化
+    fn config() -> LaunchConfigCustomTranslator {
+        let hjson = r#"{
+            input: {
+                NSE: "{tail}"
+                CYC: ":c {tail}"
+                VOL: "*{head} {tail}"
+            }
+            output: [
+                { pattern: "^Answer: (?<narsese>.+?) %", type: "ANSWER" }
+                { pattern: "^EXE: \\^(?<operator>\\w+)\\((?<params>.*)\\)$", type: "exe" }
+                { pattern: "^INFO: (?<content>.*)$", type: "INFO" }
+                { pattern: "^ANTICIPATE: (?<narsese>.+)$", type: "ANTICIPATE" }
+            ]
+        }"#;
+        let translators = deser_hjson::from_str::<LaunchConfigTranslators>(hjson).unwrap();
+        match translators {
+            LaunchConfigTranslators::Custom(config) => config,
+            other => panic!("未识别为自定义转译器：{other:?}"),
+        }
+    }
+
+    /// 测试/输入模板
+    #[test]
+    fn test_input() {
+        let translators = custom_translators(&config()).unwrap();
+        let input = |cmd| (translators.input_translator)(cmd);
+        asserts! {
+            input(Cmd::CYC(5)).unwrap() => ":c 5"
+            input(Cmd::VOL(0)).unwrap() => "*VOL 0"
+            input(Cmd::parse("NSE <A --> B>.").unwrap()).unwrap() => "<A --> B>."
+            // 没有模板⇒不支持
+            input(Cmd::REM { comment: "c".into() }).is_err() => true
+        }
+    }
+
+    /// 测试/输出规则
+    #[test]
+    fn test_output() {
+        let translators = custom_translators(&config()).unwrap();
+        let output = |line: &str| (translators.output_translator)(line.into()).unwrap();
+        let answer = output("Answer: <A --> C>. %1.00;0.81%");
+        let exe = output("EXE: ^left({SELF}, (/, ^left, a, _))");
+        let exe_no_params = output("EXE: ^wait()");
+        asserts! {
+            answer.type_name() => "ANSWER"
+            answer.get_narsese().is_some() => true
+            answer.get_content() => "Answer: <A --> C>. %1.00;0.81%"
+            exe.type_name() => "EXE"
+            exe.get_operation().map(|op| op.operator_name.as_str()) => Some("left")
+            exe.get_operation().map(|op| op.params.len()) => Some(2)
+            exe_no_params.get_operation().map(|op| op.params.len()) => Some(0)
+            // 捕获组`content`
+            output("INFO: hello").get_content() => "hello"
+            // 行尾换行符不影响`$`锚点
+            output("INFO: hello\r\n").get_content() => "hello"
+            // 非内置类型⇒保留类型名
+            output("ANTICIPATE: <A --> B>").type_name() => "ANTICIPATE"
+            output("ANTICIPATE: <A --> B>").get_narsese().is_some() => true
+            // 都不匹配⇒`OTHER`
+            output("whatever").type_name() => "OTHER"
+        }
+    }
+
+    /// 测试/无效规则在构建时报错
+    #[test]
+    fn test_invalid_rules() {
+        let with_rule = |pattern: &str, output_type: &str| LaunchConfigCustomTranslator {
+            output: vec![LaunchConfigOutputRule {
+                pattern: pattern.into(),
+                output_type: output_type.into(),
+            }],
+            ..Default::default()
+        };
+        asserts! {
+            custom_translators(&with_rule("(", "OUT")).is_err() => true
+            custom_translators(&with_rule("^EXE: (.*)$", "EXE")).is_err() => true
+            custom_translators(&with_rule("^EXE: (?<operator>.*)$", "EXE")).is_ok() => true
+        }
+    }
+}
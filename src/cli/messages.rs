@@ -0,0 +1,635 @@
+//! CLI消息目录
+//! * 🎯集中存放CLI中所有面向用户的提示、日志与报错信息
+//! * 🚩按模块分组，每条消息提供中英两种模板
+//!   * 📌当前语言由[`crate::support::i18n`]管理
+//! * 📝调用处形如`println_cli!([Info] "{}", messages::profile_selected(name))`
+
+use crate::message_catalog;
+use std::{
+    fmt::{Debug, Display},
+    path::Path,
+};
+
+// 主程序 //
+message_catalog! {
+    cwd_unavailable(e: &dyn Display) {
+        zh: "无法获取当前工作目录：{e}",
+        en: "Failed to get the current working directory: {e}",
+    }
+    provenance_header() {
+        zh: "各配置项的来源：",
+        en: "Sources of config fields:",
+    }
+    starting_soon() {
+        zh: "配置加载完毕！程序将在1s后启动。。。",
+        en: "Config loaded! Starting in 1s...",
+    }
+    clear_screen_failed(e: &dyn Display) {
+        zh: "清屏失败：{e}",
+        en: "Failed to clear the screen: {e}",
+    }
+    launch_failed(e: &dyn Display) {
+        zh: "NARS运行时启动错误：{e}",
+        en: "Failed to launch the NARS runtime: {e}",
+    }
+    exiting_in(seconds: u64) {
+        zh: "程序将在 {seconds} 秒后自动退出。。。",
+        en: "Exiting in {seconds} seconds...",
+    }
+    config_incomplete() {
+        zh: "配置中缺少启动命令或转译器：非交互式子命令不会询问用户，请用`-c`指定配置文件",
+        en: "The config lacks a command or translators: non-interactive subcommands do not prompt, please pass a config file with `-c`",
+    }
+    serve_without_websocket() {
+        zh: "配置中缺少Websocket参数，无法启动服务",
+        en: "The config has no Websocket settings, cannot serve",
+    }
+    search_dir_unknown() {
+        zh: "无法确定搜索的起始目录，请显式指定",
+        en: "Cannot determine where to start searching, please specify a directory",
+    }
+    terminal_color_failed() {
+        zh: "无法启动终端彩色显示。。",
+        en: "Failed to enable colored terminal output.",
+    }
+}
+
+// 参数解析与配置加载 //
+message_catalog! {
+    output_format_unsupported(s: &str) {
+        zh: "不支持的输出格式 {s:?}：应为`text`或`jsonl`",
+        en: "Unsupported output format {s:?}: expected `text` or `jsonl`",
+    }
+    profile_selected(name: &str) {
+        zh: "已选中配置档案「{name}」",
+        en: "Selected config profile \"{name}\"",
+    }
+    override_failed(config_override: &dyn Display, e: &dyn Display) {
+        zh: "无法应用配置覆盖「{config_override}」：{e}",
+        en: "Failed to apply config override \"{config_override}\": {e}",
+    }
+    no_config_loaded() {
+        zh: "未加载任何外部配置",
+        en: "No external config loaded",
+    }
+    config_loaded(json: &str) {
+        zh: "外部配置已加载：{json}",
+        en: "External config loaded: {json}",
+    }
+    config_display_failed(e: &dyn Display) {
+        zh: "展示加载的配置时出现预期之外的错误: {e}",
+        en: "Unexpected error while displaying the loaded config: {e}",
+    }
+    provenance_runtime_unavailable(e: &dyn Display) {
+        zh: "# 无法转换为运行时配置，仅展示启动配置：{e}",
+        en: "# Cannot convert to a runtime config, showing the launch config only: {e}",
+    }
+    config_not_found(path: &Path) {
+        zh: "未在路径 {path:?} 找到外部配置，返回空配置……",
+        en: "No external config found at {path:?}, using an empty config...",
+    }
+    config_read_unexpected(e: &dyn Display) {
+        zh: "读取外部配置时出现预期之外的错误: {e}",
+        en: "Unexpected error while reading the external config: {e}",
+    }
+    config_syntax_error(e: &dyn Display) {
+        zh: "外部配置文件格式错误，使用空配置……：{e}",
+        en: "Malformed external config file, using an empty config...: {e}",
+    }
+    config_io_error(e: &dyn Display) {
+        zh: "外部配置文件读取错误，使用空配置……：{e}",
+        en: "Failed to read the external config file, using an empty config...: {e}",
+    }
+    config_parse_unexpected(e: &dyn Display) {
+        zh: "解析外部配置时出现预期之外的错误: {e}",
+        en: "Unexpected error while parsing the external config: {e}",
+    }
+    toml_syntax_error(e: &dyn Display) {
+        zh: "外部TOML配置文件格式错误，使用空配置……\n{e}",
+        en: "Malformed external TOML config file, using an empty config...\n{e}",
+    }
+    yaml_syntax_error(line: usize, column: usize, e: &dyn Display) {
+        zh: "外部YAML配置文件格式错误（第{line}行第{column}列），使用空配置……：{e}",
+        en: "Malformed external YAML config file (line {line}, column {column}), using an empty config...: {e}",
+    }
+    yaml_parse_unexpected(e: &dyn Display) {
+        zh: "解析外部YAML配置时出现预期之外的错误: {e}",
+        en: "Unexpected error while parsing the external YAML config: {e}",
+    }
+    config_load_unexpected(e: &dyn Display) {
+        zh: "加载外部配置时出现预期之外的错误: {e}",
+        en: "Unexpected error while loading the external config: {e}",
+    }
+    circular_extends(chain: &str) {
+        zh: "配置文件存在循环继承：{chain}",
+        en: "Circular inheritance between config files: {chain}",
+    }
+    extends_failed(path: &Path, parent: &Path) {
+        zh: "加载 {path:?} 所继承的配置 {parent:?} 失败",
+        en: "Failed to load config {parent:?} extended by {path:?}",
+    }
+    invalid_root_path() {
+        zh: "无效的根路径！",
+        en: "Invalid root path!",
+    }
+}
+
+// 启动配置 //
+message_catalog! {
+    missing_translators() {
+        zh: "启动配置缺少转译器",
+        en: "The launch config has no translators",
+    }
+    missing_command() {
+        zh: "启动配置缺少启动命令",
+        en: "The launch config has no command",
+    }
+    missing_cmd() {
+        zh: "启动命令缺少`cmd`",
+        en: "The launch command has no `cmd`",
+    }
+    invalid_pipe_grace_period(seconds: f64) {
+        zh: "无效的管道输入宽限期 {seconds}：须为有限的非负数",
+        en: "Invalid pipe grace period {seconds}: must be finite and non-negative",
+    }
+    invalid_glob(pattern: &str, e: &dyn Display) {
+        zh: "预置NAL通配符「{pattern}」无效：{e}",
+        en: "Invalid prelude NAL glob \"{pattern}\": {e}",
+    }
+    glob_no_match(pattern: &str) {
+        zh: "预置NAL通配符「{pattern}」未匹配到任何文件",
+        en: "Prelude NAL glob \"{pattern}\" matched no files",
+    }
+    glob_not_expanded(pattern: &str) {
+        zh: "预置NAL通配符「{pattern}」未展开",
+        en: "Prelude NAL glob \"{pattern}\" was not expanded",
+    }
+    no_profiles(name: &str) {
+        zh: "配置中不存在档案「{name}」：配置中没有任何档案",
+        en: "No profile \"{name}\" in the config: the config has no profiles",
+    }
+    unknown_profile(name: &str, available: &str) {
+        zh: "配置中不存在档案「{name}」，可用的档案：{available}",
+        en: "No profile \"{name}\" in the config; available profiles: {available}",
+    }
+    unresolved_vars(vars: &str) {
+        zh: "配置中存在无法解析的变量：{vars}",
+        en: "Unresolved variables in the config: {vars}",
+    }
+}
+
+// 配置覆盖 //
+message_catalog! {
+    empty_key_path() {
+        zh: "键路径不能为空",
+        en: "The key path must not be empty",
+    }
+    parent_not_object(key: &str) {
+        zh: "配置项「{key}」的上级不是对象",
+        en: "The parent of config field \"{key}\" is not an object",
+    }
+    override_missing_eq(s: &str) {
+        zh: "配置覆盖「{s}」缺少「=」：应为「键路径=值」的形式",
+        en: "Config override \"{s}\" lacks \"=\": expected \"key.path=value\"",
+    }
+    override_invalid_key_path(s: &str) {
+        zh: "配置覆盖「{s}」的键路径无效",
+        en: "Config override \"{s}\" has an invalid key path",
+    }
+    field_type_error(path: &str, e: &dyn Display) {
+        zh: "配置项「{path}」类型错误：{e}",
+        en: "Wrong type for config field \"{path}\": {e}",
+    }
+    unknown_fields(keys: &str) {
+        zh: "未知的配置项：{keys}",
+        en: "Unknown config fields: {keys}",
+    }
+}
+
+// 配置搜索与用户输入 //
+message_catalog! {
+    config_input_hint() {
+        zh: "现在需要输入配置文件位置。\n    示例：「BabelNAR.launch.json」\n    若搜索到已有配置文件，可输入其在方括号内的索引，如「0」\n    若其含有命名档案，可输入「索引:档案名」，如「0:strict」\n    可直接按下回车，以查看详细搜索过程",
+        en: "Please enter the location of a config file.\n    Example: \"BabelNAR.launch.json\"\n    To use a config found by the search, enter its index in brackets, e.g. \"0\"\n    To use a named profile of it, enter \"index:profile\", e.g. \"0:strict\"\n    Press Enter directly to see the detailed search process",
+    }
+    config_input_prompt() {
+        zh: "配置文件位置: ",
+        en: "Config file location: ",
+    }
+    invalid_input(e: &dyn Display) {
+        zh: "输入无效：{e}",
+        en: "Invalid input: {e}",
+    }
+    index_out_of_range(i: usize) {
+        zh: "索引「{i}」超出范围",
+        en: "Index \"{i}\" is out of range",
+    }
+    searched_config_selected(i: usize, searched: &dyn Debug) {
+        zh: "已选择搜索到的第「{i}」个配置：{searched:?}",
+        en: "Selected searched config #{i}: {searched:?}",
+    }
+    searched_profile_selected(i: usize, name: &str) {
+        zh: "已选择搜索到的第「{i}」个配置中的档案「{name}」",
+        en: "Selected profile \"{name}\" of searched config #{i}",
+    }
+    file_not_found(path: &Path) {
+        zh: "文件「{path:?}」不存在",
+        en: "File {path:?} does not exist",
+    }
+    config_file_read_failed(path: &Path, e: &dyn Display) {
+        zh: "配置文件「{path:?}」读取失败：{e}",
+        en: "Failed to read config file {path:?}: {e}",
+    }
+    searching(path: &Path) {
+        zh: "正在搜索 {path:?}",
+        en: "Searching {path:?}",
+    }
+    config_found(config: &dyn Debug) {
+        zh: "搜索到配置文件：{config:?}",
+        en: "Found config file: {config:?}",
+    }
+    search_summary(c: usize, c_valid: usize, c_non_empty: usize) {
+        zh: "一共搜索了{c}个文件，其中 {c_valid} 个文件符合条件，{c_non_empty} 个非空",
+        en: "Searched {c} files, {c_valid} of them valid and {c_non_empty} non-empty",
+    }
+    search_budget_exhausted() {
+        zh: "已达到搜索的路径数或时间上限，搜索提前结束。",
+        en: "Search stopped early: the path count or time limit was reached.",
+    }
+    search_cache_save_failed(e: &dyn Display) {
+        zh: "无法保存搜索索引缓存：{e}",
+        en: "Failed to save the search index cache: {e}",
+    }
+    no_valid_config() {
+        zh: "未搜索到任何有效配置。",
+        en: "No valid config found.",
+    }
+    valid_configs_header() {
+        zh: "已搜索到以下有效配置：",
+        en: "Found the following valid configs:",
+    }
+    translator_not_found(name: &str, hint: &str) {
+        zh: "未找到转译器「{name}」：{hint}",
+        en: "Translator {name:?} not found: {hint}",
+    }
+    translator_did_you_mean(names: &str) {
+        zh: "你是否想要：{names}？",
+        en: "did you mean: {names}?",
+    }
+    translator_list_hint() {
+        zh: "可用`translators`子命令列出所有转译器",
+        en: "run the `translators` subcommand to list all translators",
+    }
+    translator_ambiguous(name: &str, names: &str) {
+        zh: "转译器名「{name}」有歧义，同时匹配：{names}",
+        en: "Translator name {name:?} is ambiguous, matching: {names}",
+    }
+}
+
+// 自定义转译器 //
+message_catalog! {
+    custom_translator_invalid_regex(pattern: &str, e: &dyn Display) {
+        zh: "自定义转译器的输出规则中，正则表达式无效：{pattern:?}：{e}",
+        en: "Invalid regex in an output rule of the custom translator: {pattern:?}: {e}",
+    }
+    custom_translator_no_operator(pattern: &str) {
+        zh: "自定义转译器中，`EXE`输出规则须有命名捕获组`operator`：{pattern:?}",
+        en: "An `EXE` output rule of the custom translator needs a named capture group `operator`: {pattern:?}",
+    }
+    custom_translator_invalid_params(params: &str, e: &dyn Display) {
+        zh: "无法将{params:?}解析为操作参数：{e}",
+        en: "Failed to parse {params:?} as operation parameters: {e}",
+    }
+}
+
+// CIN发现 //
+message_catalog! {
+    invalid_start_dir(start: &Path) {
+        zh: "起始目录无效：{start:?}",
+        en: "Invalid start directory: {start:?}",
+    }
+    cin_search_summary(n_found: usize) {
+        zh: "已发现 {n_found} 个CIN：",
+        en: "Found {n_found} CIN(s):",
+    }
+    no_cin_found() {
+        zh: "未发现任何CIN。",
+        en: "No CIN found.",
+    }
+    cin_select_hint() {
+        zh: "请输入要生成启动配置的CIN索引（留空则退出）",
+        en: "Enter the index of the CIN to generate a launch config for (empty to quit)",
+    }
+    cin_select_prompt() {
+        zh: "CIN索引: ",
+        en: "CIN index: ",
+    }
+    cin_config_generated() {
+        zh: "由`search-cin`自动生成",
+        en: "Generated by `search-cin`",
+    }
+    cin_config_written(path: &Path) {
+        zh: "启动配置已写入 {path:?}",
+        en: "Launch config written to {path:?}",
+    }
+}
+
+// 配置子命令 //
+message_catalog! {
+    schema_written(path: &Path) {
+        zh: "JSON Schema已写入 {path:?}",
+        en: "JSON Schema written to {path:?}",
+    }
+    config_file_unreadable(file: &Path, e: &dyn Display) {
+        zh: "无法读取配置文件 {file:?}：{e}",
+        en: "Failed to read config file {file:?}: {e}",
+    }
+    config_file_valid(file: &Path) {
+        zh: "配置文件 {file:?} 校验通过",
+        en: "Config file {file:?} is valid",
+    }
+    validation_failed(n: usize) {
+        zh: "配置校验失败：共发现 {n} 处问题",
+        en: "Config validation failed: {n} issue(s) found",
+    }
+}
+
+// 配置热重载 //
+message_catalog! {
+    config_unchanged() {
+        zh: "配置文件已变更，但运行时配置未变化",
+        en: "Config files changed, but the runtime config did not",
+    }
+    config_change(change: &dyn Display) {
+        zh: "配置变更：{change}",
+        en: "Config changed: {change}",
+    }
+    websocket_needs_relaunch() {
+        zh: "Websocket配置的变更需重新启动CLI方可生效",
+        en: "Websocket config changes take effect only after restarting the CLI",
+    }
+    restarting_vm() {
+        zh: "启动命令/转译器已变更，正在重启虚拟机。。。",
+        en: "Command/translators changed, restarting the VM...",
+    }
+    vm_restarted() {
+        zh: "虚拟机已按新配置重启",
+        en: "VM restarted with the new config",
+    }
+    vm_restart_failed(e: &dyn Display) {
+        zh: "按新配置重启虚拟机失败：{e}",
+        en: "Failed to restart the VM with the new config: {e}",
+    }
+    prelude_rerun_failed(e: &dyn Display) {
+        zh: "重新执行预置NAL时发生错误：{e}",
+        en: "Error while re-running the prelude NAL: {e}",
+    }
+    watching_header() {
+        zh: "正在监视配置文件：",
+        en: "Watching config files:",
+    }
+    config_file_changed(path: &dyn Display) {
+        zh: "检测到配置文件变更：{path}",
+        en: "Config file changed: {path}",
+    }
+    reload_failed(e: &dyn Display) {
+        zh: "重新加载配置失败，将保留原配置：{e}",
+        en: "Failed to reload the config, keeping the current one: {e}",
+    }
+    nothing_to_watch() {
+        zh: "没有可监视的配置文件，已忽略`--watch`",
+        en: "No config file to watch, ignoring `--watch`",
+    }
+}
+
+// 运行时管理 //
+message_catalog! {
+    prelude_failed(e: &dyn Display) {
+        zh: "预置NAL输入发生错误：{e}",
+        en: "Error in the prelude NAL input: {e}",
+    }
+    prelude_summary(n_passed: usize, n_total: usize) {
+        zh: "预置NAL执行完毕：{n_passed}/{n_total} 通过",
+        en: "Prelude NAL finished: {n_passed}/{n_total} passed",
+    }
+    prelude_sources_failed(n_failed: usize) {
+        zh: "{n_failed}个预置NAL来源未通过",
+        en: "{n_failed} prelude NAL source(s) failed",
+    }
+    prelude_file_read_failed(path: &Path, e: &dyn Display) {
+        zh: "读取预置NAL文件 {path:?} 发生错误：{e}",
+        en: "Failed to read prelude NAL file {path:?}: {e}",
+    }
+    nal_inputs_failed(n_failed: usize) {
+        zh: "{n_failed}条NAL输入未通过",
+        en: "{n_failed} NAL input(s) failed",
+    }
+    runtime_terminated(e: &dyn Display) {
+        zh: "NAVM运行时已终止：{e}",
+        en: "The NAVM runtime has terminated: {e}",
+    }
+    fetch_output_failed(e: &dyn Display) {
+        zh: "尝试拉取NAVM运行时输出时发生错误：{e}",
+        en: "Error while fetching output from the NAVM runtime: {e}",
+    }
+    cache_output_failed(e: &dyn Display) {
+        zh: "缓存NAVM运行时输出时发生错误：{e}",
+        en: "Error while caching output from the NAVM runtime: {e}",
+    }
+    runtime_lock_failed(e: &dyn Debug) {
+        zh: "获取运行时引用时发生错误：{e:?}",
+        en: "Error while locking the runtime: {e:?}",
+    }
+    output_cache_lock_failed(e: &dyn Display) {
+        zh: "获取NAVM输出缓存时发生错误：{e}",
+        en: "Error while locking the NAVM output cache: {e}",
+    }
+    pipe_eof(seconds: f64) {
+        zh: "标准输入已结束，将在CIN空闲 {seconds}s 后退出",
+        en: "Standard input ended, exiting once the CIN has been idle for {seconds}s",
+    }
+    input_failed(e: &dyn Display) {
+        zh: "输入过程中发生错误：{e}",
+        en: "Error while handling input: {e}",
+    }
+    cmd_parse_failed(e: &dyn Display) {
+        zh: "NAVM指令解析错误：{e}",
+        en: "Failed to parse NAVM command: {e}",
+    }
+    cmd_exec_failed(e: &dyn Display) {
+        zh: "NAVM指令执行错误：{e}",
+        en: "Failed to execute NAVM command: {e}",
+    }
+    nal_parse_failed(e: &dyn Display) {
+        zh: "解析NAL输入时发生错误：{e}",
+        en: "Error while parsing NAL input: {e}",
+    }
+    nal_put_failed(nal: &dyn Debug, e: &dyn Display) {
+        zh: "置入NAL输入「{nal:?}」时发生错误：{e}",
+        en: "Error while putting NAL input {nal:?}: {e}",
+    }
+    runtime_unlock_failed(e: &dyn Debug) {
+        zh: "runtime Mutex解锁失败：{e:?}",
+        en: "Failed to unlock the runtime mutex: {e:?}",
+    }
+    runtime_error(e: &dyn Display) {
+        zh: "运行时发生错误：{e}",
+        en: "Runtime error: {e}",
+    }
+    restarting_in(seconds: u64) {
+        zh: "程序将在 {seconds} 秒后自动重启。。。",
+        en: "Restarting in {seconds} seconds...",
+    }
+    restart_failed(e: &dyn Display) {
+        zh: "重启失败：{e}",
+        en: "Restart failed: {e}",
+    }
+    runtime_error_restart_failed(e: &dyn Display) {
+        zh: "NAVM运行时发生错误，且重启失败：{e}",
+        en: "The NAVM runtime failed and could not be restarted: {e}",
+    }
+    old_runtime_terminate_failed(e: &dyn Display) {
+        zh: "终止旧的虚拟机失败，仍将启动新的虚拟机：{e}",
+        en: "Failed to terminate the old VM, launching the new one anyway: {e}",
+    }
+    old_config_relaunched() {
+        zh: "已按原配置重新启动虚拟机",
+        en: "Relaunched the VM with the previous config",
+    }
+    old_config_relaunch_failed(e: &dyn Display) {
+        zh: "按原配置重新启动虚拟机亦失败：{e}",
+        en: "Relaunching the VM with the previous config failed as well: {e}",
+    }
+}
+
+// NAL测试 //
+message_catalog! {
+    no_nal_in_dir(path: &Path) {
+        zh: "目录 {path:?} 中没有任何`.nal`文件",
+        en: "No `.nal` files in directory {path:?}",
+    }
+    no_nal_sources() {
+        zh: "没有要执行的NAL：请指定NAL文件/目录，或在配置中设置`preludeNAL`",
+        en: "Nothing to run: pass NAL files/directories, or set `preludeNAL` in the config",
+    }
+    test_running(source: &dyn Display) {
+        zh: "正在执行 {source}",
+        en: "Running {source}",
+    }
+    test_summary_header() {
+        zh: "测试结果：",
+        en: "Test results:",
+    }
+    test_summary_total(n_total: usize, n_passed: usize, n_failed: usize, duration: &str) {
+        zh: "共 {n_total} 个NAL来源：{n_passed} 通过，{n_failed} 失败，用时 {duration}",
+        en: "{n_total} NAL source(s): {n_passed} passed, {n_failed} failed, in {duration}",
+    }
+    tests_failed(n_failed: usize, n_total: usize) {
+        zh: "{n_failed}/{n_total} 个NAL来源未通过",
+        en: "{n_failed}/{n_total} NAL source(s) failed",
+    }
+    test_report_format_unsupported(s: &str) {
+        zh: "不支持的测试报告格式 {s:?}：应为`junit`、`tap`或`json`",
+        en: "Unsupported test report format {s:?}: expected `junit`, `tap` or `json`",
+    }
+    test_report_written(format: &dyn Display, path: &Path) {
+        zh: "{format}测试报告已写入 {path:?}",
+        en: "{format} test report written to {path:?}",
+    }
+    bench_format_unsupported(s: &str) {
+        zh: "不支持的基准测试输出格式 {s:?}：应为`csv`或`json`",
+        en: "Unsupported benchmark output format {s:?}: expected `csv` or `json`",
+    }
+    bench_running(source: &dyn Display, i: usize, runs: usize) {
+        zh: "正在执行 {source}（第 {i}/{runs} 次）",
+        en: "Running {source} ({i}/{runs})",
+    }
+    bench_written(format: &dyn Display, path: &Path) {
+        zh: "{format}基准测试结果已写入 {path:?}",
+        en: "{format} benchmark results written to {path:?}",
+    }
+    matrix_child_spawn_failed(e: &dyn Display) {
+        zh: "无法启动子进程：{e}",
+        en: "Failed to spawn the child process: {e}",
+    }
+    matrix_child_no_report(status: &dyn Display, reason: &str) {
+        zh: "子进程未给出测试报告（{status}）：{reason}",
+        en: "The child process produced no test report ({status}): {reason}",
+    }
+    matrix_cell_not_run() {
+        zh: "未执行",
+        en: "Not run",
+    }
+    matrix_legend() {
+        zh: "单元格：✅通过/❌未通过 通过的预期数/预期总数 (推理步数)；⚠️未能执行",
+        en: "Cells: ✅ passed / ❌ failed, passed/total expectations (cycles); ⚠️ could not run",
+    }
+}
+
+// Websocket服务器 //
+message_catalog! {
+    ws_server_started(address: &dyn Debug) {
+        zh: "Websocket服务器已在 {address:?} 启动",
+        en: "Websocket server started at {address:?}",
+    }
+    ws_register_listener_failed(e: &dyn Display) {
+        zh: "无法为服务端注册侦听器：{e}",
+        en: "Failed to register the server listener: {e}",
+    }
+    ws_shutdown() {
+        zh: "Websocket连接已关停",
+        en: "Websocket connection shut down",
+    }
+    ws_opened(addr: &dyn Display) {
+        zh: "Websocket连接已打开：{addr}",
+        en: "Websocket connection opened: {addr}",
+    }
+    ws_message(msg: &dyn Display) {
+        zh: "Websocket收到消息：{msg}",
+        en: "Websocket message received: {msg}",
+    }
+    ws_runtime_lock_failed(e: &dyn Display) {
+        zh: "在Websocket连接中获取运行时失败：{e}",
+        en: "Failed to lock the runtime in the Websocket connection: {e}",
+    }
+    ws_output_cache_lock_failed(e: &dyn Display) {
+        zh: "在Websocket连接中获取输出缓存失败：{e}",
+        en: "Failed to lock the output cache in the Websocket connection: {e}",
+    }
+    ws_input_failed(msg: &dyn Display, e: &dyn Display) {
+        zh: "在Websocket连接中输入「{msg}」时发生错误：{e}",
+        en: "Error while inputting \"{msg}\" in the Websocket connection: {e}",
+    }
+    ws_closed(code: &dyn Debug, reason: &str) {
+        zh: "Websocket连接关闭（退出码：{code:?}；原因：「{reason}」）",
+        en: "Websocket connection closed (code: {code:?}; reason: \"{reason}\")",
+    }
+    ws_connection_error(err: &dyn Debug) {
+        zh: "连接发生错误：{err:?}",
+        en: "Connection error: {err:?}",
+    }
+    ws_timeout(event: &dyn Debug) {
+        zh: "连接超时：{event:?}",
+        en: "Connection timed out: {event:?}",
+    }
+    ws_broadcast_failed(e: &dyn Display) {
+        zh: "广播消息失败：{e}",
+        en: "Failed to broadcast message: {e}",
+    }
+    ws_broadcast_to_senders_failed(e: &dyn Debug) {
+        zh: "Websocket回传广播到发送者时出现错误：{e:?}",
+        en: "Error while broadcasting back to Websocket senders: {e:?}",
+    }
+    ws_connection_established(id: &dyn Display) {
+        zh: "Websocket连接已在id {id} 处建立",
+        en: "Websocket connection established with id {id}",
+    }
+    ws_server_shutdown() {
+        zh: "Websocket服务器已关停",
+        en: "Websocket server shut down",
+    }
+    ws_client_disconnected(id: &dyn Display) {
+        zh: "与id为 {id} 的客户端断开连接！",
+        en: "Disconnected from the client with id {id}!",
+    }
+}
@@ -1,10 +1,13 @@
 //! 原BabelNAR.rs `src/bin/babelnar_cli/*.rs`
 //! * 🚩【2024-09-12 17:41:35】现在统一放置在`src/cli`下
 
-use crate::{eprintln_cli, println_cli};
+use crate::{
+    println_cli,
+    support::{i18n::set_locale, io::output_print::set_output_format},
+};
 use anyhow::Result;
 use clap::Parser;
-use std::{io::Result as IoResult, path::PathBuf, thread::sleep, time::Duration};
+use std::{io::Result as IoResult, path::PathBuf};
 
 // 启动参数
 mod vm_config;
@@ -14,10 +17,38 @@ pub use vm_config::*;
 mod arg_parse;
 pub use arg_parse::*;
 
+// 配置变量插值
+mod config_interpolate;
+pub use config_interpolate::*;
+
+// 命令行中的配置覆盖
+mod config_override;
+pub use config_override::*;
+
+// 配置项的来源追踪
+mod config_provenance;
+pub use config_provenance::*;
+
+// 配置Schema生成与校验
+mod config_validate;
+pub use config_validate::*;
+
+// 搜索索引缓存
+mod search_cache;
+pub use search_cache::*;
+
 // 配置（自动）搜索
 mod config_search;
 pub use config_search::*;
 
+// CIN自动发现
+mod cin_discover;
+pub use cin_discover::*;
+
+// 自定义转译器
+mod custom_translator;
+pub use custom_translator::*;
+
 // 从配置启动
 mod config_launcher;
 pub use config_launcher::*;
@@ -26,6 +57,33 @@ pub use config_launcher::*;
 mod runtime_manage;
 pub use runtime_manage::*;
 
+// 配置热重载
+mod config_watch;
+pub use config_watch::*;
+
+// 无交互的NAL测试执行
+mod test_runner;
+pub use test_runner::*;
+
+// NAL基准测试
+mod test_bench;
+pub use test_bench::*;
+
+// 跨CIN的测试矩阵
+mod test_matrix;
+pub use test_matrix::*;
+
+// NAL测试报告的导出
+mod test_report;
+pub use test_report::*;
+
+// 子命令
+mod commands;
+pub use commands::*;
+
+// 消息目录
+pub mod messages;
+
 // Websocket服务端
 mod websocket_server;
 pub use websocket_server::*;
@@ -36,7 +94,7 @@ pub use websocket_server::*;
 pub fn main_args(cwd: IoResult<PathBuf>, args: impl Iterator<Item = String>) -> Result<()> {
     // 解包当前工作目录
     let cwd = cwd
-        .inspect_err(|e| println_cli!([Warn] "无法获取当前工作目录：{e}"))
+        .inspect_err(|e| println_cli!([Warn] "{}", messages::cwd_unavailable(e)))
         .ok();
 
     // 启用终端颜色（若可）
@@ -45,57 +103,55 @@ pub fn main_args(cwd: IoResult<PathBuf>, args: impl Iterator<Item = String>) ->
     // 解析命令行参数
     let args = CliArgs::parse_from(args);
 
-    // 读取配置 | with 默认配置文件
-    let mut config = load_config(&args);
-
-    // 是否向用户展示「详细信息」 | 用于等待、提示等
-    let user_verbose = config.user_input.is_none() || config.user_input.unwrap();
-
-    // 用户填充配置项 | 需要用户输入、工作路径（🎯自动搜索）
-    polyfill_config_from_user(&mut config, cwd);
-
-    // 清屏，预备启动
-    if !args.quick_start && user_verbose {
-        println_cli!([Info] "配置加载完毕！程序将在1s后启动。。。");
-        sleep(Duration::from_secs(1));
+    // 设置界面语言 | 配置尚未加载，先按命令行参数与环境变量
+    set_locale(args.locale(None));
+
+    // 设置输出格式
+    set_output_format(args.output_format);
+
+    // 按子命令分派 | 缺省⇒交互式启动
+    match &args.command {
+        None => launch_main(&args, cwd, LaunchMode::from_stdin(), &WalkArgs::default()),
+        Some(CliCommand::Run { walk }) => launch_main(&args, cwd, LaunchMode::from_stdin(), walk),
+        Some(CliCommand::Test {
+            paths,
+            report,
+            report_format,
+        }) => test_main(&args, paths, report.as_deref(), *report_format),
+        Some(CliCommand::Matrix { cins, paths, jobs }) => matrix_main(&args, cins, paths, *jobs),
+        Some(CliCommand::Bench {
+            paths,
+            runs,
+            output,
+            format,
+        }) => bench_main(&args, paths, *runs, output.as_deref(), *format),
+        Some(CliCommand::Serve) => launch_main(&args, cwd, LaunchMode::Serve, &WalkArgs::default()),
+        Some(CliCommand::Search { dir, walk }) => search_main(&args, cwd, dir.as_deref(), walk),
+        Some(CliCommand::SearchCin {
+            dir,
+            select,
+            output,
+            launch,
+            walk,
+        }) => search_cin_main(
+            cwd,
+            dir.as_deref(),
+            *select,
+            output.as_deref(),
+            *launch,
+            walk,
+        ),
+        Some(CliCommand::Config { command }) => config_main(command, &args),
+        Some(CliCommand::Translators) => translators_main(),
     }
-    let _ = clearscreen::clear().inspect_err(|e| eprintln_cli!([Warn] "清屏失败：{e}"));
-
-    // 从配置项启动 | 复制一个新配置，不会附带任何非基础类型开销
-    let (runtime, config) = match launch_by_config(config.clone()) {
-        // 启动成功⇒返回
-        Ok((r, c)) => (r, c),
-        // 启动失败⇒打印错误信息，等待并退出
-        Err(e) => {
-            println_cli!([Error] "NARS运行时启动错误：{e}");
-            // 空配置/启用用户输入⇒延时提示
-            if user_verbose {
-                println_cli!([Info] "程序将在 3 秒后自动退出。。。");
-                sleep(Duration::from_secs(3));
-            }
-            return Err(e);
-        }
-    };
-
-    // 运行时交互、管理
-    let manager = RuntimeManager::new(runtime, config.clone());
-    let result = loop_manage(manager, &config);
-
-    // 启用用户输入时延时提示
-    if config.user_input {
-        println_cli!([Info] "程序将在 5 秒后自动退出。。。");
-        sleep(Duration::from_secs(3));
-    }
-
-    // 返回结果
-    result
 }
 
 /// 启动终端颜色（Windows下）
 #[cfg(windows)]
 fn set_virtual_terminal() {
+    use crate::eprintln_cli;
     let _ = colored::control::set_virtual_terminal(true)
-        .inspect_err(|_| eprintln_cli!([Error] "无法启动终端彩色显示。。"));
+        .inspect_err(|_| eprintln_cli!([Error] "{}", messages::terminal_color_failed()));
 }
 
 /// 启动终端颜色（非Windows）
@@ -2,7 +2,10 @@
 
 use super::websocket_server::*;
 use crate::{
-    cli::{launch_by_runtime_config, InputMode, LaunchConfigPreludeNAL, RuntimeConfig},
+    cli::{
+        messages, relaunch_by_runtime_config, InputMode, LaunchConfigPreludeNAL, RuntimeConfig,
+        SharedConfig,
+    },
     eprintln_cli, if_let_err_eprintln_cli, println_cli,
     support::{
         error_handling_boost::error_anyhow,
@@ -14,7 +17,7 @@ use crate::{
 };
 use anyhow::{anyhow, Result};
 use babel_nar::{
-    runtimes::TranslateError,
+    runtimes::{CommandVmRuntime, TranslateError},
     test_tools::{nal_format::parse, put_nal, VmOutputCache},
 };
 use nar_dev_utils::{if_return, manipulate, pipe, ResultBoost};
@@ -24,6 +27,7 @@ use navm::{
 };
 use std::{
     fmt::Debug,
+    io::{stdin, IsTerminal},
     ops::ControlFlow::{self, Break, Continue},
     path::Path,
     sync::{Arc, Mutex},
@@ -35,7 +39,7 @@ use std::{
 /// * 🎯在一个数据结构中封装「虚拟机运行时」与「配置信息」
 /// * 📌只负责**单个运行时**的运行管理
 ///   * 🚩不负责「终止、重启运行时」等过程
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct RuntimeManager<R>
 where
     // ! 🚩【2024-04-02 14:51:23】需要`Send + Sync`进行多线程操作，需要`'static`保证生命周期
@@ -48,13 +52,37 @@ where
 
     /// 内部封装的「命令行参数」
     /// * 🎯用于从命令行中加载配置
-    /// * 🚩只读
-    pub(crate) config: Arc<RuntimeConfig>,
+    /// * 🚩多线程共享：各线程在使用时获取最新的快照
+    ///   * 🎯配置热重载：可在运行时原地替换
+    pub(crate) config: SharedConfig,
 
     /// 内部缓存的「NAVM输出」
     /// * 🎯用于NAL测试
     /// * 🚩多线程共享
     pub(crate) output_cache: ArcMutex<OutputCache>,
+
+    /// 已生成的「Websocket服务」「用户输入」子线程
+    /// * 🎯自动重启后不重复生成：子线程持有共享引用，重启后继续作用于新的虚拟机
+    /// * 🚩尚未生成⇒[`None`]
+    pub(crate) side_threads: Option<Vec<JoinHandle<Result<()>>>>,
+}
+
+/// 拷贝共享引用
+/// * 🚩手动实现：无需虚拟机运行时自身可拷贝
+/// * 🎯在其它线程中（如「配置热重载」）访问同一个虚拟机、配置与输出缓存
+impl<R> Clone for RuntimeManager<R>
+where
+    R: VmRuntime + Send + Sync + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            runtime: self.runtime.clone(),
+            config: self.config.clone(),
+            output_cache: self.output_cache.clone(),
+            // 子线程句柄不可拷贝，仅由原管理者等待
+            side_threads: None,
+        }
+    }
 }
 
 impl<R> RuntimeManager<R>
@@ -66,9 +94,10 @@ where
     pub fn new(runtime: R, config: RuntimeConfig) -> Self {
         Self {
             runtime: Arc::new(Mutex::new(runtime)),
-            config: Arc::new(config),
+            config: SharedConfig::new(config),
             // 创建的同时增加侦听器
             output_cache: Self::new_output_cache(),
+            side_threads: None,
         }
     }
 
@@ -128,26 +157,29 @@ where
             // 预置输入要求终止⇒终止
             Break(result) => return Ok(result),
             // 预置输入发生错误⇒展示 & 继续
-            Continue(Err(e)) => println_cli!([Error] "预置NAL输入发生错误：{e}"),
+            Continue(Err(e)) => println_cli!([Error] "{}", messages::prelude_failed(&e)),
             Continue(Ok(..)) => (),
         }
 
         // 虚拟机被终止 & 无用户输入 ⇒ 程序退出
         if let VmStatus::Terminated(..) = self.runtime.lock().transform_err(error_anyhow)?.status()
         {
-            if !self.config.user_input {
+            if !self.config.get().user_input {
                 // 直接返回，使程序退出
                 return Ok(Ok(()));
             }
         }
 
-        // 生成「Websocket服务」子线程（若有连接）
-        let thread_ws = self.try_spawn_ws_server()?;
-
-        // 生成「用户输入」子线程
-        let mut thread_input = None;
-        if self.config.user_input {
-            thread_input = Some(self.spawn_user_input()?);
+        // 生成「Websocket服务」「用户输入」子线程 | 仅在首次管理时生成，自动重启后沿用
+        if self.side_threads.is_none() {
+            let mut side_threads = vec![];
+            // 生成「Websocket服务」子线程（若有连接）
+            side_threads.extend(self.try_spawn_ws_server()?);
+            // 生成「用户输入」子线程
+            if self.config.get().user_input {
+                side_threads.push(self.spawn_user_input()?);
+            }
+            self.side_threads = Some(side_threads);
         }
 
         // ! 🚩不要在主线程开始用户输入
@@ -155,11 +187,8 @@ where
         // 等待子线程结束，并抛出其抛出的错误
         // ! 🚩【2024-04-02 15:09:32】错误处理交给外界
         thread_read.join().transform_err(error_anyhow)??;
-        if let Some(thread_ws) = thread_ws {
-            thread_ws.join().transform_err(error_anyhow)??
-        }
-        if let Some(thread_input) = thread_input {
-            thread_input.join().transform_err(error_anyhow)??;
+        for thread in self.side_threads.take().into_iter().flatten() {
+            thread.join().transform_err(error_anyhow)??;
         }
 
         // 正常运行结束
@@ -173,8 +202,10 @@ where
     ///   * 📌[`Break`] => 告知调用者「需要提前结束」
     ///     * 📌[`Break`]([`Ok`]) => 正常退出
     ///     * 📌[`Break`]([`Err`]) => 异常退出（报错）
+    /// * 🚩按顺序执行所有来源（通配符展开为多个文件），最后报告每个来源是否通过
     pub fn prelude_nal(&mut self) -> ControlFlow<Result<()>, Result<()>> {
-        let config = &*self.config;
+        let config = self.config.get();
+        let config = &*config;
 
         /// 尝试获取结果并返回
         /// * 🎯对错误返回`Break(Err(错误))`而非`Err(错误)`
@@ -197,50 +228,97 @@ where
         let runtime = &mut *try_break!(anyhow self.runtime.lock());
 
         // 仅在有预置NAL时开始
-        if let Some(prelude_nal) = &config.prelude_nal {
-            // 尝试获取输出缓冲区引用 | 仅有其它地方panic了才会停止
-            let output_cache =
-                &mut *try_break!(OutputCache::unlock_arc_mutex(&mut self.output_cache));
-
-            // 读取内容
-            let nal = match prelude_nal {
-                // 文件⇒尝试读取文件内容 | ⚠️此处创建了一个新值，所以要统一成`String`
-                LaunchConfigPreludeNAL::File(path) => {
-                    try_break!(std::fs::read_to_string(path) => e {
-                        println_cli!([Error] "读取预置NAL文件 {path:?} 发生错误：{e}");
-                        // 继续（用户输入/Websocket服务端）
-                        e.into()
-                    })
+        if_return! { config.prelude_nal.is_empty() => Continue(Ok(())) }
+
+        // 尝试获取输出缓冲区引用 | 仅有其它地方panic了才会停止
+        let output_cache = &mut *try_break!(OutputCache::unlock_arc_mutex(&mut self.output_cache));
+
+        // 逐个来源执行，并记录每个来源的结果
+        // * 🚩【2024-04-03 11:10:44】遇到错误，统一上报
+        //   * 根据「严格模式」判断要「继续」还是「终止」
+        //   * 📌严格模式下，首个失败的来源即终止后续执行
+        let mut summary = vec![];
+        'sources: for source in config.prelude_nal.iter() {
+            // 展开通配符
+            let sources = match source.expand() {
+                Ok(sources) => sources,
+                Err(e) => {
+                    println_cli!([Error] "{e}");
+                    summary.push((source.to_string(), Err(e)));
+                    match config.strict_mode {
+                        true => break 'sources,
+                        false => continue 'sources,
+                    }
                 }
-                // 纯文本⇒直接引入
-                LaunchConfigPreludeNAL::Text(nal) => nal.to_string(),
             };
-
-            // 获取「NAL执行路径」
-            // * 🎯在「预置NAL」中执行「保存文件」时，决定以哪个路径为「相对路径起点」
-            let nal_file_path = match prelude_nal {
-                // 文件⇒基于文件路径
-                LaunchConfigPreludeNAL::File(path) => {
-                    path.parent().unwrap_or(&self.config.config_path)
+            for source in sources {
+                let result = Self::prelude_nal_source(runtime, &source, output_cache, config);
+                let failed = result.is_err();
+                summary.push((source.to_string(), result));
+                if failed && config.strict_mode {
+                    break 'sources;
                 }
-                // 纯文本⇒直接引入
-                LaunchConfigPreludeNAL::Text(..) => &self.config.config_path,
-            };
+            }
+        }
 
-            // 输入NAL并处理
-            // * 🚩【2024-04-03 11:10:44】遇到错误，统一上报
-            //   * 根据「严格模式」判断要「继续」还是「终止」
-            let put_result =
-                Self::input_nal_to_vm(runtime, &nal, output_cache, config, nal_file_path);
-            match self.config.strict_mode {
-                false => Continue(put_result),
-                true => Break(put_result),
+        // 报告每个来源的结果
+        let n_failed = summary.iter().filter(|(_, result)| result.is_err()).count();
+        println_cli!([Info] "{}", messages::prelude_summary(summary.len() - n_failed, summary.len()));
+        for (source, result) in &summary {
+            match result {
+                Ok(..) => println_cli!([Info] "  ✅ {source}"),
+                Err(e) => println_cli!([Error] "  ❌ {source}：{e}"),
             }
         }
-        // 否则自动返回「正常」
-        else {
-            // 返回 | 正常继续
-            Continue(Ok(()))
+        let result = match n_failed {
+            0 => Ok(()),
+            _ => Err(anyhow!(messages::prelude_sources_failed(n_failed))),
+        };
+        match config.strict_mode {
+            false => Continue(result),
+            true => Break(result),
+        }
+    }
+
+    /// 执行单个（已展开的）预置NAL来源
+    /// * 🚩文件⇒以文件所在目录为「NAL执行路径」；纯文本⇒以配置文件所在目录为「NAL执行路径」
+    ///   * 🎯在「预置NAL」中执行「保存文件」时，决定以哪个路径为「相对路径起点」
+    /// * 📌有任何一条NAL输入失败（不论是否严格模式）⇒报错
+    fn prelude_nal_source(
+        runtime: &mut R,
+        source: &LaunchConfigPreludeNAL,
+        output_cache: &mut OutputCache,
+        config: &RuntimeConfig,
+    ) -> Result<()> {
+        // 读取内容与「NAL执行路径」
+        let (nal, nal_root_path) = match source {
+            // 文件⇒尝试读取文件内容 | ⚠️此处创建了一个新值，所以要统一成`String`
+            LaunchConfigPreludeNAL::File(path) => {
+                let nal = std::fs::read_to_string(path).inspect_err(
+                    |e| println_cli!([Error] "{}", messages::prelude_file_read_failed(path, &e)),
+                )?;
+                (nal, path.parent().unwrap_or(&config.config_path))
+            }
+            // 纯文本⇒直接引入
+            LaunchConfigPreludeNAL::Text(nal) => (nal.to_string(), config.config_path.as_path()),
+            // 通配符⇒应已展开
+            LaunchConfigPreludeNAL::Glob(pattern) => {
+                return Err(anyhow!(messages::glob_not_expanded(pattern)))
+            }
+        };
+        // 输入NAL，并统计失败的输入
+        let mut n_failed = 0;
+        Self::input_nal_to_vm_counting(
+            runtime,
+            &nal,
+            output_cache,
+            config,
+            nal_root_path,
+            &mut n_failed,
+        )?;
+        match n_failed {
+            0 => Ok(()),
+            _ => Err(anyhow!(messages::nal_inputs_failed(n_failed))),
         }
     }
 
@@ -261,20 +339,20 @@ where
                     // * 🚩【2024-04-02 21:48:07】↓下面没法简化：[`anyhow::Result`]拷贝之后还是引用
                     match result {
                         Ok(..) => break Ok(()),
-                        Err(e) => break Err(anyhow!("NAVM运行时已终止：{e}")),
+                        Err(e) => break Err(anyhow!(messages::runtime_terminated(&e))),
                     }
                 }
 
                 // 尝试拉取所有NAVM运行时输出
                 while let Ok(Some(output)) = runtime
                     .try_fetch_output()
-                    .inspect_err(|e| eprintln_cli!([Error] "尝试拉取NAVM运行时输出时发生错误：{e}"))
+                    .inspect_err(|e| eprintln_cli!([Error] "{}", messages::fetch_output_failed(e)))
                 {
                     // 缓存输出
                     // * 🚩在缓存时格式化输出
                     match output_cache.lock() {
                         Ok(mut output_cache) => output_cache.put(output)?,
-                        Err(e) => eprintln_cli!([Error] "缓存NAVM运行时输出时发生错误：{e}"),
+                        Err(e) => eprintln_cli!([Error] "{}", messages::cache_output_failed(&e)),
                     }
                 }
             }
@@ -287,7 +365,7 @@ where
     /// 生成「Websocket服务」子线程
     pub fn try_spawn_ws_server(&mut self) -> Result<Option<JoinHandle<Result<()>>>> {
         // 若有⇒启动
-        if self.config.websocket.is_some() {
+        if self.config.get().websocket.is_some() {
             let thread = spawn_ws_server(self)?;
             return Ok(Some(thread));
         }
@@ -303,6 +381,8 @@ where
         let runtime = self.runtime.clone();
         let config = self.config.clone();
         let output_cache = self.output_cache.clone();
+        // 标准输入为管道⇒读到EOF后等待CIN空闲，然后终止
+        let piped = !stdin().is_terminal();
 
         // 启动线程
         let thread = thread::spawn(move || {
@@ -320,14 +400,14 @@ where
                 // ! 📝PoisonError无法在线程中传递
                 let runtime = &mut *runtime
                     .lock()
-                    .transform_err(|e| anyhow!("获取运行时引用时发生错误：{e:?}"))?;
+                    .transform_err(|e| anyhow!(messages::runtime_lock_failed(&e)))?;
 
                 // 若运行时已终止，返回终止信号
                 if let VmStatus::Terminated(result) = runtime.status() {
                     // * 🚩【2024-04-02 21:48:07】↓下面没法简化：[`anyhow::Result`]拷贝之后还是引用
                     match result {
                         Ok(..) => return Ok(()),
-                        Err(e) => return Err(anyhow!("NAVM运行时已终止：{e}")),
+                        Err(e) => return Err(anyhow!(messages::runtime_terminated(&e))),
                     }
                 }
 
@@ -335,18 +415,30 @@ where
                 // ! 🚩【2024-04-02 19:27:01】及早报错：即便无关紧要，也停止
                 let output_cache = &mut *output_cache
                     .lock()
-                    .transform_err(|e| anyhow!("获取NAVM输出缓存时发生错误：{e}"))?;
+                    .transform_err(|e| anyhow!(messages::output_cache_lock_failed(&e)))?;
 
-                // 非空⇒解析输入并执行
+                // 非空⇒解析输入并执行 | 使用最新的配置
                 if !line.is_empty() {
+                    let config = config.get();
                     if_let_err_eprintln_cli!(
                         // * 🚩【2024-04-09 22:11:41】置入时以「配置文件所在目录」为NAL工作目录
                         Self::input_line_to_vm(runtime, line, &config, output_cache, &config.config_path)
-                        => e => [Error] "输入过程中发生错误：{e}"
+                        => e => [Error] "{}", messages::input_failed(&e)
                     );
                 }
             }
 
+            // 管道输入结束⇒等待CIN空闲后终止，使程序正常退出
+            if piped {
+                let grace_period = Duration::from_secs_f64(config.get().pipe_grace_period);
+                println_cli!([Log] "{}", messages::pipe_eof(grace_period.as_secs_f64()));
+                wait_for_idle(&output_cache, grace_period)?;
+                runtime
+                    .lock()
+                    .transform_err(|e| anyhow!(messages::runtime_lock_failed(&e)))?
+                    .terminate()?;
+            }
+
             // 返回
             Ok(())
         });
@@ -383,11 +475,11 @@ where
 
     /// 像NAVM实例输入NAVM指令
     fn input_cmd_to_vm(runtime: &mut R, line: &str) -> Result<()> {
-        let cmd =
-            Cmd::parse(line).inspect_err(|e| eprintln_cli!([Error] "NAVM指令解析错误：{e}"))?;
+        let cmd = Cmd::parse(line)
+            .inspect_err(|e| eprintln_cli!([Error] "{}", messages::cmd_parse_failed(e)))?;
         runtime
             .input_cmd(cmd)
-            .inspect_err(|e| eprintln_cli!([Error] "NAVM指令执行错误：{e}"))
+            .inspect_err(|e| eprintln_cli!([Error] "{}", messages::cmd_exec_failed(e)))
     }
 
     /// 向NAVM实例输入NAL（输入）
@@ -402,6 +494,20 @@ where
         output_cache: &mut OutputCache,
         config: &RuntimeConfig,
         nal_root_path: &Path, // 📄从NAL文件加载⇒NAL文件所在路径；用户输入⇒配置文件所在路径
+    ) -> Result<()> {
+        Self::input_nal_to_vm_counting(runtime, input, output_cache, config, nal_root_path, &mut 0)
+    }
+
+    /// 向NAVM实例输入NAL（输入），并统计失败的输入
+    /// * 🎯预置NAL的逐文件报告：非严格模式下也能得知「是否有失败」
+    /// * 📌`n_failed`：解析失败、置入失败的输入都会计数
+    fn input_nal_to_vm_counting(
+        runtime: &mut R,
+        input: &str,
+        output_cache: &mut OutputCache,
+        config: &RuntimeConfig,
+        nal_root_path: &Path,
+        n_failed: &mut usize,
     ) -> Result<()> {
         // 解析输入，并遍历解析出的每个NAL输入
         for input in parse(input) {
@@ -410,7 +516,8 @@ where
                 // 错误⇒根据严格模式处理
                 Err(e) => {
                     // 无论是否严格模式，都报告错误
-                    eprintln_cli!([Error] "解析NAL输入时发生错误：{e}");
+                    eprintln_cli!([Error] "{}", messages::nal_parse_failed(&e));
+                    *n_failed += 1;
                     // 严格模式下提前返回
                     if_return! { config.strict_mode => Err(e) }
                 }
@@ -427,7 +534,8 @@ where
                     // 处理错误
                     if let Err(e) = put_result {
                         // 无论是否严格模式，都报告错误
-                        eprintln_cli!([Error] "置入NAL输入「{nal:?}」时发生错误：{e}");
+                        eprintln_cli!([Error] "{}", messages::nal_put_failed(&nal, &e));
+                        *n_failed += 1;
                         // 严格模式下考虑上报错误
                         if config.strict_mode {
                             match e.downcast_ref::<TranslateError>() {
@@ -447,61 +555,109 @@ where
     }
 }
 
+/// 等待CIN空闲
+/// * 🎯管道输入结束后，接收CIN的剩余输出
+/// * 🚩在一整个宽限期内，输出缓存中都没有新的输出⇒视作空闲
+pub fn wait_for_idle(output_cache: &ArcMutex<OutputCache>, grace_period: Duration) -> Result<()> {
+    let n_outputs = || -> Result<usize> {
+        let output_cache = output_cache
+            .lock()
+            .transform_err(|e| anyhow!(messages::output_cache_lock_failed(&e)))?;
+        Ok(output_cache.borrow_inner().len())
+    };
+    let mut last = n_outputs()?;
+    loop {
+        sleep(grace_period);
+        let current = n_outputs()?;
+        if current == last {
+            return Ok(());
+        }
+        last = current;
+    }
+}
+
+/// 按配置重新启动虚拟机
+/// * 🎯自动重启、配置热重载共用
+/// * 🚩新的虚拟机总是以「命令行虚拟机」启动，再转换为管理者的运行时类型
+impl<R> RuntimeManager<R>
+where
+    R: VmRuntime + Send + Sync + From<CommandVmRuntime> + 'static,
+{
+    /// 按新配置原地重启虚拟机
+    /// * 🚩先终止旧虚拟机⇒再按新配置启动⇒成功后替换共享的虚拟机与配置
+    ///   * 📌同一时刻只有一个CIN进程：占用固定端口、锁文件的CIN亦可重启
+    ///   * 📌启动失败⇒按原配置重新启动，并返回错误；配置保持不变
+    /// * 📌重启期间持有虚拟机的锁：其它线程不会使用已终止的虚拟机
+    /// * 📌各线程持有的共享引用保持不变，无需重建线程与输出缓存
+    pub fn relaunch(&self, config: RuntimeConfig) -> Result<()> {
+        let mut runtime = self
+            .runtime
+            .lock()
+            .transform_err(|e| anyhow!(messages::runtime_unlock_failed(&e)))?;
+        // 终止旧虚拟机 | 失败仅警告（如旧虚拟机已退出）
+        if_let_err_eprintln_cli! {
+            runtime.terminate()
+            => e => [Warn] "{}", messages::old_runtime_terminate_failed(&e)
+        }
+        match relaunch_by_runtime_config(&config) {
+            Ok(new_runtime) => {
+                *runtime = new_runtime.into();
+                self.config.set(config);
+                Ok(())
+            }
+            Err(e) => {
+                // 按原配置重新启动 | 原配置与新配置相同（如自动重启）⇒不再重试
+                let old_config = self.config.get();
+                if *old_config != config {
+                    match relaunch_by_runtime_config(&old_config) {
+                        Ok(old_runtime) => {
+                            *runtime = old_runtime.into();
+                            println_cli!([Info] "{}", messages::old_config_relaunched());
+                        }
+                        Err(e) => {
+                            eprintln_cli!([Error] "{}", messages::old_config_relaunch_failed(&e))
+                        }
+                    }
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
 /// 重启虚拟机
-/// * 🚩消耗原先的虚拟机管理者，返回一个新的管理者
-///   * 🚩【2024-04-02 20:25:21】目前对「终止先前虚拟机」持放松态度
-/// * 📝从`ArcMutex<T>>`中拿取值的所有权：[`Arc::try_unwrap`] + [`Mutex::into_inner]
-///   * 🔗参考：<https://users.rust-lang.org/t/move-out-of-arc-mutex-t/85940>
-pub fn restart_manager(
-    manager: RuntimeManager<impl VmRuntime + Send + Sync>,
-) -> Result<RuntimeManager<impl VmRuntime + Send + Sync>> {
-    // 尝试终止先前的虚拟机
-    // ! ❌[`Arc::try_unwrap`]的返回值包括`VmRuntime`，所以连[`Debug`]都不支持
-    // ! ❌【2024-04-02 20:33:01】目前测试中`Arc::into_inner`基本总是失败（线程里还有引用）
-    // * 🚩【2024-04-02 20:33:18】现在通过修改NAVM API，不再需要获取运行时所有权了（销毁交给）
-    // let old_runtime_mutex =
-    // Arc::into_inner(manager.runtime).ok_or(anyhow!("runtime Arc解包失败"))?;
-    // let mut old_runtime = old_runtime_mutex.into_inner()?;
-    let old_runtime = &mut *manager
-        .runtime
-        .lock()
-        .transform_err(|e| anyhow!("runtime Mutex解锁失败：{e:?}"))?;
-    old_runtime.terminate()?;
-
-    // 启动新的虚拟机
-    let config_ref = &*manager.config;
-    let new_runtime = launch_by_runtime_config(config_ref)?;
-    let new_manager = RuntimeManager::new(new_runtime, config_ref.clone());
-
-    // 返回
-    Ok(new_manager)
+/// * 🚩按当前配置原地重启：替换共享的虚拟机，保留配置、输出缓存与各线程
+///   * 📌配置热重载等线程持有的共享引用因此依然有效
+pub fn restart_manager<R>(manager: &RuntimeManager<R>) -> Result<()>
+where
+    R: VmRuntime + Send + Sync + From<CommandVmRuntime> + 'static,
+{
+    manager.relaunch((*manager.config.get()).clone())
 }
 
 /// 根据配置（的「是否重启」选项）管理（一系列）虚拟机实例
-pub fn loop_manage(
-    mut manager: RuntimeManager<impl VmRuntime + Send + Sync>,
-    config: &RuntimeConfig,
-) -> Result<()> {
+/// * 🚩每次出错时读取最新的配置：热重载的`autoRestart`随即生效
+pub fn loop_manage<R>(mut manager: RuntimeManager<R>) -> Result<()>
+where
+    R: VmRuntime + Send + Sync + From<CommandVmRuntime> + 'static,
+{
     match manager.manage() {
         // 返回了「结果」⇒解包并传递结果
         Ok(result) => result,
         // 发生错误⇒尝试处理
         Err(e) => {
             // 打印错误信息
-            println_cli!([Error] "运行时发生错误：{e}");
+            println_cli!([Error] "{}", messages::runtime_error(&e));
             // 尝试重启
-            if config.auto_restart {
-                println_cli!([Info] "程序将在 2 秒后自动重启。。。");
+            if manager.config.get().auto_restart {
+                println_cli!([Info] "{}", messages::restarting_in(2));
                 sleep(Duration::from_secs(2));
-                let new_manager = match restart_manager(manager) {
-                    Ok(manager) => manager,
-                    Err(e) => {
-                        println_cli!([Error] "重启失败：{e}");
-                        return Err(anyhow!("NAVM运行时发生错误，且重启失败：{e}"));
-                    }
-                };
+                if let Err(e) = restart_manager(&manager) {
+                    println_cli!([Error] "{}", messages::restart_failed(&e));
+                    return Err(anyhow!(messages::runtime_error_restart_failed(&e)));
+                }
                 // 重启之后继续循环
-                return loop_manage(new_manager, config);
+                return loop_manage(manager);
             }
             // 正常返回
             Ok(())
@@ -0,0 +1,491 @@
+//! 搜索索引缓存
+//! * 🎯重复搜索时，避免反复解析配置文件、识别CIN
+//!   * 📄配置选择界面中，每次按下回车都会重新搜索
+//! * 🚩以「路径 + 修改时间」为键，缓存
+//!   * 有效配置文件的摘要（连同其继承的配置文件），参见[`SearchedConfig`]
+//!   * 识别出CIN的路径及其匹配度
+//!   * 📌无效的配置文件、未识别出CIN的路径不作缓存：避免索引随遍历范围无限增长
+//! * ⚠️不缓存完整的配置：配置中的变量插值依赖工作目录与环境变量，且可能含有密钥
+//!   * 🚩摘要中只缓存**未插值**的原始文本，取出时再按当前环境插值
+//!   * 🚩选中后再通过[`SearchedConfig::load`]重新读取
+//! * 🚩增量刷新：只重新读取/识别修改时间有变的路径；保存时清除已不存在的路径
+//! * 📌缓存文件存放于用户缓存目录下，参见[`default_index_path`]
+//! * ⚠️目录型CIN（如PyNARS）以目录自身的修改时间为准：深层文件的变化不会使其失效
+//!   * 🚩可用`--no-cache`绕过缓存
+
+use crate::{
+    cli::{
+        merge_config_layers, read_config_extern, read_config_layers, ConfigLayer,
+        InterpolateContext, LaunchConfig,
+    },
+    support::{
+        cin_search::{
+            cin_registry::{cin_kind, CIN_KINDS},
+            impls_path_builder::CinSearchResult,
+        },
+        i18n::LocalizedText,
+    },
+};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::SystemTime,
+};
+
+/// 缓存格式的版本
+/// * 🚩与文件中的版本不一致⇒丢弃整个缓存
+/// * 📝版本2：配置只缓存摘要，不再缓存完整配置
+/// * 📝版本3：摘要不再插值；不再缓存无效配置、未识别出CIN的路径
+const INDEX_VERSION: u32 = 3;
+
+/// 本进程已写入的临时文件数
+/// * 🎯与进程ID一同区分临时文件，参见[`SearchIndex::save`]
+static N_TEMP_FILES: AtomicUsize = AtomicUsize::new(0);
+
+/// 默认的缓存文件路径
+/// * 📄Linux：`~/.cache/babel_nar_cli/search_index.json`
+/// * 📄Windows：`%LOCALAPPDATA%\babel_nar_cli\search_index.json`
+pub fn default_index_path() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("babel_nar_cli").join("search_index.json"))
+}
+
+/// 缓存中使用的路径
+/// * 🎯不受工作目录影响：同一文件在不同工作目录下搜索，命中同一缓存
+/// * 🚩转换为绝对路径（不解析符号链接）
+fn index_key(path: &Path) -> Option<PathBuf> {
+    std::path::absolute(path).ok()
+}
+
+/// 获取路径的修改时间
+/// * 🚩路径不存在、平台不支持⇒[`None`]
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    path.metadata().and_then(|meta| meta.modified()).ok()
+}
+
+/// 搜索到的配置文件
+/// * 🎯仅记录展示、选择所需的信息
+/// * 🚩缓存中的描述未经插值，参见[`Self::interpolated`]
+/// * 🚩选中后通过[`Self::load`]重新读取完整配置
+///   * 📌按当前的工作目录与环境变量插值
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchedConfig {
+    /// 配置文件路径
+    /// * 📌绝对路径，参见[`index_key`]
+    pub path: PathBuf,
+    /// 配置的描述
+    pub description: Option<LocalizedText>,
+    /// 配置是否为空
+    pub is_empty: bool,
+    /// 命名档案：`(档案名, 描述)`
+    pub profiles: Vec<(String, Option<LocalizedText>)>,
+}
+
+impl SearchedConfig {
+    /// 从读取到的配置中提取摘要
+    pub fn new(path: PathBuf, config: &LaunchConfig) -> Self {
+        Self {
+            path,
+            description: config.description.clone(),
+            is_empty: config.is_empty(),
+            profiles: config
+                .profiles
+                .iter()
+                .flatten()
+                .map(|(name, profile)| (name.clone(), profile.description.clone()))
+                .collect(),
+        }
+    }
+
+    /// 按当前的工作目录与环境变量，对各描述插值
+    /// * 📌无法解析的变量保留原文：摘要仅作展示
+    /// * ⚠️继承而来的描述亦以此配置文件所在目录作为`CONFIG_DIR`
+    fn interpolated(mut self) -> Self {
+        let context = InterpolateContext::from_config_path(&self.path);
+        let mut unresolved = vec![];
+        let profile_descriptions = self.profiles.iter_mut().filter_map(|(_, d)| d.as_mut());
+        for text in self
+            .description
+            .iter_mut()
+            .chain(profile_descriptions)
+            .flat_map(LocalizedText::texts_mut)
+        {
+            context.interpolate_string(text, &mut unresolved);
+        }
+        self
+    }
+
+    /// 重新读取完整的配置
+    /// * 📌同[`read_config_extern`]
+    pub fn load(&self) -> Result<LaunchConfig> {
+        read_config_extern(&self.path)
+    }
+
+    /// 重新读取完整的配置，并选中其中的命名档案
+    /// * 📌同[`LaunchConfig::with_profile`]
+    pub fn load_profile(&self, name: &str) -> Result<LaunchConfig> {
+        self.load()?.with_profile(name)
+    }
+}
+
+/// 缓存的配置读取结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedConfig {
+    /// 配置文件及其所继承配置文件的修改时间
+    /// * ⚠️读取失败时只记录配置文件自身
+    sources: Vec<(PathBuf, SystemTime)>,
+    /// 读取结果的摘要
+    /// * 📌未经插值
+    summary: SearchedConfig,
+}
+
+impl CachedConfig {
+    /// 所有来源的修改时间均未变化
+    fn is_fresh(&self) -> bool {
+        self.sources
+            .iter()
+            .all(|(path, time)| modified_time(path).as_ref() == Some(time))
+    }
+}
+
+/// 缓存的CIN识别结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedCin {
+    /// 路径的修改时间
+    modified: SystemTime,
+    /// 识别出的`(CIN名称, 匹配度)`
+    /// * 📌只记录匹配度非零的
+    matches: Vec<(String, usize)>,
+}
+
+/// 搜索索引
+/// * 🎯缓存配置搜索、CIN搜索的中间结果
+/// * 🚩通过[`Self::open`]从文件加载，通过[`Self::save`]写回
+///   * 📌文件缺失、损坏、版本不符⇒从空索引开始
+/// * 🚩[`Self::in_memory`]：不读写文件，仅在本次运行中复用
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    /// 缓存格式的版本
+    version: u32,
+    /// 配置文件路径 ⇒ 读取结果的摘要
+    configs: HashMap<PathBuf, CachedConfig>,
+    /// 路径 ⇒ CIN识别结果
+    cins: HashMap<PathBuf, CachedCin>,
+    /// 缓存文件路径
+    #[serde(skip)]
+    file: Option<PathBuf>,
+    /// 自加载以来是否有变化
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl SearchIndex {
+    /// 不读写文件的空索引
+    pub fn in_memory() -> Self {
+        Self {
+            version: INDEX_VERSION,
+            ..Default::default()
+        }
+    }
+
+    /// 从缓存文件加载
+    /// * 🚩文件缺失、损坏、版本不符⇒空索引（保存时覆盖原文件）
+    pub fn open(file: PathBuf) -> Self {
+        let loaded = fs::read_to_string(&file)
+            .ok()
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .filter(|index| index.version == INDEX_VERSION);
+        Self {
+            file: Some(file),
+            ..loaded.unwrap_or_else(Self::in_memory)
+        }
+    }
+
+    /// 从默认位置加载
+    /// * 🚩无法确定用户缓存目录⇒不读写文件
+    pub fn open_default() -> Self {
+        match default_index_path() {
+            Some(file) => Self::open(file),
+            None => Self::in_memory(),
+        }
+    }
+
+    /// 读取配置文件的摘要
+    /// * 🚩配置文件及其继承的配置文件均未修改⇒直接使用缓存
+    /// * 🚩否则⇒重新读取，并更新缓存
+    /// * 🚩返回前按当前环境插值，参见[`SearchedConfig::interpolated`]
+    /// * 📌读取失败⇒[`None`]，且不缓存
+    ///   * ⚠️始终以绝对路径读取，参见[`index_key`]
+    pub fn read_config(&mut self, path: &Path) -> Option<SearchedConfig> {
+        let path = &index_key(path)?;
+        if let Some(cached) = self.configs.get(path).filter(|cached| cached.is_fresh()) {
+            return Some(cached.summary.clone().interpolated());
+        }
+        let Some(cached) = Self::read_config_uncached(path) else {
+            // 先前有效、现已无效⇒清除
+            self.dirty |= self.configs.remove(path).is_some();
+            return None;
+        };
+        let summary = cached.summary.clone();
+        self.configs.insert(path.to_owned(), cached);
+        self.dirty = true;
+        Some(summary.interpolated())
+    }
+
+    /// 不经缓存地读取配置文件的摘要
+    /// * 🚩先完整读取（含插值）以检验配置是否有效
+    /// * 🚩再从各配置层的原始内容中提取摘要
+    fn read_config_uncached(path: &Path) -> Option<CachedConfig> {
+        let layers = read_config_layers(path).ok()?;
+        let sources = layers
+            .iter()
+            .filter_map(|layer| Some((layer.path.clone(), modified_time(&layer.path)?)))
+            .collect();
+        let raw_layers = layers
+            .into_iter()
+            .map(|layer| {
+                Some(ConfigLayer {
+                    config: layer.read_raw().ok()?,
+                    path: layer.path,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(CachedConfig {
+            sources,
+            summary: SearchedConfig::new(path.to_owned(), &merge_config_layers(raw_layers)),
+        })
+    }
+
+    /// 用注册表中的所有CIN种类识别一批路径
+    /// * 🚩路径未修改⇒直接使用缓存的匹配度
+    /// * 📌结果同[`search_results_sorted`](crate::support::cin_search::impls_path_builder::search_results_sorted)
+    pub fn cin_results(&mut self, paths: &[PathBuf]) -> Vec<CinSearchResult> {
+        let matches = paths
+            .iter()
+            .map(|path| self.cin_matches(path))
+            .collect::<Vec<_>>();
+        // 按「种类→路径」的顺序展开，与不使用缓存时一致
+        let mut results = vec![];
+        for kind in CIN_KINDS {
+            for (path, matches) in paths.iter().zip(&matches) {
+                for (name, score) in matches {
+                    if *name == kind.name() {
+                        results.push(CinSearchResult::new(*kind, path, *score));
+                    }
+                }
+            }
+        }
+        results.sort_by_key(|result| std::cmp::Reverse(result.score)); // ←此处是倒序
+        results
+    }
+
+    /// 识别单个路径
+    /// * 🚩路径不存在⇒不识别，也不缓存
+    /// * 🚩未识别出CIN⇒不缓存
+    fn cin_matches(&mut self, path: &Path) -> Vec<(String, usize)> {
+        let (Some(path), Some(modified)) = (index_key(path), modified_time(path)) else {
+            return vec![];
+        };
+        let path = &path;
+        if let Some(cached) = self.cins.get(path).filter(|c| c.modified == modified) {
+            return cached.matches.clone();
+        }
+        let matches = CIN_KINDS
+            .iter()
+            .map(|kind| (kind.name().to_string(), kind.match_path(path)))
+            .filter(|(_, score)| *score > 0)
+            .collect::<Vec<_>>();
+        match matches.is_empty() {
+            // 先前识别出、现已识别不出⇒清除
+            true => self.dirty |= self.cins.remove(path).is_some(),
+            false => {
+                let cached = CachedCin {
+                    modified,
+                    matches: matches.clone(),
+                };
+                self.cins.insert(path.to_owned(), cached);
+                self.dirty = true;
+            }
+        }
+        matches
+    }
+
+    /// 写回缓存文件
+    /// * 🚩先清除已不存在的路径，再在有变化时写入
+    /// * 📌先写入临时文件再重命名：避免多个进程同时写入时损坏缓存
+    ///   * 🚩临时文件名含进程ID与序号：各次写入互不覆盖
+    pub fn save(&mut self) -> Result<()> {
+        let n_before = self.configs.len() + self.cins.len();
+        self.configs.retain(|path, _| path.exists());
+        self.cins.retain(|path, cached| {
+            path.exists()
+                && cached
+                    .matches
+                    .iter()
+                    .all(|(name, _)| cin_kind(name).is_some())
+        });
+        self.dirty |= self.configs.len() + self.cins.len() != n_before;
+        let Some(file) = self.file.as_ref().filter(|_| self.dirty) else {
+            return Ok(());
+        };
+        if let Some(dir) = file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let n = N_TEMP_FILES.fetch_add(1, Ordering::Relaxed);
+        let temp_file = file.with_extension(format!("json.{}.{n}.tmp", process::id()));
+        let written = fs::write(&temp_file, serde_json::to_string(self)?)
+            .and_then(|_| fs::rename(&temp_file, file));
+        if written.is_err() {
+            let _ = fs::remove_file(&temp_file);
+        }
+        written?;
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture::FixtureDir;
+    use nar_dev_utils::asserts;
+    use std::{fs::File, time::Duration};
+
+    /// 工具/写入文件，并指定修改时间
+    fn write_with_time(path: &Path, content: &str, time: SystemTime) {
+        fs::write(path, content).unwrap();
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(time)
+            .unwrap();
+    }
+
+    /// 测试/配置缓存
+    /// * 🎯修改时间不变⇒使用缓存；修改时间变化⇒重新读取
+    #[test]
+    fn test_config_cache() {
+        let root = FixtureDir::new("search_cache_config");
+        let valid = root.join("valid.json");
+        let invalid = root.join("invalid.json");
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        write_with_time(
+            &valid,
+            r#"{ "description": "old", "command": { "cmd": "secret-cmd" } }"#,
+            time,
+        );
+        write_with_time(&invalid, "not a config", time);
+        let description = |config: Option<SearchedConfig>| {
+            config
+                .and_then(|config| config.description)
+                .map(|description| description.get().to_string())
+        };
+
+        // 首次读取，并写入缓存文件
+        let index_file = root.join("cache/index.json");
+        let mut index = SearchIndex::open(index_file.clone());
+        let first = index.read_config(&valid);
+        asserts! {
+            description(first.clone()) => Some("old".to_string())
+            index.read_config(&invalid).is_none() => true
+        }
+        index.save().unwrap();
+        // 只缓存摘要，不缓存完整配置；无效的配置不作缓存
+        asserts! {
+            fs::read_to_string(&index_file).unwrap().contains("secret-cmd") => false
+            index.configs.contains_key(&invalid) => false
+        }
+
+        // 内容变化但修改时间不变⇒使用缓存
+        write_with_time(&valid, r#"{ "description": "new" }"#, time);
+        let mut index = SearchIndex::open(index_file.clone());
+        let cached = index.read_config(&valid);
+        asserts! {
+            description(cached.clone()) => Some("old".to_string())
+            cached.clone().map(|config| config.path) => first.map(|config| config.path)
+        }
+        // 选中后重新读取⇒总是最新的完整配置
+        let loaded = cached.unwrap().load().unwrap();
+        asserts! {
+            loaded.description.map(|d| d.get().to_string()) => Some("new".to_string())
+            loaded.command.is_none() => true
+        }
+
+        // 修改时间变化⇒重新读取
+        write_with_time(
+            &valid,
+            r#"{ "description": "new" }"#,
+            time + Duration::from_secs(1),
+        );
+        asserts! {
+            description(index.read_config(&valid)) => Some("new".to_string())
+        }
+
+        // 已删除的文件⇒保存时清除
+        fs::remove_file(&valid).unwrap();
+        index.save().unwrap();
+        let index = SearchIndex::open(index_file);
+        assert!(index.configs.is_empty());
+    }
+
+    /// 测试/摘要中的插值
+    /// * 🎯缓存原始文本，取出时再按当前环境插值
+    #[test]
+    fn test_config_cache_interpolation() {
+        let root = FixtureDir::new("search_cache_interpolation");
+        let path = root.write("config.json", r#"{ "description": "cwd: ${CWD}" }"#);
+        let index_file = root.join("cache/index.json");
+        let expected = format!("cwd: {}", std::env::current_dir().unwrap().display());
+        let description = |config: Option<SearchedConfig>| {
+            config
+                .and_then(|config| config.description)
+                .map(|description| description.get().to_string())
+        };
+
+        let mut index = SearchIndex::open(index_file.clone());
+        assert_eq!(
+            description(index.read_config(&path)),
+            Some(expected.clone())
+        );
+        index.save().unwrap();
+        // 缓存文件中只有原始文本
+        let content = fs::read_to_string(&index_file).unwrap();
+        asserts! {
+            content.contains("cwd: ${CWD}") => true
+            content.contains(&expected) => false
+        }
+        // 使用缓存时同样插值
+        let mut index = SearchIndex::open(index_file);
+        assert_eq!(description(index.read_config(&path)), Some(expected));
+    }
+
+    /// 测试/CIN缓存
+    /// * 🎯使用缓存与否，结果一致
+    #[test]
+    fn test_cin_cache() {
+        let files = ["NAR.exe", "opennars.jar", "README.md"];
+        let root = FixtureDir::with_empty_files("search_cache_cin", &files);
+        let paths = files.map(|file| root.join(file)).to_vec();
+        let index_file = root.join("cache/index.json");
+
+        let mut index = SearchIndex::open(index_file.clone());
+        let fresh = index.cin_results(&paths);
+        index.save().unwrap();
+        let mut index = SearchIndex::open(index_file);
+        let n_cached = index.cins.len();
+        let cached = index.cin_results(&paths);
+        let dirty = index.dirty;
+        asserts! {
+            fresh => cached
+            fresh.len() => 2
+            n_cached => 2 // 未识别出CIN的路径不作缓存
+            dirty => false
+        }
+    }
+}
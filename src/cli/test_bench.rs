@@ -0,0 +1,363 @@
+//! NAL基准测试
+//! * 🎯以数字客观对比不同CIN（或同一CIN的不同版本）的推理性能
+//!   * 📄替代`src/tests/mod.rs`中「成功（步数性能上不佳）」之类的定性记录
+//! * 🚩将每个NAL来源重复执行多次（每次启动新的CIN），统计每条预期的用时与推理步数
+//!   * 📌用时、步数只统计「预期得到满足」的执行；未满足的执行仅计入次数
+//!   * 📌推理步数：满足预期前置入的`CYC`步数，参见[`crate::cli::NalCaseReport::cycles`]
+//! * 🚩统计量：最小值、中位数、最大值；以CSV或JSON输出
+
+use crate::{
+    cli::{messages, run_nal_source, LaunchConfigPreludeNAL, NalSourceReport, RuntimeConfig},
+    println_cli,
+};
+use serde::Serialize;
+use std::{
+    fmt::{Display, Formatter, Write},
+    path::Path,
+    str::FromStr,
+};
+
+/// 基准测试结果的输出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BenchFormat {
+    /// CSV
+    /// * 📜默认值
+    /// * 🚩每个NAL来源一行总计，其下每条预期一行
+    #[default]
+    Csv,
+    /// JSON
+    /// * 🚩[`BenchReport`]的序列化结果
+    Json,
+}
+
+impl BenchFormat {
+    /// 从输出文件路径推断格式
+    /// * 🚩扩展名为`.json`⇒JSON；其它⇒CSV
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Csv,
+        }
+    }
+}
+
+impl Display for BenchFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Csv => "CSV",
+            Self::Json => "JSON",
+        })
+    }
+}
+
+/// 从格式名解析
+/// * 📄`csv`、`json`
+impl FromStr for BenchFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "json" => Ok(Self::Json),
+            _ => Err(messages::bench_format_unsupported(s)),
+        }
+    }
+}
+
+/// 统计量：最小值、中位数、最大值
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct BenchStats {
+    pub min: f64,
+    pub median: f64,
+    pub max: f64,
+}
+
+impl BenchStats {
+    /// 从样本计算
+    /// * 🚩偶数个样本⇒中位数取中间两个的平均
+    /// * 📌没有样本⇒[`None`]
+    pub fn from_samples(samples: impl IntoIterator<Item = f64>) -> Option<Self> {
+        let mut samples = samples.into_iter().collect::<Vec<_>>();
+        samples.sort_by(f64::total_cmp);
+        let n = samples.len();
+        let median = match n {
+            0 => return None,
+            _ if n % 2 == 1 => samples[n / 2],
+            _ => (samples[n / 2 - 1] + samples[n / 2]) / 2.0,
+        };
+        Some(Self {
+            min: samples[0],
+            median,
+            max: samples[n - 1],
+        })
+    }
+}
+
+/// 单条预期在多次执行中的统计
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct BenchCaseStats {
+    /// 预期名称
+    /// * 🚩取自NAL输入，参见[`crate::cli::NalCaseReport::name`]
+    pub name: String,
+    /// 执行到该预期的次数
+    /// * 📌可能少于总执行次数：如此前CIN已终止
+    pub runs: usize,
+    /// 预期得到满足的次数
+    pub passed: usize,
+    /// 用时（秒）
+    /// * 🚩仅统计预期得到满足的执行
+    pub time: Option<BenchStats>,
+    /// 推理步数
+    /// * 🚩仅统计预期得到满足的执行
+    pub cycles: Option<BenchStats>,
+}
+
+/// 单个NAL来源的基准测试结果
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct NalBenchReport {
+    /// NAL来源的展示名
+    pub source: String,
+    /// 执行次数
+    pub runs: usize,
+    /// 完全通过的次数
+    pub passed: usize,
+    /// 整次执行的用时（秒）
+    /// * 📌含CIN的启动与终止；统计所有执行
+    pub time: Option<BenchStats>,
+    /// 整次执行的推理步数
+    /// * 📌统计所有执行
+    pub cycles: Option<BenchStats>,
+    /// 各条预期的统计
+    pub cases: Vec<BenchCaseStats>,
+}
+
+impl NalBenchReport {
+    /// 汇总同一NAL来源的多次执行结果
+    /// * 🚩预期按出现顺序对齐：每次执行的是同一段NAL
+    pub fn from_runs(source: String, runs: &[NalSourceReport]) -> Self {
+        let n_cases = runs.iter().map(|run| run.cases.len()).max().unwrap_or(0);
+        let cases = (0..n_cases)
+            .map(|i| {
+                let cases = runs.iter().filter_map(|run| run.cases.get(i));
+                let passed = cases.clone().filter(|case| case.passed());
+                BenchCaseStats {
+                    name: cases
+                        .clone()
+                        .next()
+                        .map(|case| case.name.clone())
+                        .unwrap_or_default(),
+                    runs: cases.clone().count(),
+                    passed: passed.clone().count(),
+                    time: BenchStats::from_samples(
+                        passed.clone().map(|case| case.duration.as_secs_f64()),
+                    ),
+                    cycles: BenchStats::from_samples(passed.map(|case| case.cycles as f64)),
+                }
+            })
+            .collect();
+        Self {
+            source,
+            runs: runs.len(),
+            passed: runs.iter().filter(|run| run.passed()).count(),
+            time: BenchStats::from_samples(runs.iter().map(|run| run.duration.as_secs_f64())),
+            cycles: BenchStats::from_samples(runs.iter().map(|run| run.cycles as f64)),
+            cases,
+        }
+    }
+}
+
+/// 一次基准测试的总结果
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    /// 所用CIN的名称
+    /// * 🚩取自转译器配置
+    pub cin: String,
+    /// 各NAL来源的结果
+    pub sources: Vec<NalBenchReport>,
+}
+
+impl BenchReport {
+    /// 以指定格式生成文本
+    pub fn render(&self, format: BenchFormat) -> String {
+        match format {
+            BenchFormat::Csv => self.to_csv(),
+            BenchFormat::Json => serde_json::to_string_pretty(self).unwrap_or_default(),
+        }
+    }
+
+    /// 生成CSV
+    /// * 🚩每个NAL来源先一行总计（`expectation`列为空），其下每条预期一行
+    /// * 🚩用时以秒为单位；没有样本的统计量留空
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "cin,source,expectation,runs,passed,\
+            time_min,time_median,time_max,cycles_min,cycles_median,cycles_max\n",
+        );
+        let mut push_row =
+            |(source, expectation): (&str, &str), runs: usize, passed: usize, time, cycles| {
+                let _ = writeln!(
+                    csv,
+                    "{},{},{},{runs},{passed},{},{}",
+                    escape_csv(&self.cin),
+                    escape_csv(source),
+                    escape_csv(expectation),
+                    stats_columns(time, |x| format!("{x:.6}")),
+                    stats_columns(cycles, |x| x.to_string()),
+                );
+            };
+        for source in &self.sources {
+            let name = source.source.as_str();
+            push_row(
+                (name, ""),
+                source.runs,
+                source.passed,
+                source.time,
+                source.cycles,
+            );
+            for case in &source.cases {
+                push_row(
+                    (name, &case.name),
+                    case.runs,
+                    case.passed,
+                    case.time,
+                    case.cycles,
+                );
+            }
+        }
+        csv
+    }
+}
+
+/// 统计量对应的三列CSV
+fn stats_columns(stats: Option<BenchStats>, format: impl Fn(f64) -> String) -> String {
+    match stats {
+        Some(BenchStats { min, median, max }) => {
+            format!("{},{},{}", format(min), format(median), format(max))
+        }
+        None => ",,".into(),
+    }
+}
+
+/// 转义CSV字段
+/// * 🚩含逗号、引号、换行⇒以双引号包裹，内部引号加倍
+fn escape_csv(s: &str) -> String {
+    match s.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", s.replace('"', "\"\"")),
+        false => s.into(),
+    }
+}
+
+/// 执行基准测试
+/// * 🚩每个NAL来源依次重复执行`runs`次，每次启动新的CIN
+/// * 📌`verbose`⇒打印CIN的输出
+pub fn run_nal_bench(
+    config: &RuntimeConfig,
+    sources: &[LaunchConfigPreludeNAL],
+    runs: usize,
+    verbose: bool,
+) -> BenchReport {
+    let sources = sources
+        .iter()
+        .map(|source| {
+            let reports = (1..=runs)
+                .map(|i| {
+                    println_cli!([Info] "{}", messages::bench_running(source, i, runs));
+                    run_nal_source(config, source, verbose)
+                })
+                .collect::<Vec<_>>();
+            NalBenchReport::from_runs(source.to_string(), &reports)
+        })
+        .collect();
+    BenchReport {
+        cin: config.translators.name(),
+        sources,
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::NalCaseReport;
+    use nar_dev_utils::asserts;
+    use std::time::Duration;
+
+    /// 测试/统计量
+    /// * 🎯奇数、偶数个样本的中位数；无样本⇒[`None`]
+    #[test]
+    fn test_stats() {
+        let stats = |samples: &[f64]| BenchStats::from_samples(samples.iter().copied());
+        asserts! {
+            stats(&[3.0, 1.0, 2.0]) => Some(BenchStats { min: 1.0, median: 2.0, max: 3.0 })
+            stats(&[4.0, 1.0, 2.0, 3.0]) => Some(BenchStats { min: 1.0, median: 2.5, max: 4.0 })
+            stats(&[]) => None
+        }
+    }
+
+    /// 测试/汇总多次执行
+    /// * 🎯预期按顺序对齐；用时与步数只统计满足预期的执行
+    #[test]
+    fn test_from_runs() {
+        let run = |cycles: &[(usize, bool)]| NalSourceReport {
+            source: "a.nal".into(),
+            duration: Duration::from_secs(1),
+            cycles: cycles.iter().map(|(c, _)| c).sum(),
+            cases: cycles
+                .iter()
+                .map(|&(cycles, passed)| NalCaseReport {
+                    name: "''expect-cycle(100, 10, 0.25s): ANSWER <A --> C>.".into(),
+                    duration: Duration::from_millis(cycles as u64),
+                    cycles,
+                    failure: (!passed).then(|| "失败".into()),
+                })
+                .collect(),
+            errors: vec![],
+        };
+        let runs = [
+            run(&[(10, true), (20, true)]),
+            run(&[(30, true), (100, false)]),
+            run(&[(20, true)]),
+        ];
+        let report = NalBenchReport::from_runs("a.nal".into(), &runs);
+        let first = &report.cases[0];
+        let second = &report.cases[1];
+        asserts! {
+            report.runs => 3
+            report.passed => 2
+            report.cycles.map(|s| s.median) => Some(30.0)
+            first.runs => 3
+            first.passed => 3
+            first.cycles => Some(BenchStats { min: 10.0, median: 20.0, max: 30.0 })
+            first.time.map(|s| s.max) => Some(0.03)
+            second.runs => 2
+            second.passed => 1
+            second.cycles => Some(BenchStats { min: 20.0, median: 20.0, max: 20.0 })
+        }
+
+        // CSV：总计一行 + 每条预期一行
+        let csv = BenchReport {
+            cin: "ONA".into(),
+            sources: vec![report],
+        }
+        .to_csv();
+        let lines = csv.lines().collect::<Vec<_>>();
+        asserts! {
+            lines.len() => 4
+            lines[1] => "ONA,a.nal,,3,2,1.000000,1.000000,1.000000,20,30,130"
+            lines[2] => "ONA,a.nal,\"''expect-cycle(100, 10, 0.25s): ANSWER <A --> C>.\",3,3,0.010000,0.020000,0.030000,10,20,30"
+        }
+    }
+
+    /// 测试/输出格式的解析与推断
+    #[test]
+    fn test_bench_format() {
+        asserts! {
+            "CSV".parse() => Ok(BenchFormat::Csv)
+            "json".parse() => Ok(BenchFormat::Json)
+            "xml".parse::<BenchFormat>().is_err() => true
+            BenchFormat::from_path(Path::new("bench.json")) => BenchFormat::Json
+            BenchFormat::from_path(Path::new("bench.csv")) => BenchFormat::Csv
+            BenchFormat::from_path(Path::new("bench")) => BenchFormat::Csv
+        }
+    }
+}
@@ -0,0 +1,330 @@
+//! 跨CIN的测试矩阵
+//! * 🎯对比不同CIN在同一批NAL测试上的表现
+//!   * 📄替代在`src/tests/mod.rs`的文档注释中手动记录的✅/❌
+//! * 🚩每个「CIN配置 × NAL文件」组合各自在一个子进程中执行`test`子命令
+//!   * 📌子进程以JSON报告回传结果，参见[`TestReportFormat::Json`]
+//!   * 📌子进程隔离了工作目录切换、CIN崩溃与残留子进程
+//!   * ✨可同时运行多个子进程，由`--jobs`控制
+//! * 🚩最后以Markdown表格呈现：行为NAL文件，列为CIN
+
+use crate::{
+    cli::{
+        collect_nal_sources, messages, CliArgs, LaunchConfigPreludeNAL, NalSourceReport,
+        NalTestReport, TestReportFormat,
+    },
+    println_cli,
+    support::i18n::current_locale,
+};
+use anyhow::{anyhow, Result};
+use std::{
+    env::{current_exe, temp_dir},
+    ffi::OsString,
+    fs::{read_to_string, remove_file},
+    path::{absolute, Path, PathBuf},
+    process::{id as process_id, Command},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// 矩阵中单个组合的结果
+/// * 🚩子进程未能给出报告（如启动失败、崩溃）⇒错误信息
+pub type MatrixCell = Result<NalSourceReport, String>;
+
+/// 测试矩阵
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestMatrix {
+    /// 各列的CIN名称
+    /// * 🚩取自CIN配置的文件名
+    pub cins: Vec<String>,
+    /// 各行的NAL名称
+    /// * 🚩取自NAL文件名
+    pub nals: Vec<String>,
+    /// 各组合的结果
+    /// * 📌按「行（NAL）⇒列（CIN）」索引
+    pub cells: Vec<Vec<MatrixCell>>,
+}
+
+impl TestMatrix {
+    /// 以Markdown表格展示
+    /// * 🚩单元格：状态 通过的预期数/预期总数 (推理步数)
+    ///   * 📄`✅ 1/1 (5)`、`❌ 0/1 (100)`
+    ///   * 📄子进程未给出报告⇒`⚠️`
+    pub fn render(&self) -> String {
+        let mut lines = vec![
+            format!("| NAL | {} |", self.cins.join(" | ")),
+            format!("| --- |{}", " --- |".repeat(self.cins.len())),
+        ];
+        for (nal, row) in self.nals.iter().zip(&self.cells) {
+            let cells = row.iter().map(render_cell).collect::<Vec<_>>();
+            lines.push(format!("| {nal} | {} |", cells.join(" | ")));
+        }
+        lines.join("\n")
+    }
+}
+
+/// 展示单个组合的结果
+fn render_cell(cell: &MatrixCell) -> String {
+    match cell {
+        Ok(report) => format!(
+            "{} {}/{} ({})",
+            if report.passed() { "✅" } else { "❌" },
+            report.n_passed_cases(),
+            report.cases.len(),
+            report.cycles,
+        ),
+        Err(..) => "⚠️".into(),
+    }
+}
+
+/// 执行测试矩阵
+/// * 🚩收集NAL文件 ⇒ 逐个组合启动子进程 ⇒ 汇总
+/// * 📌子进程沿用当前的全局参数（`-c`、`-d`、`--profile`、`--set`、`--lang`），再叠加各CIN配置
+/// * 📌`jobs`：同时运行的子进程数，至少为1
+pub fn run_test_matrix(
+    args: &CliArgs,
+    cins: &[PathBuf],
+    paths: &[PathBuf],
+    jobs: usize,
+) -> Result<TestMatrix> {
+    // 收集CIN配置与NAL文件 | 转换为绝对路径，便于子进程使用
+    let cins = cins
+        .iter()
+        .map(|cin| match cin.is_file() {
+            true => Ok(absolute(cin)?),
+            false => Err(anyhow!(messages::file_not_found(cin))),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let nals = collect_nal_sources(paths)?
+        .into_iter()
+        .filter_map(|source| match source {
+            LaunchConfigPreludeNAL::File(path) => Some(path),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // 逐个组合执行 | 工作队列：每个线程领取下一个组合
+    let exe = current_exe()?;
+    let base_args = child_base_args(args)?;
+    let combinations = (0..nals.len())
+        .flat_map(|nal| (0..cins.len()).map(move |cin| (nal, cin)))
+        .collect::<Vec<_>>();
+    let next = AtomicUsize::new(0);
+    let n_done = AtomicUsize::new(0);
+    let results = Mutex::new(vec![None; combinations.len()]);
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, combinations.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                let Some(&(nal, cin)) = combinations.get(i) else {
+                    break;
+                };
+                let report_path =
+                    temp_dir().join(format!("babelnar-matrix-{}-{nal}-{cin}.json", process_id()));
+                let cell = run_matrix_cell(&exe, &base_args, &cins[cin], &nals[nal], &report_path);
+                let done = n_done.fetch_add(1, Ordering::SeqCst) + 1;
+                let name = format!("{} × {}", file_label(&cins[cin]), file_label(&nals[nal]));
+                match &cell {
+                    Ok(..) => println_cli!(
+                        [Info] "[{done}/{}] {name}：{}",
+                        combinations.len(),
+                        render_cell(&cell)
+                    ),
+                    Err(e) => println_cli!(
+                        [Error] "[{done}/{}] {name}：{e}",
+                        combinations.len()
+                    ),
+                }
+                if let Ok(mut results) = results.lock() {
+                    results[i] = Some(cell);
+                }
+            });
+        }
+    });
+
+    // 整理为矩阵
+    let mut results = results
+        .into_inner()
+        .map_err(|e| anyhow!("{e}"))?
+        .into_iter()
+        .map(|cell| cell.unwrap_or_else(|| Err(messages::matrix_cell_not_run())));
+    let cells = nals
+        .iter()
+        .map(|_| results.by_ref().take(cins.len()).collect())
+        .collect();
+    Ok(TestMatrix {
+        cins: cins.iter().map(|cin| file_label(cin)).collect(),
+        nals: nals.iter().map(|nal| file_label(nal)).collect(),
+        cells,
+    })
+}
+
+/// 子进程共用的命令行参数
+/// * 🚩沿用当前的全局参数，不包括子命令
+/// * 📌配置文件路径转换为绝对路径：子进程的工作目录可能不同
+fn child_base_args(args: &CliArgs) -> Result<Vec<OsString>> {
+    let mut child_args: Vec<OsString> = vec![];
+    if args.disable_default {
+        child_args.push("-d".into());
+    }
+    for config in &args.config {
+        child_args.push("-c".into());
+        child_args.push(absolute(config)?.into());
+    }
+    if let Some(profile) = &args.profile {
+        child_args.push("--profile".into());
+        child_args.push(profile.into());
+    }
+    for config_override in &args.overrides {
+        child_args.push("--set".into());
+        child_args.push(config_override.to_string().into());
+    }
+    child_args.push("--lang".into());
+    child_args.push(current_locale().code().into());
+    Ok(child_args)
+}
+
+/// 单个CIN的子进程命令行参数
+/// * 🚩CIN配置在前，全局参数在后
+///   * 📌越靠前的`-c`优先级越高：CIN配置须叠加于全局配置文件之上
+fn child_cin_args(base_args: &[OsString], cin: &Path) -> Vec<OsString> {
+    [OsString::from("-c"), cin.into()]
+        .into_iter()
+        .chain(base_args.iter().cloned())
+        .collect()
+}
+
+/// 在子进程中执行单个组合
+/// * 🚩以`test`子命令执行，并通过临时的JSON报告取回结果
+/// * ⚠️子进程未能写入报告⇒以其报错（或最后一行输出）作为错误信息
+fn run_matrix_cell(
+    exe: &Path,
+    base_args: &[OsString],
+    cin: &Path,
+    nal: &Path,
+    report_path: &Path,
+) -> MatrixCell {
+    let output = Command::new(exe)
+        .args(child_cin_args(base_args, cin))
+        .arg("test")
+        .arg("--report")
+        .arg(report_path)
+        .arg("--report-format")
+        .arg(TestReportFormat::Json.to_string())
+        .arg(nal)
+        .output()
+        .map_err(|e| messages::matrix_child_spawn_failed(&e))?;
+    let report = read_to_string(report_path);
+    let _ = remove_file(report_path);
+    match report {
+        Ok(json) => serde_json::from_str::<NalTestReport>(&json)
+            .map_err(|e| e.to_string())?
+            .sources
+            .into_iter()
+            .next()
+            .ok_or_else(messages::matrix_cell_not_run),
+        Err(..) => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let reason = stderr
+                .lines()
+                .find_map(|line| line.strip_prefix("Error: "))
+                .or_else(|| stderr.lines().rfind(|line| !line.trim().is_empty()))
+                .or_else(|| stdout.lines().rfind(|line| !line.trim().is_empty()))
+                .unwrap_or_default();
+            Err(messages::matrix_child_no_report(&output.status, reason))
+        }
+    }
+}
+
+/// 文件的展示名
+/// * 🚩优先取文件名（不含扩展名）
+fn file_label(path: &Path) -> String {
+    path.file_stem()
+        .unwrap_or(path.as_os_str())
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::NalCaseReport;
+    use clap::Parser;
+    use nar_dev_utils::asserts;
+    use std::time::Duration;
+
+    /// 测试/矩阵的Markdown表格
+    #[test]
+    fn test_render() {
+        let source = |passed: bool, cycles| NalSourceReport {
+            source: "test.nal".into(),
+            duration: Duration::ZERO,
+            cycles,
+            cases: vec![NalCaseReport {
+                name: "''expect-cycle(100, 10, 0.25s): ANSWER <A --> C>.".into(),
+                duration: Duration::ZERO,
+                cycles,
+                failure: (!passed).then(|| "失败".into()),
+            }],
+            errors: vec![],
+        };
+        let matrix = TestMatrix {
+            cins: vec!["cin_ona".into(), "cin_pynars".into()],
+            nals: vec!["test_simple_deduction".into(), "test_operation".into()],
+            cells: vec![
+                vec![Ok(source(true, 10)), Ok(source(true, 20))],
+                vec![Ok(source(true, 30)), Err("崩溃".into())],
+            ],
+        };
+        let rendered = matrix.render();
+        let lines = rendered.lines().collect::<Vec<_>>();
+        asserts! {
+            lines.len() => 4
+            lines[0] => "| NAL | cin_ona | cin_pynars |"
+            lines[1] => "| --- | --- | --- |"
+            lines[2] => "| test_simple_deduction | ✅ 1/1 (10) | ✅ 1/1 (20) |"
+            lines[3] => "| test_operation | ✅ 1/1 (30) | ⚠️ |"
+            render_cell(&Ok(source(false, 100))) => "❌ 0/1 (100)"
+        }
+    }
+
+    /// 测试/子进程的命令行参数
+    /// * 🎯沿用全局参数，并能被再次解析
+    #[test]
+    fn test_child_base_args() {
+        let args = CliArgs::parse_from([
+            "test.exe",
+            "-d",
+            "-c",
+            "global.hjson",
+            "--profile",
+            "strict",
+            "--set",
+            "websocket.host=localhost",
+            "matrix",
+            "--cin",
+            "cin_ona.hjson",
+            "test.nal",
+        ]);
+        let child_args = child_base_args(&args).expect("参数生成失败");
+        let cin = absolute("cin_ona.hjson").unwrap();
+        let reparsed = CliArgs::parse_from(
+            [OsString::from("test.exe")]
+                .into_iter()
+                .chain(child_cin_args(&child_args, &cin))
+                .chain(["test".into()]),
+        );
+        asserts! {
+            // CIN配置优先于全局配置文件
+            reparsed.config => [cin, absolute("global.hjson").unwrap()]
+            reparsed.disable_default => true
+            reparsed.profile => Some("strict".into())
+            reparsed.overrides => args.overrides
+            reparsed.lang.is_some() => true
+        }
+    }
+}
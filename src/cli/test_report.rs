@@ -0,0 +1,346 @@
+//! NAL测试报告的导出
+//! * 🎯供CI面板等外部工具读取[`NalTestReport`]
+//! * 🚩支持JUnit XML与TAP两种格式
+//!   * 📌每个NAL来源⇒一个测试套件；每条预期⇒一个测试用例
+//!   * 📌预期之外的错误（CIN启动失败、NAL解析失败等）⇒各自作为一个「出错」的用例
+//! * 🚩另支持直接导出为JSON：可被重新读取，如测试矩阵汇总子进程的结果
+//! * 🔗JUnit XML：<https://github.com/testmoapp/junitxml>
+//! * 🔗TAP：<https://testanything.org/tap-version-13-specification.html>
+
+use crate::cli::{messages, NalSourceReport, NalTestReport};
+use std::{
+    fmt::{Display, Formatter, Write},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+
+/// 测试报告格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TestReportFormat {
+    /// JUnit XML
+    /// * 📜默认值
+    #[default]
+    JUnit,
+    /// TAP（Test Anything Protocol）第13版
+    Tap,
+    /// JSON
+    /// * 🚩[`NalTestReport`]的序列化结果，可原样读回
+    Json,
+}
+
+impl TestReportFormat {
+    /// 从报告文件路径推断格式
+    /// * 🚩扩展名为`.tap`⇒TAP；`.json`⇒JSON；其它⇒JUnit XML
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("tap") => Self::Tap,
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::JUnit,
+        }
+    }
+}
+
+impl Display for TestReportFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::JUnit => "JUnit XML",
+            Self::Tap => "TAP",
+            Self::Json => "JSON",
+        })
+    }
+}
+
+/// 从格式名解析
+/// * 📄`junit`、`xml` ⇒ JUnit XML
+/// * 📄`tap` ⇒ TAP
+/// * 📄`json` ⇒ JSON
+impl FromStr for TestReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "junit" | "xml" => Ok(Self::JUnit),
+            "tap" => Ok(Self::Tap),
+            "json" => Ok(Self::Json),
+            _ => Err(messages::test_report_format_unsupported(s)),
+        }
+    }
+}
+
+impl NalTestReport {
+    /// 以指定格式生成报告文本
+    pub fn render(&self, format: TestReportFormat) -> String {
+        match format {
+            TestReportFormat::JUnit => self.to_junit_xml(),
+            TestReportFormat::Tap => self.to_tap(),
+            TestReportFormat::Json => serde_json::to_string_pretty(self).unwrap_or_default(),
+        }
+    }
+
+    /// 生成JUnit XML报告
+    /// * 🚩用例的`classname`为CIN名称，套件属性中亦记录CIN名称
+    pub fn to_junit_xml(&self) -> String {
+        let cin = escape_xml(&self.cin);
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"BabelNAR\" tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{}\">",
+            self.sources.iter().map(n_tests).sum::<usize>(),
+            self.sources.iter().map(n_failures).sum::<usize>(),
+            self.sources.iter().map(|s| s.errors.len()).sum::<usize>(),
+            seconds(self.duration),
+        );
+        for source in &self.sources {
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{}\">",
+                escape_xml(&source.source),
+                n_tests(source),
+                n_failures(source),
+                source.errors.len(),
+                seconds(source.duration),
+            );
+            let _ = writeln!(xml, "    <properties>");
+            let _ = writeln!(xml, "      <property name=\"cin\" value=\"{cin}\"/>");
+            let _ = writeln!(xml, "    </properties>");
+            for case in &source.cases {
+                let _ = write!(
+                    xml,
+                    "    <testcase name=\"{}\" classname=\"{cin}\" time=\"{}\"",
+                    escape_xml(&case.name),
+                    seconds(case.duration),
+                );
+                match &case.failure {
+                    None => xml.push_str("/>\n"),
+                    Some(failure) => {
+                        let failure = escape_xml(failure);
+                        let _ = writeln!(xml, ">");
+                        let _ = writeln!(
+                            xml,
+                            "      <failure message=\"{failure}\">{failure}</failure>"
+                        );
+                        let _ = writeln!(xml, "    </testcase>");
+                    }
+                }
+            }
+            for (i, error) in source.errors.iter().enumerate() {
+                let error = escape_xml(error);
+                let _ = writeln!(
+                    xml,
+                    "    <testcase name=\"error #{}\" classname=\"{cin}\" time=\"0.000\">",
+                    i + 1
+                );
+                let _ = writeln!(xml, "      <error message=\"{error}\">{error}</error>");
+                let _ = writeln!(xml, "    </testcase>");
+            }
+            let _ = writeln!(xml, "  </testsuite>");
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    /// 生成TAP报告
+    /// * 🚩每个用例之后附带YAML诊断块：CIN名称、NAL来源、用时、推理步数、失败信息
+    pub fn to_tap(&self) -> String {
+        let mut tap = String::from("TAP version 13\n");
+        let _ = writeln!(
+            tap,
+            "1..{}",
+            self.sources.iter().map(n_tests).sum::<usize>()
+        );
+        let mut i = 0;
+        let mut push_case = |tap: &mut String,
+                             name: &str,
+                             source: &str,
+                             (duration, cycles),
+                             failure: Option<&str>| {
+            i += 1;
+            let status = if failure.is_none() { "ok" } else { "not ok" };
+            let _ = writeln!(tap, "{status} {i} - {}", escape_tap(name));
+            let _ = writeln!(tap, "  ---");
+            let _ = writeln!(tap, "  cin: {}", yaml_string(&self.cin));
+            let _ = writeln!(tap, "  source: {}", yaml_string(source));
+            let _ = writeln!(tap, "  duration: {}", seconds(duration));
+            let _ = writeln!(tap, "  cycles: {cycles}");
+            if let Some(failure) = failure {
+                let _ = writeln!(tap, "  message: {}", yaml_string(failure));
+            }
+            let _ = writeln!(tap, "  ...");
+        };
+        for source in &self.sources {
+            let _ = writeln!(tap, "# {}", escape_tap(&source.source));
+            for case in &source.cases {
+                let cost = (case.duration, case.cycles);
+                let failure = case.failure.as_deref();
+                push_case(&mut tap, &case.name, &source.source, cost, failure);
+            }
+            for (j, error) in source.errors.iter().enumerate() {
+                let name = format!("error #{}", j + 1);
+                let cost = (Duration::ZERO, 0);
+                push_case(&mut tap, &name, &source.source, cost, Some(error.as_str()));
+            }
+        }
+        tap
+    }
+}
+
+/// 单个NAL来源在报告中的用例数
+/// * 🚩预期数 + 错误数
+fn n_tests(source: &NalSourceReport) -> usize {
+    source.cases.len() + source.errors.len()
+}
+
+/// 单个NAL来源中未通过的预期数
+fn n_failures(source: &NalSourceReport) -> usize {
+    source.cases.len() - source.n_passed_cases()
+}
+
+/// 以秒为单位展示用时，精确到毫秒
+fn seconds(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64())
+}
+
+/// 转义XML属性与文本中的特殊字符
+/// * 🚩换行等控制字符⇒字符引用，保证在属性中不丢失
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\r' | '\t' => {
+                let _ = write!(escaped, "&#{};", c as u32);
+            }
+            // XML 1.0 不允许其它控制字符
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 转义TAP用例描述
+/// * 🚩`#`会被视作指令的开始⇒转义；换行⇒空格
+fn escape_tap(s: &str) -> String {
+    s.replace('#', "\\#").replace(['\n', '\r'], " ")
+}
+
+/// 以YAML双引号字符串展示
+/// * 📝JSON字符串即为合法的YAML双引号字符串
+fn yaml_string(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_default()
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::NalCaseReport;
+    use nar_dev_utils::asserts;
+
+    /// 测试用报告
+    /// * 🚩一个全部通过的来源 + 一个有失败与错误的来源
+    fn report() -> NalTestReport {
+        let case = |name: &str, millis, failure: Option<&str>| NalCaseReport {
+            name: name.into(),
+            duration: Duration::from_millis(millis),
+            cycles: millis as usize / 10,
+            failure: failure.map(String::from),
+        };
+        NalTestReport {
+            cin: "OpenNARS".into(),
+            sources: vec![
+                NalSourceReport {
+                    source: "a.nal".into(),
+                    duration: Duration::from_millis(1500),
+                    cycles: 1,
+                    cases: vec![case("''expect-contains: ANSWER <A --> B>.", 12, None)],
+                    errors: vec![],
+                },
+                NalSourceReport {
+                    source: "b.nal".into(),
+                    duration: Duration::from_millis(250),
+                    cycles: 10,
+                    cases: vec![
+                        case("''await: OUT", 5, None),
+                        case(
+                            "''expect-cycle(10, 1, 0.1s): ANSWER",
+                            100,
+                            Some("不存在 <\"#\">"),
+                        ),
+                    ],
+                    errors: vec!["NAL解析失败".into()],
+                },
+            ],
+            duration: Duration::from_millis(1750),
+        }
+    }
+
+    /// 测试/报告格式的解析与推断
+    #[test]
+    fn test_report_format() {
+        asserts! {
+            "junit".parse() => Ok(TestReportFormat::JUnit)
+            "XML".parse() => Ok(TestReportFormat::JUnit)
+            "tap".parse() => Ok(TestReportFormat::Tap)
+            "json".parse() => Ok(TestReportFormat::Json)
+            "html".parse::<TestReportFormat>().is_err() => true
+            TestReportFormat::from_path(Path::new("out/report.TAP")) => TestReportFormat::Tap
+            TestReportFormat::from_path(Path::new("out/report.xml")) => TestReportFormat::JUnit
+            TestReportFormat::from_path(Path::new("out/report.json")) => TestReportFormat::Json
+            TestReportFormat::from_path(Path::new("report")) => TestReportFormat::JUnit
+        }
+    }
+
+    /// 测试/JUnit XML报告
+    /// * 🎯计数、用时、CIN名称、失败信息与转义
+    #[test]
+    fn test_junit_xml() {
+        let xml = report().to_junit_xml();
+        for expected in [
+            r#"<testsuites name="BabelNAR" tests="4" failures="1" errors="1" time="1.750">"#,
+            r#"<testsuite name="a.nal" tests="1" failures="0" errors="0" time="1.500">"#,
+            r#"<testsuite name="b.nal" tests="3" failures="1" errors="1" time="0.250">"#,
+            r#"<property name="cin" value="OpenNARS"/>"#,
+            r#"<testcase name="&apos;&apos;expect-contains: ANSWER &lt;A --&gt; B&gt;." classname="OpenNARS" time="0.012"/>"#,
+            r#"<failure message="不存在 &lt;&quot;#&quot;&gt;">"#,
+            r#"<error message="NAL解析失败">"#,
+        ] {
+            assert!(xml.contains(expected), "报告中缺少{expected:?}：\n{xml}");
+        }
+        assert_eq!(xml.matches("<testcase ").count(), 4);
+    }
+
+    /// 测试/TAP报告
+    /// * 🎯计划行、用例编号与状态、诊断块
+    #[test]
+    fn test_tap() {
+        let tap = report().to_tap();
+        let lines = tap.lines().collect::<Vec<_>>();
+        asserts! {
+            lines[0] => "TAP version 13"
+            lines[1] => "1..4"
+            lines[2] => "# a.nal"
+            lines[3] => "ok 1 - ''expect-contains: ANSWER <A --> B>."
+            lines[5] => "  cin: \"OpenNARS\""
+            lines[8] => "  cycles: 1"
+            tap.contains("not ok 3 - ''expect-cycle(10, 1, 0.1s): ANSWER") => true
+            tap.contains("  message: \"不存在 <\\\"#\\\">\"") => true
+            tap.contains("not ok 4 - error \\#1") => true
+        }
+    }
+
+    /// 测试/JSON报告
+    /// * 🎯可原样读回
+    #[test]
+    fn test_json() {
+        let report = report();
+        let json = report.render(TestReportFormat::Json);
+        let parsed: NalTestReport = serde_json::from_str(&json).expect("JSON报告解析失败");
+        assert_eq!(parsed, report);
+    }
+}
@@ -0,0 +1,505 @@
+//! 无交互的NAL测试执行器
+//! * 🎯在CI等场景中批量执行`.nal`测试文件，并以退出码报告结果
+//! * 🚩每个NAL来源（文件/纯文本）各自启动一个新的CIN
+//!   * 📌避免前一个文件的输出影响后一个文件的预期
+//! * 🚩逐条通过[`put_nal`]置入NAL输入，对每条「预期」单独计时
+//!   * 📌遇到失败不中止：记录后继续执行，最后统一汇总
+
+use crate::{
+    cli::{launch_by_runtime_config, messages, LaunchConfigPreludeNAL, RuntimeConfig},
+    println_cli,
+    support::io::navm_output_cache::OutputCache,
+};
+use anyhow::{anyhow, Result};
+use babel_nar::{
+    runtimes::TranslateError,
+    test_tools::{nal_format::parse, put_nal, NALInput},
+};
+use glob::{glob, Pattern};
+use navm::{
+    cmd::Cmd,
+    output::Output,
+    vm::{VmRuntime, VmStatus},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::read_to_string,
+    path::{absolute, Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// 单条测试用例（预期）的结果
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct NalCaseReport {
+    /// 用例名称
+    /// * 🚩取自NAL输入，如`expect-cycle(100): ANSWER <A --> C>.`
+    pub name: String,
+    /// 用时
+    pub duration: Duration,
+    /// 推理步数
+    /// * 🚩执行该预期期间置入的`CYC`步数，如`expect-cycle`的步进
+    pub cycles: usize,
+    /// 失败信息
+    /// * 📌通过⇒[`None`]
+    pub failure: Option<String>,
+}
+
+impl NalCaseReport {
+    /// 是否通过
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// 单个NAL来源的测试结果
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct NalSourceReport {
+    /// NAL来源的展示名
+    /// * 📄文件路径，或纯文本的首行
+    pub source: String,
+    /// 总用时
+    /// * 📌含CIN的启动与终止
+    pub duration: Duration,
+    /// 总推理步数
+    /// * 🚩执行期间置入的所有`CYC`步数
+    pub cycles: usize,
+    /// 各条预期的结果
+    pub cases: Vec<NalCaseReport>,
+    /// 预期之外的错误
+    /// * 📄CIN启动失败、文件读取失败、NAL解析失败、`''terminate`报告的失败等
+    pub errors: Vec<String>,
+}
+
+impl NalSourceReport {
+    /// 是否通过
+    /// * 🚩没有任何错误，且所有预期均通过
+    pub fn passed(&self) -> bool {
+        self.errors.is_empty() && self.cases.iter().all(NalCaseReport::passed)
+    }
+
+    /// 通过的预期数
+    pub fn n_passed_cases(&self) -> usize {
+        self.cases.iter().filter(|case| case.passed()).count()
+    }
+}
+
+/// 一次测试执行的总结果
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct NalTestReport {
+    /// 所用CIN的名称
+    /// * 🚩取自转译器配置，如`OpenNARS`
+    pub cin: String,
+    /// 各NAL来源的结果
+    pub sources: Vec<NalSourceReport>,
+    /// 总用时
+    pub duration: Duration,
+}
+
+impl NalTestReport {
+    /// 未通过的NAL来源数
+    pub fn n_failed(&self) -> usize {
+        self.sources.iter().filter(|s| !s.passed()).count()
+    }
+
+    /// 打印汇总表
+    /// * 🚩每个来源一行：状态、用时、通过的预期数、来源
+    /// * 🚩失败的来源⇒在其下列出失败的预期与错误
+    pub fn print_summary(&self) {
+        println_cli!([Info] "{}", messages::test_summary_header());
+        for source in &self.sources {
+            let line = format!(
+                "{} {:>8}  {:>3}/{:<3}  {}",
+                if source.passed() { "✅" } else { "❌" },
+                format_duration(source.duration),
+                source.n_passed_cases(),
+                source.cases.len(),
+                source.source,
+            );
+            match source.passed() {
+                true => println_cli!([Info] "  {line}"),
+                false => println_cli!([Error] "  {line}"),
+            }
+            for case in &source.cases {
+                if let Some(failure) = &case.failure {
+                    println_cli!([Error] "      ✗ {}：{failure}", case.name);
+                }
+            }
+            for error in &source.errors {
+                println_cli!([Error] "      ✗ {error}");
+            }
+        }
+        let n_total = self.sources.len();
+        let n_failed = self.n_failed();
+        println_cli!(
+            [Info]
+            "{}",
+            messages::test_summary_total(
+                n_total,
+                n_total - n_failed,
+                n_failed,
+                &format_duration(self.duration)
+            )
+        );
+    }
+
+    /// 转换为结果
+    /// * 🎯有失败⇒报错，以非零状态码退出
+    pub fn result(&self) -> Result<()> {
+        match self.n_failed() {
+            0 => Ok(()),
+            n => Err(anyhow!(messages::tests_failed(n, self.sources.len()))),
+        }
+    }
+}
+
+/// 以秒为单位展示用时
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2}s", duration.as_secs_f64())
+}
+
+/// 从命令行中的路径收集NAL测试来源
+/// * 🚩文件⇒直接作为来源；目录⇒递归收集其中所有`.nal`文件（按路径排序）
+/// * 📌转换为绝对路径：启动CIN时会切换工作目录
+/// * ⚠️路径不存在、目录中没有`.nal`文件⇒报错
+pub fn collect_nal_sources(paths: &[PathBuf]) -> Result<Vec<LaunchConfigPreludeNAL>> {
+    let mut sources = vec![];
+    for path in paths {
+        let path = absolute(path)?;
+        if path.is_file() {
+            sources.push(LaunchConfigPreludeNAL::File(path));
+            continue;
+        }
+        if !path.is_dir() {
+            return Err(anyhow!(messages::file_not_found(&path)));
+        }
+        let pattern = Pattern::escape(&path.to_string_lossy());
+        let mut files = glob(&format!("{pattern}/**/*.nal"))?.collect::<Result<Vec<_>, _>>()?;
+        if files.is_empty() {
+            return Err(anyhow!(messages::no_nal_in_dir(&path)));
+        }
+        files.sort();
+        sources.extend(files.into_iter().map(LaunchConfigPreludeNAL::File));
+    }
+    Ok(sources)
+}
+
+/// 依次执行所有NAL来源
+/// * 🚩每个来源各自启动一个新的CIN，执行完毕后终止
+/// * 📌`verbose`⇒打印CIN的输出
+pub fn run_nal_tests(
+    config: &RuntimeConfig,
+    sources: &[LaunchConfigPreludeNAL],
+    verbose: bool,
+) -> NalTestReport {
+    let start = Instant::now();
+    let sources = sources
+        .iter()
+        .map(|source| {
+            println_cli!([Info] "{}", messages::test_running(source));
+            run_nal_source(config, source, verbose)
+        })
+        .collect();
+    NalTestReport {
+        cin: config.translators.name(),
+        sources,
+        duration: start.elapsed(),
+    }
+}
+
+/// 执行单个NAL来源
+/// * 🚩读取内容 ⇒ 启动CIN ⇒ 逐条置入 ⇒ 终止CIN
+pub fn run_nal_source(
+    config: &RuntimeConfig,
+    source: &LaunchConfigPreludeNAL,
+    verbose: bool,
+) -> NalSourceReport {
+    let start = Instant::now();
+    let mut report = NalSourceReport {
+        source: source.to_string(),
+        duration: Duration::ZERO,
+        cycles: 0,
+        cases: vec![],
+        errors: vec![],
+    };
+    // 读取内容与「NAL执行路径」
+    let content = match source {
+        LaunchConfigPreludeNAL::File(path) => read_to_string(path)
+            .map(|nal| (nal, path.parent().unwrap_or(&config.config_path)))
+            .map_err(|e| messages::prelude_file_read_failed(path, &e)),
+        LaunchConfigPreludeNAL::Text(nal) => Ok((nal.clone(), config.config_path.as_path())),
+        LaunchConfigPreludeNAL::Glob(pattern) => Err(messages::glob_not_expanded(pattern)),
+    };
+    // 启动CIN并执行
+    match content {
+        Ok((nal, nal_root_path)) => match launch_by_runtime_config(config) {
+            Ok(mut runtime) => {
+                let mut output_cache = OutputCache::default();
+                if verbose {
+                    output_cache.output_handlers.add_handler(|output| {
+                        println_cli!(&output);
+                        Some(output)
+                    });
+                }
+                run_nal_inputs(
+                    &mut runtime,
+                    &nal,
+                    &mut output_cache,
+                    config,
+                    nal_root_path,
+                    &mut report,
+                );
+                // 终止CIN | 可能已被`''terminate`终止，忽略错误
+                let _ = runtime.terminate();
+            }
+            Err(e) => report.errors.push(messages::launch_failed(&e)),
+        },
+        Err(e) => report.errors.push(e),
+    }
+    report.duration = start.elapsed();
+    report
+}
+
+/// 向CIN逐条置入NAL输入，并记录结果
+/// * 🚩预期（`await`、`expect-contains`、`expect-cycle`）⇒各自作为一个用例，单独计时
+/// * 🚩其它输入出错⇒记为错误；不支持的输入⇒仅警告（兼容尽可能多的CIN）
+/// * 🚩遇到`''terminate`⇒记录其结果后停止
+/// * 🚩统计推理步数：每个预期各自的步数，以及整个来源的总步数
+/// * 📌总是以「无用户输入」执行：`''terminate(if-no-user)`会生效
+fn run_nal_inputs(
+    runtime: &mut impl VmRuntime,
+    nal: &str,
+    output_cache: &mut OutputCache,
+    config: &RuntimeConfig,
+    nal_root_path: &Path,
+    report: &mut NalSourceReport,
+) {
+    let mut runtime = CycleCounter {
+        inner: runtime,
+        cycles: 0,
+    };
+    for input in parse(nal) {
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => {
+                report.errors.push(messages::nal_parse_failed(&e));
+                continue;
+            }
+        };
+        let name = describe_nal_input(&input);
+        let is_expectation = matches!(
+            input,
+            NALInput::Await(..) | NALInput::ExpectContains(..) | NALInput::ExpectCycle(..)
+        );
+        let is_terminate = matches!(input, NALInput::Terminate { .. });
+        let start = Instant::now();
+        let cycles_before = runtime.cycles;
+        let result = put_nal(
+            &mut runtime,
+            input,
+            output_cache,
+            false,
+            nal_root_path,
+            config.short_float_epoch,
+        );
+        let duration = start.elapsed();
+        report.cycles = runtime.cycles;
+        match result {
+            _ if is_expectation => report.cases.push(NalCaseReport {
+                name,
+                duration,
+                cycles: runtime.cycles - cycles_before,
+                failure: result.err().map(|e| e.to_string()),
+            }),
+            Ok(()) => {}
+            Err(e) => match e.downcast_ref::<TranslateError>() {
+                Some(TranslateError::UnsupportedInput(..)) => {
+                    println_cli!([Warn] "{}", messages::nal_put_failed(&name, &e))
+                }
+                _ => report.errors.push(messages::nal_put_failed(&name, &e)),
+            },
+        }
+        if is_terminate {
+            break;
+        }
+    }
+}
+
+/// 统计推理步数的运行时包装
+/// * 🎯不改动[`put_nal`]，即可得知每个预期花费了多少步
+/// * 🚩累计所有经过的`CYC`指令的步数，其余操作原样转发
+struct CycleCounter<'a, R: VmRuntime> {
+    /// 被包装的运行时
+    inner: &'a mut R,
+    /// 累计的推理步数
+    cycles: usize,
+}
+
+impl<R: VmRuntime> VmRuntime for CycleCounter<'_, R> {
+    fn input_cmd(&mut self, cmd: Cmd) -> Result<()> {
+        if let Cmd::CYC(n) = cmd {
+            self.cycles += n;
+        }
+        self.inner.input_cmd(cmd)
+    }
+
+    fn fetch_output(&mut self) -> Result<Output> {
+        self.inner.fetch_output()
+    }
+
+    fn try_fetch_output(&mut self) -> Result<Option<Output>> {
+        self.inner.try_fetch_output()
+    }
+
+    fn status(&self) -> &VmStatus {
+        self.inner.status()
+    }
+
+    fn terminate(&mut self) -> Result<()> {
+        self.inner.terminate()
+    }
+}
+
+/// 以NAL语法展示NAL输入
+/// * 🎯作为测试用例的名称
+fn describe_nal_input(input: &NALInput) -> String {
+    match input {
+        NALInput::Put(cmd) => cmd.to_string(),
+        NALInput::Sleep(duration) => format!("''sleep: {duration:?}"),
+        NALInput::Await(expectation) => format!("''await: {expectation}"),
+        NALInput::ExpectContains(expectation) => format!("''expect-contains: {expectation}"),
+        NALInput::ExpectCycle(max_cycles, step_cycles, _, expectation) => {
+            format!("''expect-cycle({max_cycles}, {step_cycles}): {expectation}")
+        }
+        NALInput::SaveOutputs(path) => format!("''save-outputs: {path}"),
+        NALInput::Terminate { .. } => "''terminate".into(),
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::config_paths::*;
+    use nar_dev_utils::asserts;
+    use std::collections::VecDeque;
+
+    /// 回显虚拟机
+    /// * 🎯无需真实CIN即可测试执行逻辑
+    /// * 🚩每条Narsese输入⇒一条`OUT`输出
+    #[derive(Debug)]
+    struct EchoVm {
+        outputs: VecDeque<Output>,
+        status: VmStatus,
+    }
+
+    impl EchoVm {
+        fn new() -> Self {
+            Self {
+                outputs: VecDeque::new(),
+                status: VmStatus::Running,
+            }
+        }
+    }
+
+    impl VmRuntime for EchoVm {
+        fn input_cmd(&mut self, cmd: Cmd) -> Result<()> {
+            if let Cmd::NSE(..) = cmd {
+                self.outputs.push_back(Output::OUT {
+                    content_raw: cmd.to_string(),
+                    narsese: None,
+                });
+            }
+            Ok(())
+        }
+
+        fn fetch_output(&mut self) -> Result<Output> {
+            self.outputs.pop_front().ok_or_else(|| anyhow!("没有输出"))
+        }
+
+        fn try_fetch_output(&mut self) -> Result<Option<Output>> {
+            Ok(self.outputs.pop_front())
+        }
+
+        fn status(&self) -> &VmStatus {
+            &self.status
+        }
+
+        fn terminate(&mut self) -> Result<()> {
+            self.status = VmStatus::Terminated(Ok(()));
+            Ok(())
+        }
+    }
+
+    /// 测试/逐条执行NAL输入
+    /// * 🎯每条预期各自记录结果，失败后继续执行
+    /// * 🎯`''terminate`之后的输入不再执行
+    /// * 🎯统计推理步数：`expect-cycle`首步即通过
+    #[test]
+    fn test_run_nal_inputs() {
+        let config = RuntimeConfig::try_from(crate::cli::LaunchConfig {
+            translators: Some(crate::cli::LaunchConfigTranslators::Same("native".into())),
+            command: Some(crate::cli::LaunchConfigCommand {
+                cmd: "echo".into(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+        .expect("运行时配置转换失败");
+        let nal = "
+            <A --> B>.
+            ''expect-contains: ANSWER
+            ''expect-cycle(10, 1, 0.01s): OUT
+            ''terminate(if-no-user)
+            ''expect-contains: OUT
+        ";
+        let mut report = NalSourceReport {
+            source: "text".into(),
+            duration: Duration::ZERO,
+            cycles: 0,
+            cases: vec![],
+            errors: vec![],
+        };
+        run_nal_inputs(
+            &mut EchoVm::new(),
+            nal,
+            &mut OutputCache::default(),
+            &config,
+            Path::new("."),
+            &mut report,
+        );
+        let passed = report
+            .cases
+            .iter()
+            .map(NalCaseReport::passed)
+            .collect::<Vec<_>>();
+        asserts! {
+            passed => vec![false, true]
+            report.cases.iter().map(|case| case.cycles).collect::<Vec<_>>() => vec![0, 1]
+            report.cycles => 1
+            report.errors.is_empty() => true
+            report.passed() => false
+            report.n_passed_cases() => 1
+        }
+    }
+
+    /// 测试/收集NAL来源
+    /// * 🎯目录⇒其中所有`.nal`文件，按路径排序
+    #[test]
+    fn test_collect_nal_sources() {
+        let sources = collect_nal_sources(&[NAL_DIR.into()]).expect("收集失败");
+        let names = sources
+            .iter()
+            .filter_map(|source| match source {
+                LaunchConfigPreludeNAL::File(path) => path.file_name()?.to_str(),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let mut sorted = names.clone();
+        sorted.sort();
+        asserts! {
+            names.contains(&"test_simple_deduction.nal") => true
+            names => sorted
+        }
+        assert!(collect_nal_sources(&["./nonexistent.nal".into()]).is_err());
+    }
+}
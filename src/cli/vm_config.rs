@@ -1,11 +1,19 @@
 //! BabelNAR CLI的启动配置
 //! * ✨格式支持
 //!   * ✅JSON
+//!   * ✅HJSON
+//!   * ✅TOML
+//!   * ✅YAML
+//! * ✨变量插值：`${ENV_VAR}`、`${ENV_VAR:-default}`、`${CONFIG_DIR}`、`${CWD}`
+//!   * 🔗详见[`crate::cli::InterpolateContext`]
 //! * 🎯用于配置表示，❗不用于命令行解析
 //! * ⚠️【2024-04-01 14:31:09】特定于二进制crate，目前不要并入[`babel_nar`]
 //!
 //! ## ⚙️内容
 //!
+//! * 📌完整的JSON Schema可通过`config schema`子命令生成，配置文件可通过`config validate`检查
+//!   * 🔗参见[`crate::cli::launch_config_schema`]、[`crate::cli::validate_config_file`]
+//!
 //! Rust结构：
 //!
 //! * 📌转译器组合?
@@ -17,21 +25,36 @@
 //!   * 命令 `XXX.exe` / `python` / `java` / `node` / ...
 //!   * 命令参数? `["-m", 【Python模块】]` / `["-jar", 【Jar路径】]`
 //!   * 工作目录? `root/path/to/current_dir` | 🎯用于Python模块
-//! * 📌预置NAL?
-//!   * （互斥）文件路径? `root/path/to/file` | 与下边「纯文本」互斥
+//!   * 环境变量? `{ "JAVA_OPTS": "-Xmx1024m" }` | 🎯用于`JAVA_OPTS`、`PYTHONPATH`、`NODE_OPTIONS`等
+//!   * 要移除的环境变量? `["http_proxy"]`
+//!   * 清空继承的环境变量? `true` / `false`（默认）
+//! * 📌预置NAL? | 单个来源，或有序的来源列表
+//!   * （互斥）文件路径? `root/path/to/file` | 与下边「纯文本」「通配符」互斥
 //!   * （互斥）纯文本? `"'/VOL 0"`
+//!   * （互斥）通配符? `root/path/to/*.nal` | 按文件名排序后逐个执行
+//! * 📌预置NAL的合并方式? `replace`（默认） / `append`
+//!   * 多个配置合并时，替换或追加（被合并者在前）预置NAL
 //! * 📌Websocket参数? | ✅支持ipv6
 //!   * 主机地址 `localhost` `192.168.1.1` `fe80::abcd:fade:dad1`
 //!   * 连接端口 `3040`
+//! * 📌继承的配置? `["../base.hjson", ...]` | 别名`include`
+//!   * 路径相对于配置文件自身
+//!   * 按顺序加载，越靠前优先级越高；配置文件自身的优先级最高
+//! * 📌命名档案? `{ strict: {...}, serve: {...} }`
+//!   * 每个档案都是一个（部分的）启动配置，选中时覆盖于顶层配置之上
+//!   * 通过`--profile`选中
 //!
 //! TypeScript声明：
 //!
 //! ```ts
 //! type LaunchConfig = {
+//!     extends?: string[], // 别名`include`
+//!     profiles?: { [name: string]: LaunchConfig },
 //!     translators?: LaunchConfigTranslators,
 //!     command?: LaunchConfigCommand,
 //!     websocket?: LaunchConfigWebsocket,
-//!     preludeNAL?: LaunchConfigPreludeNAL,
+//!     preludeNAL?: LaunchConfigPreludeNAL | LaunchConfigPreludeNAL[],
+//!     preludeNALMerge?: PreludeNALMerge,
 //!     userInput?: boolean
 //!     inputMode?: InputMode
 //!     autoRestart?: boolean
@@ -43,29 +66,54 @@
 //!     // ↓虽然`in`是JavaScript/TypeScript/Rust的关键字，但仍可在此直接使用
 //!     in: string,
 //!     out: string,
+//! } | LaunchConfigCustomTranslator
+//!
+//! type LaunchConfigCustomTranslator = {
+//!     // NAVM指令头部 => 模板 | 模板中的`{head}`、`{tail}`替换为指令的头部、参数
+//!     input: { [head: string]: string },
+//!     // 按顺序尝试，取第一条匹配的规则
+//!     output: LaunchConfigOutputRule[],
+//! }
+//!
+//! type LaunchConfigOutputRule = {
+//!     // 命名捕获组`content`、`narsese`、`operator`、`params`
+//!     pattern: string,
+//!     type: string, // NAVM输出类型，如`ANSWER`、`EXE`
 //! }
 //!
 //! type LaunchConfigCommand = {
 //!     cmd: string,
 //!     cmdArgs?: string[],
 //!     currentDir?: string,
+//!     env?: { [name: string]: string },
+//!     envRemove?: string[],
+//!     clearEnv?: boolean,
 //! }
 //! type LaunchConfigWebsocket = {
 //!     host: string,
 //!     port: number, // Uint16
 //! }
-//! // ↓ 文件、纯文本 二选一
+//! // ↓ 文件、纯文本、通配符 三选一
 //! type LaunchConfigPreludeNAL = {
 //!     file?: string,
 //!     text?: string,
+//!     glob?: string,
 //! }
+//!
+//! type PreludeNALMerge = 'replace' | 'append'
 //! ```
 
-use crate::println_cli;
+use crate::{
+    cli::{messages, InterpolateContext},
+    println_cli,
+    support::i18n::{Locale, LocalizedText},
+};
 use anyhow::{anyhow, Result};
 use nar_dev_utils::{if_return, pipe, OptionBoost, ResultBoost};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     fs::read_to_string,
     path::{Component, Path, PathBuf},
 };
@@ -76,9 +124,57 @@ pub type Float = narsese::api::FloatPrecision;
 
 /// 允许的配置文件扩展名
 /// * 🚩【2024-04-07 18:30:24】目前支持JSON与HJSON
+/// * 🚩现在另支持TOML与YAML（`yaml`与`yml`两种扩展名）
 /// * 📌其顺序决定了在「扩展名优先补充」中的遍历顺序
 ///   * 📄当`a.hjson`与`a.json`存在时，`a`优先补全为`a.hjson`
-pub const SUPPORTED_CONFIG_EXTENSIONS: &[&str] = &["hjson", "json"];
+pub const SUPPORTED_CONFIG_EXTENSIONS: &[&str] = &["hjson", "json", "toml", "yaml", "yml"];
+
+/// 配置文件格式
+/// * 🎯根据扩展名分派不同的解析器
+/// * 📌无法识别的扩展名（包括无扩展名）一律视作HJSON
+///   * 📄HJSON兼容JSON，与先前「一律使用[`deser_hjson`]解析」的行为一致
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// HJSON（兼容JSON）
+    /// * 📜默认格式
+    Hjson,
+    /// TOML
+    Toml,
+    /// YAML
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// 从扩展名识别格式
+    /// * 🚩忽略大小写
+    /// * 📌无法识别⇒[`None`]
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_lowercase().as_str() {
+            "hjson" | "json" => Some(Self::Hjson),
+            "toml" => Some(Self::Toml),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    /// 从文件路径识别格式
+    /// * 🚩无扩展名/无法识别⇒默认的HJSON
+    pub fn from_path(path: &Path) -> Self {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Self::from_extension)
+            .unwrap_or(Self::Hjson)
+    }
+
+    /// 按此格式解析启动配置
+    pub fn parse(self, content: &str) -> Result<LaunchConfig> {
+        match self {
+            Self::Hjson => LaunchConfig::from_json_str(content),
+            Self::Toml => LaunchConfig::from_toml_str(content),
+            Self::Yaml => LaunchConfig::from_yaml_str(content),
+        }
+    }
+}
 
 /// 工具宏/批量拷贝性合并
 /// * 🎯简化重复的`对象.方法`调用
@@ -134,7 +230,7 @@ macro_rules! coalesce_clones {
 ///   * 🎯不论CLI自身所处何处，均保证配置读取稳定
 /// * 🚩【2024-08-01 10:31:10】因引入浮点类型[`Float`]，放弃派生[`Eq`]特征（传递性丧失）
 ///   * 📄含[`NaN`](Float::NAN)、[`Infinity`](Float::INFINITY)、[`-Infinity`](Float::NEG_INFINITY)
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")] // 🔗参考：<https://serde.rs/container-attrs.html>
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct LaunchConfig {
@@ -150,12 +246,37 @@ pub struct LaunchConfig {
     /// 启动配置的文本描述
     /// * 🎯在自动搜索时呈现给用户
     /// * 📌一般是单行文本
-    ///
-    /// * ❓I18n 国际化
-    pub description: Option<String>,
+    /// * ✨可按语言分别提供，如`{ "zh": "严格测试", "en": "Strict test" }`
+    ///   * 🚩展示时按当前语言选取，参见[`LocalizedText`]
+    pub description: Option<LocalizedText>,
+
+    /// 界面语言
+    /// * 🎯决定CLI提示、日志、报错信息所用的语言
+    /// * 📌优先级：命令行参数`--lang` > 配置 > 环境变量`LANG` > 中文
+    pub lang: Option<Locale>,
+
+    /// 继承的配置文件
+    /// * 🎯在配置文件之间复用公共部分，替代「在命令行中重复`-c`」
+    /// * 📌别名：`include`
+    /// * 🚩在[`read_config_extern`]中递归加载并合并，参见[`read_config_layers`]
+    ///   * 📌路径相对于配置文件自身
+    ///   * 📌优先级：配置自身 > 第一个被继承者 > 第二个被继承者 > ……
+    /// * 🚩合并完成后置空，不参与后续的配置合并
+    #[serde(alias = "include")]
+    pub extends: Option<Vec<PathBuf>>,
+
+    /// 命名的配置档案
+    /// * 🎯在同一个配置文件中容纳多套近似的配置
+    ///   * 📄同一CIN的「严格测试」「Websocket服务」等变体
+    /// * 📌每个档案都是一个（部分的）启动配置，选中时覆盖于顶层配置之上
+    ///   * 🚩参见[`LaunchConfig::with_profile`]
+    /// * 🚩合并时按档案名逐个合并
+    /// * ⚠️档案中的`extends`与`profiles`不生效
+    pub profiles: Option<BTreeMap<String, LaunchConfig>>,
 
     /// 转译器组合
-    /// * 🚩使用字符串模糊匹配
+    /// * 🚩按名称或别名匹配内置转译器
+    ///   * ⚠️无匹配、有歧义⇒启动时报错，参见[`resolve_translator`](crate::cli::resolve_translator)
     pub translators: Option<LaunchConfigTranslators>,
 
     /// 启动命令
@@ -166,8 +287,17 @@ pub struct LaunchConfig {
     pub websocket: Option<LaunchConfigWebsocket>,
 
     /// 预置NAL
+    /// * 📌单个来源，或有序的来源列表
     #[serde(rename = "preludeNAL")] // * 📝serde配置中，`rename`优先于`rename_all`
-    pub prelude_nal: Option<LaunchConfigPreludeNAL>,
+    pub prelude_nal: Option<LaunchConfigPreludeNALs>,
+
+    /// 预置NAL的合并方式
+    /// * 🎯在「配置继承」「多个配置文件」「命名档案」中累积预置NAL
+    /// * 🚩决定合并时「自身的预置NAL」如何对待「被合并者的预置NAL」
+    ///   * 📌自身未指定⇒沿用被合并者的设置
+    /// * 📜默认值：`replace`（替换）
+    #[serde(rename = "preludeNALMerge")]
+    pub prelude_nal_merge: Option<PreludeNALMerge>,
 
     /// 启用用户输入
     /// * 🎯控制该实例是否需要（来自用户的）交互式输入
@@ -202,6 +332,12 @@ pub struct LaunchConfig {
     /// * 🎯判等模糊性：用于解决不同版本NARS的小数位数差异问题（统一限定在最低位）
     /// * 🚩只需「真值/预算值」处的短浮点与预期之差在一定范围内，而无需绝对精确匹配
     pub short_float_epoch: Option<Float>,
+
+    /// 管道输入的宽限期（秒）
+    /// * 🎯标准输入为管道时，读到EOF后留出时间接收CIN的剩余输出
+    /// * 🚩读到EOF后，在一整个宽限期内没有新输出⇒视作CIN空闲，终止并退出
+    /// * 🚩默认值由「运行时转换」决定
+    pub pipe_grace_period: Option<Float>,
 }
 
 /// 使用`const`常量存储「空启动配置」
@@ -211,15 +347,20 @@ pub struct LaunchConfig {
 const EMPTY_LAUNCH_CONFIG: LaunchConfig = LaunchConfig {
     config_path: None,
     description: None,
+    lang: None,
+    extends: None,
+    profiles: None,
     translators: None,
     command: None,
     websocket: None,
     prelude_nal: None,
+    prelude_nal_merge: None,
     user_input: None,
     input_mode: None,
     auto_restart: None,
     strict_mode: None,
     short_float_epoch: None,
+    pipe_grace_period: None,
 };
 
 /// NAVM虚拟机（运行时）运行时配置
@@ -251,9 +392,11 @@ pub struct RuntimeConfig {
     pub websocket: Option<LaunchConfigWebsocket>,
 
     /// 预置NAL
-    /// * 🚩允许无：不预置NAL测试文件
+    /// * 🚩允许为空：不预置NAL测试文件
+    /// * 📌按顺序执行
     #[serde(rename = "preludeNAL")] // * 📝serde配置中，`rename`优先于`rename_all`
-    pub prelude_nal: Option<LaunchConfigPreludeNAL>,
+    #[serde(default)]
+    pub prelude_nal: Vec<LaunchConfigPreludeNAL>,
 
     /// 启用用户输入
     /// * 🚩必选：[`None`]将视为默认值
@@ -284,6 +427,12 @@ pub struct RuntimeConfig {
     /// * 📜默认值：`0.0`（绝对匹配）
     #[serde(default = "default_epoch")]
     pub short_float_epoch: Float,
+
+    /// 管道输入的宽限期（秒）
+    /// * 🚩必选：[`None`]将视为默认值
+    /// * 📜默认值：`1.0`
+    #[serde(default = "default_pipe_grace_period")]
+    pub pipe_grace_period: Float,
 }
 
 /// 布尔值`true`
@@ -311,11 +460,18 @@ const fn default_epoch() -> Float {
     0.0
 }
 
+/// 默认的管道输入宽限期（秒）
+/// * 🎯配置解析中「默认为`1.0`」的默认值指定
+#[inline(always)]
+const fn default_pipe_grace_period() -> Float {
+    1.0
+}
+
 /// 检查精度是否有效
 /// * 📌正实数：无穷、NaN、负数均无效
 /// * 🚩只负责检查是否有效：有限 && 非负
 #[inline(always)]
-fn check_epoch(epoch: Float) -> bool {
+pub(crate) fn check_epoch(epoch: Float) -> bool {
     epoch.is_finite() && epoch >= 0.0
 }
 
@@ -330,6 +486,24 @@ fn checked_epoch(epoch: Float) -> Result<Float> {
     }
 }
 
+/// 检查管道输入的宽限期是否有效，并返回原值/错误
+/// * 🚩与精度相同：有限 && 非负
+fn checked_pipe_grace_period(seconds: Float) -> Result<Float> {
+    match check_epoch(seconds) {
+        true => Ok(seconds),
+        false => Err(anyhow!(messages::invalid_pipe_grace_period(seconds))),
+    }
+}
+
+/// 检查启动命令是否完整，并返回原值/错误
+/// * 🚩命令为空（如仅在档案中填写了部分字段）⇒报错
+fn checked_command(command: LaunchConfigCommand) -> Result<LaunchConfigCommand> {
+    match command.cmd.is_empty() {
+        false => Ok(command),
+        true => Err(anyhow!(messages::missing_cmd())),
+    }
+}
+
 /// 尝试将启动时配置[`LaunchConfig`]转换成运行时配置[`RuntimeConfig`]
 /// * 📌默认项：存在默认值，如「启用用户输入」「不自动重启」
 /// * 📌必选项：要求必填值，如「转译器组」「启动命令」
@@ -343,11 +517,19 @@ impl TryFrom<LaunchConfig> for RuntimeConfig {
             // * 路径承袭：空值自动补默认值（空白）
             config_path: config.config_path.unwrap_or_default(),
             // * 🚩必选项统一用`ok_or(..)?`
-            translators: config.translators.ok_or(anyhow!("启动配置缺少转译器"))?,
-            command: config.command.ok_or(anyhow!("启动配置缺少启动命令"))?,
+            translators: config
+                .translators
+                .ok_or_else(|| anyhow!(messages::missing_translators()))?,
+            command: config
+                .command
+                .ok_or_else(|| anyhow!(messages::missing_command()))
+                .and_then(checked_command)?,
             // * 🚩可选项直接置入
             websocket: config.websocket,
-            prelude_nal: config.prelude_nal,
+            prelude_nal: config
+                .prelude_nal
+                .map(LaunchConfigPreludeNALs::into_sources)
+                .unwrap_or_default(),
             // * 🚩默认项统一用`unwrap_or`
             // 默认启用用户输入
             user_input: config.user_input.unwrap_or(bool_true()),
@@ -359,6 +541,12 @@ impl TryFrom<LaunchConfig> for RuntimeConfig {
             strict_mode: config.strict_mode.unwrap_or(bool_false()),
             // 完全严格的短浮点
             short_float_epoch: checked_epoch(config.short_float_epoch.unwrap_or(default_epoch()))?,
+            // 留出1秒接收剩余输出
+            pipe_grace_period: checked_pipe_grace_period(
+                config
+                    .pipe_grace_period
+                    .unwrap_or(default_pipe_grace_period()),
+            )?,
         })
     }
 }
@@ -366,7 +554,7 @@ impl TryFrom<LaunchConfig> for RuntimeConfig {
 /// NAVM实例的输入类型
 /// * 🎯处理用户输入、Websocket输入的解析方式
 /// * 📜默认值：`nal`
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 // #[serde(untagged)] // ! 🚩【2024-04-02 18:14:16】不启用方通过：本质上是几个字符串里选一个
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -385,8 +573,9 @@ pub enum InputMode {
 
 /// 转译器组合
 /// * 🚩【2024-04-01 11:20:36】目前使用「字符串+内置模糊匹配」进行有限的「转译器支持」
-///   * 🚧尚不支持自定义转译器
-#[derive(Serialize, Deserialize)]
+/// * ✨亦可在配置中声明自定义转译器，参见[`LaunchConfigCustomTranslator`]
+///   * 🎯无需重新编译，即可接入实验性的NARS实现
+#[derive(Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)] // 🔗参考：<https://serde.rs/enum-representations.html#untagged>
 #[serde(rename_all = "camelCase")] // 🔗参考：<https://serde.rs/container-attrs.html>
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -401,17 +590,80 @@ pub enum LaunchConfigTranslators {
         #[serde(rename = "out")]
         output: String,
     },
+
+    /// 🚩含`input`、`output`的对象⇒自定义转译器
+    Custom(LaunchConfigCustomTranslator),
+}
+
+impl LaunchConfigTranslators {
+    /// 转译器的展示名
+    /// * 🎯在测试报告等处标识所用的CIN
+    /// * 🚩输入输出相同⇒单个名称；不同⇒`输入/输出`
+    pub fn name(&self) -> String {
+        match self {
+            Self::Same(name) => name.clone(),
+            Self::Separated { input, output } if input == output => input.clone(),
+            Self::Separated { input, output } => format!("{input}/{output}"),
+            Self::Custom(..) => "custom".into(),
+        }
+    }
+}
+
+/// 自定义转译器
+/// * 🎯以声明的方式定义转译器：输入模板 + 输出正则规则
+/// * 🔗具体转译逻辑参见[`custom_translators`](crate::cli::custom_translators)
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LaunchConfigCustomTranslator {
+    /// 输入模板
+    /// * 📌键：NAVM指令的头部，如`NSE`、`CYC`、`VOL`
+    /// * 📌值：发送给CIN的文本；`{head}`、`{tail}`替换为指令的头部、参数
+    ///   * 📄`{ "NSE": "{tail}", "CYC": ":c {tail}" }`
+    /// * ⚠️没有对应模板的指令不受支持（同内置转译器）
+    pub input: BTreeMap<String, String>,
+
+    /// 输出规则
+    /// * 🚩按顺序尝试，取第一条匹配的规则
+    /// * 📌都不匹配⇒`OTHER`
+    pub output: Vec<LaunchConfigOutputRule>,
+}
+
+/// 自定义转译器的输出规则
+/// * 🎯将CIN输出的一行文本映射为NAVM输出
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LaunchConfigOutputRule {
+    /// 正则表达式
+    /// * 📌可用的命名捕获组
+    ///   * `content`：输出内容（缺省为整行）
+    ///   * `narsese`：其中的Narsese（按ASCII格式解析，解析失败则忽略）
+    ///   * `operator`：操作名（`EXE`必需）
+    ///   * `params`：操作参数，以逗号分隔的词项
+    /// * 📄`^Answer: (?<narsese>.+?) %`
+    pub pattern: String,
+
+    /// NAVM输出类型
+    /// * 📄`ANSWER`、`OUT`、`EXE`、`INFO`
+    /// * 📌非内置类型⇒`UNCLASSIFIED`，保留类型名
+    #[serde(rename = "type")]
+    pub output_type: String,
 }
 
 /// 启动命令
 /// * ❓后续可能支持「自动搜索」
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")] // 🔗参考：<https://serde.rs/container-attrs.html>
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct LaunchConfigCommand {
     /// 命令
     /// * 直接对应[`std::process::Command`]
     /// * 🚩[`Default`]中默认对应空字串
+    /// * 🚩缺省⇒空字串：命名档案、被继承的配置中可只填写部分字段
+    ///   * 📌合并时从被合并者补全；启动时仍为空⇒报错
+    ///   * 📌空字串不参与序列化：视作未设置（如在来源追踪中）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub cmd: String,
 
     /// 命令的参数（可选）
@@ -422,10 +674,25 @@ pub struct LaunchConfigCommand {
     /// * 🚩【2024-04-07 10:13:59】现在用于「基于配置文件的相对路径」
     ///   * 📌被主程序在启动时用于「设置自身工作目录」
     pub current_dir: Option<PathBuf>,
+
+    /// 环境变量（可选）
+    /// * 🎯为CIN设置`JAVA_OPTS`、`PYTHONPATH`、`NODE_OPTIONS`等
+    /// * 🚩合并时按变量名逐个合并
+    pub env: Option<BTreeMap<String, String>>,
+
+    /// 要移除的环境变量（可选）
+    /// * 🚩子进程不继承这些环境变量
+    /// * 📌优先于被合并者中设置的同名环境变量
+    pub env_remove: Option<Vec<String>>,
+
+    /// 清空继承的环境变量（可选）
+    /// * 🚩子进程仅保留`env`中设置的环境变量
+    /// * 📜默认值：`false`
+    pub clear_env: Option<bool>,
 }
 
 /// Websocket参数
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")] // 🔗参考：<https://serde.rs/container-attrs.html>
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct LaunchConfigWebsocket {
@@ -445,7 +712,7 @@ pub struct LaunchConfigWebsocket {
 /// 预置NAL
 /// * 🚩在CLI启动后自动执行
 /// * 📝[`serde`]允许对枚举支持序列化/反序列化
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")] // 🔗参考：<https://serde.rs/container-attrs.html>
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LaunchConfigPreludeNAL {
@@ -460,6 +727,114 @@ pub enum LaunchConfigPreludeNAL {
     /// * 📌类型：纯文本（允许换行等）
     #[serde(rename = "text")]
     Text(String),
+
+    /// 从通配符匹配的所有文件导入
+    /// * 📌键名：`glob`
+    /// * 📌类型：通配符模式，如`tests/nal/*.nal`
+    /// * 🚩执行前展开为多个文件，按路径排序
+    #[serde(rename = "glob")]
+    Glob(String),
+}
+
+impl LaunchConfigPreludeNAL {
+    /// 展开通配符
+    /// * 🚩通配符⇒所有匹配的文件（按路径排序）；其它⇒自身
+    /// * ⚠️通配符无效、未匹配到任何文件⇒报错
+    pub fn expand(&self) -> Result<Vec<Self>> {
+        let Self::Glob(pattern) = self else {
+            return Ok(vec![self.clone()]);
+        };
+        let mut paths = glob::glob(pattern)
+            .map_err(|e| anyhow!(messages::invalid_glob(pattern, &e)))?
+            .filter_map(Result::ok)
+            .filter(|path| path.is_file())
+            .collect::<Vec<_>>();
+        if_return! { paths.is_empty() => Err(anyhow!(messages::glob_no_match(pattern))) }
+        paths.sort();
+        Ok(paths.into_iter().map(Self::File).collect())
+    }
+}
+
+/// 展示预置NAL来源
+/// * 🎯用于预置NAL的执行报告
+/// * 🚩纯文本仅展示首行
+impl std::fmt::Display for LaunchConfigPreludeNAL {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Text(text) => match text.lines().next() {
+                Some(line) if line.len() < text.trim_end().len() => write!(f, "text: {line} ..."),
+                line => write!(f, "text: {}", line.unwrap_or_default()),
+            },
+            Self::Glob(pattern) => write!(f, "glob: {pattern}"),
+        }
+    }
+}
+
+/// 预置NAL（一个或多个来源）
+/// * 🎯兼容旧有的「单个来源」写法，同时支持有序的来源列表
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)] // 🔗参考：<https://serde.rs/enum-representations.html#untagged>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LaunchConfigPreludeNALs {
+    /// 🚩单个来源
+    Single(LaunchConfigPreludeNAL),
+
+    /// 🚩有序的来源列表
+    Multiple(Vec<LaunchConfigPreludeNAL>),
+}
+
+impl LaunchConfigPreludeNALs {
+    /// 所有来源
+    pub fn sources(&self) -> &[LaunchConfigPreludeNAL] {
+        match self {
+            Self::Single(source) => std::slice::from_ref(source),
+            Self::Multiple(sources) => sources,
+        }
+    }
+
+    /// 所有来源（可变）
+    pub fn sources_mut(&mut self) -> &mut [LaunchConfigPreludeNAL] {
+        match self {
+            Self::Single(source) => std::slice::from_mut(source),
+            Self::Multiple(sources) => sources,
+        }
+    }
+
+    /// 所有来源，但基于所有权
+    pub fn into_sources(self) -> Vec<LaunchConfigPreludeNAL> {
+        match self {
+            Self::Single(source) => vec![source],
+            Self::Multiple(sources) => sources,
+        }
+    }
+}
+
+impl From<LaunchConfigPreludeNAL> for LaunchConfigPreludeNALs {
+    fn from(source: LaunchConfigPreludeNAL) -> Self {
+        Self::Single(source)
+    }
+}
+
+impl From<Vec<LaunchConfigPreludeNAL>> for LaunchConfigPreludeNALs {
+    fn from(sources: Vec<LaunchConfigPreludeNAL>) -> Self {
+        Self::Multiple(sources)
+    }
+}
+
+/// 预置NAL的合并方式
+/// * 📜默认值：`replace`
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PreludeNALMerge {
+    /// 替换：有自身的预置NAL时，忽略被合并者的
+    /// * 📜默认值
+    #[default]
+    Replace,
+    /// 追加：将自身的预置NAL追加到被合并者的之后
+    /// * 📌被合并者（优先级较低者）先执行
+    Append,
 }
 
 /// 启动配置
@@ -484,6 +859,18 @@ impl LaunchConfig {
         Ok(deser_hjson::from_str(json)?)
     }
 
+    /// （尝试）从TOML字符串构造
+    /// * 🔗有关`toml`格式：<https://toml.io>
+    pub fn from_toml_str(toml: &str) -> Result<Self> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    /// （尝试）从YAML字符串构造
+    /// * 🔗有关`yaml`格式：<https://yaml.org>
+    pub fn from_yaml_str(yaml: &str) -> Result<Self> {
+        Ok(serde_norway::from_str(yaml)?)
+    }
+
     /// 判断其自身是否需要用户填充
     /// * 🎯用于在「启动NAVM运行时」时避免「参数无效」情况
     /// * 📌原则：必填参数不能为空
@@ -566,9 +953,23 @@ impl LaunchConfig {
         if let Some(root) = config_path.parent() {
             self.config_path = Some(root.to_path_buf());
         }
-        // 预加载NAL
-        if let Some(LaunchConfigPreludeNAL::File(ref mut path)) = &mut self.prelude_nal {
-            Self::rebase_relative_path(config_path, path)?;
+        // 预加载NAL | 文件路径、通配符
+        for source in self
+            .prelude_nal
+            .iter_mut()
+            .flat_map(LaunchConfigPreludeNALs::sources_mut)
+        {
+            match source {
+                LaunchConfigPreludeNAL::File(path) => {
+                    Self::rebase_relative_path(config_path, path)?
+                }
+                LaunchConfigPreludeNAL::Glob(pattern) => {
+                    let mut path = PathBuf::from(&*pattern);
+                    Self::rebase_relative_path(config_path, &mut path)?;
+                    *pattern = path.to_string_lossy().into_owned();
+                }
+                LaunchConfigPreludeNAL::Text(..) => {}
+            }
         }
         // 启动命令
         if let Some(LaunchConfigCommand {
@@ -578,6 +979,14 @@ impl LaunchConfig {
         {
             Self::rebase_relative_path(config_path, path)?;
         }
+        // 继承的配置文件
+        for path in self.extends.iter_mut().flatten() {
+            Self::rebase_relative_path(config_path, path)?;
+        }
+        // 命名档案 | 同样基于配置文件自身
+        for profile in self.profiles.iter_mut().flat_map(BTreeMap::values_mut) {
+            profile.rebase_relative_path_from(config_path)?;
+        }
         // 返回成功
         Ok(())
     }
@@ -599,34 +1008,170 @@ impl LaunchConfig {
         // 合并所有内部Option | 使用工具宏简化语法
         coalesce_clones! {
             Self: other => self;
+            lang
             translators
             websocket
-            prelude_nal
             user_input
             input_mode
             auto_restart
             strict_mode
             short_float_epoch
+            pipe_grace_period
             // 递归合并所有【含有可选键】的值
             command => {
                 LaunchConfigCommand::merge_as_key(&mut self.command, &other.command);
             }
+            // 按合并方式替换/追加 | ⚠️须在合并「合并方式」之前
+            prelude_nal => {
+                self.merge_prelude_nal(other);
+            }
+            prelude_nal_merge
             // 以下字段仍然保留自身数据
             config_path => {}
             description => {}
+            // 继承关系已在读取时展开，不参与合并
+            extends => {}
+            // 按档案名逐个合并
+            profiles => {
+                Self::merge_profiles(&mut self.profiles, &other.profiles);
+            }
         };
     }
+
+    /// 合并预置NAL
+    /// * 🚩替换（默认）⇒自身没有时才拷贝
+    /// * 🚩追加⇒被合并者的来源在前，自身的来源在后
+    fn merge_prelude_nal(&mut self, other: &Self) {
+        let merge = self.prelude_nal_merge.unwrap_or_default();
+        match (&mut self.prelude_nal, &other.prelude_nal, merge) {
+            (Some(prelude_nal), Some(other_prelude_nal), PreludeNALMerge::Append) => {
+                let sources = other_prelude_nal
+                    .sources()
+                    .iter()
+                    .chain(prelude_nal.sources())
+                    .cloned()
+                    .collect::<Vec<_>>();
+                *prelude_nal = sources.into();
+            }
+            (prelude_nal, other_prelude_nal, _) => prelude_nal.coalesce_clone(other_prelude_nal),
+        }
+    }
+
+    /// 合并命名档案
+    /// * 🚩自身没有的档案⇒直接拷贝
+    /// * 🚩双方都有的档案⇒递归合并
+    fn merge_profiles(
+        profiles: &mut Option<BTreeMap<String, Self>>,
+        other: &Option<BTreeMap<String, Self>>,
+    ) {
+        let Some(other) = other else { return };
+        let profiles = profiles.get_or_insert_with(BTreeMap::new);
+        for (name, profile_other) in other {
+            match profiles.get_mut(name) {
+                Some(profile) => profile.merge_from(profile_other),
+                None => {
+                    profiles.insert(name.clone(), profile_other.clone());
+                }
+            }
+        }
+    }
+
+    /// 获取所有命名档案的名称
+    /// * 🚩按名称排序
+    pub fn profile_names(&self) -> impl Iterator<Item = &str> {
+        self.profiles
+            .iter()
+            .flat_map(BTreeMap::keys)
+            .map(String::as_str)
+    }
+
+    /// 选中命名档案，生成新的配置
+    /// * 🚩档案 > 顶层配置
+    ///   * 📌描述：档案自身的描述优先，缺省时使用顶层配置的描述
+    /// * 🚩选中后的配置不再含有任何档案
+    /// * ⚠️档案不存在⇒报错，并列出所有可用的档案
+    pub fn with_profile(&self, name: &str) -> Result<Self> {
+        let profile = self
+            .profiles
+            .as_ref()
+            .and_then(|profiles| profiles.get(name))
+            .ok_or_else(|| {
+                let names = self.profile_names().collect::<Vec<_>>();
+                match names.is_empty() {
+                    true => anyhow!(messages::no_profiles(name)),
+                    false => anyhow!(messages::unknown_profile(name, &names.join(", "))),
+                }
+            })?;
+        // 档案覆盖于顶层配置之上
+        let mut result = profile.clone();
+        result.merge_from(self);
+        result.config_path.coalesce_clone(&self.config_path);
+        result.description.coalesce_clone(&self.description);
+        // 不再含有档案与继承关系
+        result.profiles = None;
+        result.extends = None;
+        Ok(result)
+    }
 }
 
 impl LaunchConfigCommand {
     /// 从另一个配置中并入配置
     /// * 🚩`Some(..)` => `None`
+    /// * 🚩环境变量按变量名逐个合并
     pub fn merge_from(&mut self, other: &Self) {
         coalesce_clones! {
             Self: other => self;
             cmd_args
             current_dir
-            cmd => {}
+            clear_env
+            cmd => {
+                self.merge_cmd(other);
+            }
+            // ⚠️须在合并「要移除的环境变量」之前
+            env => {
+                self.merge_env(other);
+            }
+            env_remove => {
+                self.merge_env_remove(other);
+            }
+        }
+    }
+
+    /// 合并命令
+    /// * 🚩空字串视作未设置⇒从被合并者拷贝
+    fn merge_cmd(&mut self, other: &Self) {
+        if self.cmd.is_empty() {
+            self.cmd.clone_from(&other.cmd);
+        }
+    }
+
+    /// 合并环境变量
+    /// * 🚩自身没有的变量⇒从被合并者拷贝
+    /// * 📌自身要移除的变量⇒不拷贝
+    fn merge_env(&mut self, other: &Self) {
+        let Some(other_env) = &other.env else { return };
+        let removed = self.env_remove.as_deref().unwrap_or_default();
+        let env = self.env.get_or_insert_with(BTreeMap::new);
+        for (name, value) in other_env {
+            if !env.contains_key(name) && !removed.contains(name) {
+                env.insert(name.clone(), value.clone());
+            }
+        }
+    }
+
+    /// 合并要移除的环境变量
+    /// * 🚩取并集
+    /// * 📌自身设置了的变量⇒不移除
+    fn merge_env_remove(&mut self, other: &Self) {
+        let Some(other_env_remove) = &other.env_remove else {
+            return;
+        };
+        let env_remove = self.env_remove.get_or_insert_with(Vec::new);
+        for name in other_env_remove {
+            let set_by_self = self.env.as_ref().is_some_and(|env| env.contains_key(name));
+            if !set_by_self && !env_remove.contains(name) {
+                env_remove.push(name.clone());
+            }
         }
     }
 
@@ -647,69 +1192,199 @@ impl LaunchConfigCommand {
 /// * 🎯错误处理 & 错误⇒空置
 /// * 🚩在遇到错误时会发出警告
 /// * ⚠️若无需打印警告（并手动处理错误），请使用[`read_config_extern`]
+/// * 💡若需定位配置中的具体问题，请使用[`crate::cli::validate_config_file`]
 /// * ⚠️其中的所有**相对路径**，在[`read_config_extern`]中都基于**配置文件自身**
 ///   * 🎯不论CLI自身所处何处，均保证配置读取稳定
 pub fn load_config_extern(path: &Path) -> Option<LaunchConfig> {
+    load_config_layers_extern(path).map(merge_config_layers)
+}
+
+/// 从外部配置文件中加载所有配置层
+/// * 🎯在[`load_config_extern`]的基础上，保留各配置层的来源
+///   * 📄用于展示「各配置项来自何处」
+/// * 🚩在遇到错误时会发出警告，并返回[`None`]
+pub fn load_config_layers_extern(path: &Path) -> Option<Vec<ConfigLayer>> {
     // Ok⇒Some，Err⇒警告+None
-    read_config_extern(path).ok_or_run(|e| {
-        // 根据错误类型进行分派 //
-        // 文件读写错误
-        if let Some(e) = e.downcast_ref::<std::io::Error>() {
-            match e.kind() {
-                std::io::ErrorKind::NotFound => {
-                    println_cli!([Warn] "未在路径 {path:?} 找到外部配置，返回空配置……");
-                }
-                _ => println_cli!([Warn] "读取外部配置时出现预期之外的错误: {}", e),
+    read_config_layers(path).ok_or_run(|e| warn_config_error(path, e))
+}
+
+/// 对「加载外部配置时的错误」发出警告
+/// * 🚩根据错误类型进行分派
+fn warn_config_error(path: &Path, e: anyhow::Error) {
+    // 错误发生在被继承的配置中⇒先展示完整的错误链
+    if e.chain().count() > 1 {
+        println_cli!([Warn] "{e:#}");
+    }
+    // 根据错误类型进行分派 //
+    // 文件读写错误
+    if let Some(e) = e.downcast_ref::<std::io::Error>() {
+        match e.kind() {
+            std::io::ErrorKind::NotFound => {
+                println_cli!([Warn] "{}", messages::config_not_found(path));
             }
+            _ => println_cli!([Warn] "{}", messages::config_read_unexpected(e)),
         }
-        // 配置解析错误/serde
-        else if let Some(e) = e.downcast_ref::<serde_json::Error>() {
-            match e.classify() {
-                serde_json::error::Category::Syntax => {
-                    println_cli!([Warn] "外部配置文件格式错误，返回空配置……");
-                }
-                _ => println_cli!([Warn] "解析外部配置时出现预期之外的错误: {}", e),
+    }
+    // 配置解析错误/serde
+    else if let Some(e) = e.downcast_ref::<serde_json::Error>() {
+        match e.classify() {
+            serde_json::error::Category::Syntax => {
+                println_cli!([Warn] "{}", messages::config_syntax_error(e));
             }
+            _ => println_cli!([Warn] "{}", messages::config_parse_unexpected(e)),
         }
-        // 配置解析错误/hjson
-        else if let Some(e) = e.downcast_ref::<deser_hjson::Error>() {
-            match e {
-                deser_hjson::Error::Syntax { .. } => {
-                    println_cli!([Warn] "外部配置文件格式错误，使用空配置……");
-                }
-                deser_hjson::Error::Io { .. } => {
-                    println_cli!([Warn] "外部配置文件读取错误，使用空配置……");
-                }
-                _ => println_cli!([Warn] "解析外部配置时出现预期之外的错误: {}", e),
+    }
+    // 配置解析错误/hjson
+    else if let Some(e) = e.downcast_ref::<deser_hjson::Error>() {
+        match e {
+            deser_hjson::Error::Syntax { .. } => {
+                println_cli!([Warn] "{}", messages::config_syntax_error(e));
             }
+            deser_hjson::Error::Io { .. } => {
+                println_cli!([Warn] "{}", messages::config_io_error(e));
+            }
+            _ => println_cli!([Warn] "{}", messages::config_parse_unexpected(e)),
         }
-        // 其它
-        else {
-            println_cli!([Warn] "加载外部配置时出现预期之外的错误: {}", e)
+    }
+    // 配置解析错误/toml
+    else if let Some(e) = e.downcast_ref::<toml::de::Error>() {
+        // * 🚩TOML错误信息中自带出错位置（行列号与代码片段）
+        println_cli!([Warn] "{}", messages::toml_syntax_error(e));
+    }
+    // 配置解析错误/yaml
+    else if let Some(e) = e.downcast_ref::<serde_norway::Error>() {
+        match e.location() {
+            Some(location) => println_cli!(
+                [Warn] "{}",
+                messages::yaml_syntax_error(location.line(), location.column(), e)
+            ),
+            None => println_cli!([Warn] "{}", messages::yaml_parse_unexpected(e)),
         }
-        // 空置
-    })
+    }
+    // 其它
+    else {
+        println_cli!([Warn] "{}", messages::config_load_unexpected(&e))
+    }
 }
 
-/// 从外部JSON文件中读取启动配置
+/// 从外部配置文件中读取启动配置
 /// * 🎯仅涉及具体读取逻辑，不涉及错误处理
+/// * 🚩根据（补全后的）扩展名选择解析格式，参见[`ConfigFormat`]
+/// * 🚩递归加载其继承的配置（`extends`），并按优先级合并
+///   * 📌合并后的配置中，[`LaunchConfig::extends`]被置空
 /// * ⚠️其中的所有**相对路径**，在[`read_config_extern`]中都基于**配置文件自身**
 ///   * 🎯不论CLI自身所处何处，均保证配置读取稳定
 pub fn read_config_extern(path: &Path) -> Result<LaunchConfig> {
+    // 读取所有配置层，并按优先级合并
+    let layers = read_config_layers(path)?;
+    Ok(merge_config_layers(layers))
+}
+
+/// 配置层
+/// * 🎯记录「单个配置文件自身的内容」及其来源
+/// * 📌其中的配置**未**与被继承者合并
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigLayer {
+    /// 配置文件的路径（已补全扩展名）
+    pub path: PathBuf,
+    /// 配置文件自身的内容
+    pub config: LaunchConfig,
+}
+
+impl ConfigLayer {
+    /// 重新读取配置文件的原始内容
+    /// * 🚩只解析，不插值、不变基
+    /// * 🎯供搜索索引缓存摘要：不含由环境变量等插值得来的值
+    pub fn read_raw(&self) -> Result<LaunchConfig> {
+        ConfigFormat::from_path(&self.path).parse(&read_to_string(&self.path)?)
+    }
+}
+
+/// 合并一系列配置层
+/// * 🚩以第一层为基础，后续层依次向其中并入
+///   * 📌即：越靠前，优先级越高
+/// * 🚩合并后置空[`LaunchConfig::extends`]：继承关系已展开
+pub fn merge_config_layers(layers: impl IntoIterator<Item = ConfigLayer>) -> LaunchConfig {
+    let mut layers = layers.into_iter();
+    let mut result = layers.next().map(|layer| layer.config).unwrap_or_default();
+    for layer in layers {
+        result.merge_from(&layer.config);
+    }
+    result.extends = None;
+    result
+}
+
+/// 读取配置文件及其继承的所有配置文件
+/// * 🚩返回的配置层按优先级从高到低排列（深度优先）
+///   * 📄`a`继承`[b, c]`，`b`继承`[d]` ⇒ `[a, b, d, c]`
+/// * ⚠️检测循环继承：若某配置文件出现在自身的继承链上，则返回错误
+///   * 📌错误信息中附带完整的继承链
+///   * 📌「菱形继承」（多个配置继承同一个配置）不视作循环
+pub fn read_config_layers(path: &Path) -> Result<Vec<ConfigLayer>> {
+    let mut layers = vec![];
+    read_config_layers_chained(path, &mut vec![], &mut layers)?;
+    Ok(layers)
+}
+
+/// 带「继承链」地递归读取配置层
+/// * 🎯检测循环继承
+/// * 📌继承链中存放的是规范化（[`Path::canonicalize`]）后的路径
+fn read_config_layers_chained(
+    path: &Path,
+    chain: &mut Vec<PathBuf>,
+    layers: &mut Vec<ConfigLayer>,
+) -> Result<()> {
+    // 规范化路径，以便判等
+    let completed_path = try_complete_path(path);
+    let canonical_path = completed_path.canonicalize()?;
+    // 检测循环继承
+    if chain.contains(&canonical_path) {
+        let chain_str = chain
+            .iter()
+            .chain([&canonical_path])
+            .map(|path| format!("{path:?}"))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        return Err(anyhow!(messages::circular_extends(&chain_str)));
+    }
+    // 读取配置自身，并记录
+    let config = read_config_single(path)?;
+    let parents = config.extends.clone().unwrap_or_default();
+    layers.push(ConfigLayer {
+        path: completed_path,
+        config,
+    });
+    // 递归读取被继承者
+    chain.push(canonical_path);
+    for parent in parents {
+        read_config_layers_chained(&parent, chain, layers)
+            .map_err(|e| e.context(messages::extends_failed(path, &parent)))?;
+    }
+    chain.pop();
+    Ok(())
+}
+
+/// 从外部配置文件中读取**单个**启动配置
+/// * 🚩不处理继承关系：其中的`extends`仅作变基，不作加载
+fn read_config_single(path: &Path) -> Result<LaunchConfig> {
+    // 尝试补全路径，并据此确定格式
+    let completed_path = try_complete_path(path);
+    let format = ConfigFormat::from_path(&completed_path);
     // 尝试读取外部启动配置，并尝试解析
     pipe! {
-        path
-        // 尝试补全路径
-        => try_complete_path
+        &completed_path
         // 尝试读取文件内容
         => read_to_string
         => {?}#
-        // 尝试解析JSON配置
+        // 尝试按格式解析配置
         => #{&}
-        => LaunchConfig::from_json_str
+        => [format.parse](_)
+        => {?}#
+        // 变量插值：需在变基之前，以便`${CONFIG_DIR}`等生成绝对路径
+        => .interpolated(&InterpolateContext::from_config_path(&completed_path))
         => {?}#
         // 变基相对路径，从「基于CLI自身」到「基于配置文件自身」
-        => .rebase_path_from_owned(path.parent().ok_or(anyhow!("无效的根路径！"))?)
+        => .rebase_path_from_owned(path.parent().ok_or_else(|| anyhow!(messages::invalid_root_path()))?)
         => {?}#
         // 返回Ok（转换为`anyhow::Result`）
         => Ok
@@ -720,7 +1395,7 @@ pub fn read_config_extern(path: &Path) -> Result<LaunchConfig> {
 }
 
 /// 尝试对无扩展名的路径添加扩展名
-/// * 🎯用于自动匹配`.json`与`.hjson`
+/// * 🎯用于自动匹配`.json`、`.hjson`、`.toml`、`.yaml`与`.yml`
 /// * ❌不能用于「多扩展名」的情况，如`BabelNAR.launch`
 ///   * 此处会认定是「有扩展名」而不会补全
 pub fn try_complete_path(path: &Path) -> PathBuf {
@@ -790,13 +1465,14 @@ pub mod tests {
                 command: Some(LaunchConfigCommand {
                     cmd: "java".into(),
                     cmd_args: Some(vec!["-Xmx1024m".into(), "-jar".into(), "nars.jar".into()]),
-                    current_dir: Some("root/nars/test".into())
+                    current_dir: Some("root/nars/test".into()),
+                    ..Default::default()
                 }),
                 websocket: Some(LaunchConfigWebsocket{
                     host: "localhost".into(),
                     port: 8080
                 }),
-                prelude_nal: Some(LaunchConfigPreludeNAL::Text("'/VOL 0".into())),
+                prelude_nal: Some(LaunchConfigPreludeNAL::Text("'/VOL 0".into()).into()),
                 ..Default::default()
             }
             // 测试`translators`、`prelude_nal`的其它枚举
@@ -821,7 +1497,7 @@ pub mod tests {
                     cmd: "root/nars/open_ona.exe".into(),
                     ..Default::default()
                 }),
-                prelude_nal: Some(LaunchConfigPreludeNAL::File("root/nars/prelude.nal".into())),
+                prelude_nal: Some(LaunchConfigPreludeNAL::File("root/nars/prelude.nal".into()).into()),
                 ..Default::default()
             }
             r#"
@@ -857,6 +1533,66 @@ pub mod tests {
         */
     }
 
+    /// 测试/TOML与YAML的解析
+    /// * 🎯不同格式应解析出相同的启动配置
+    #[test]
+    fn test_parse_formats() {
+        let expected = LaunchConfig {
+            translators: Some(LaunchConfigTranslators::Separated {
+                input: "opennars".into(),
+                output: "ona".into(),
+            }),
+            command: Some(LaunchConfigCommand {
+                cmd: "java".into(),
+                cmd_args: Some(vec!["-jar".into(), "nars.jar".into()]),
+                ..Default::default()
+            }),
+            websocket: Some(LaunchConfigWebsocket {
+                host: "localhost".into(),
+                port: 8080,
+            }),
+            strict_mode: Some(true),
+            short_float_epoch: Some(0.01),
+            ..Default::default()
+        };
+        let toml = r#"
+            strictMode = true
+            shortFloatEpoch = 0.01
+            translators = { in = "opennars", out = "ona" }
+            [command]
+            cmd = "java"
+            cmdArgs = ["-jar", "nars.jar"]
+            [websocket]
+            host = "localhost"
+            port = 8080
+        "#;
+        let yaml = r#"
+            strictMode: true
+            shortFloatEpoch: 0.01
+            translators:
+                in: opennars
+                out: ona
+            command:
+                cmd: java
+                cmdArgs: [-jar, nars.jar]
+            websocket:
+                host: localhost
+                port: 8080
+        "#;
+        asserts! {
+            ConfigFormat::Toml.parse(toml).expect("TOML解析失败") => expected.clone()
+            ConfigFormat::Yaml.parse(yaml).expect("YAML解析失败") => expected
+            // 扩展名识别
+            ConfigFormat::from_path(Path::new("a.TOML")) => ConfigFormat::Toml
+            ConfigFormat::from_path(Path::new("a.yml")) => ConfigFormat::Yaml
+            ConfigFormat::from_path(Path::new("a.json")) => ConfigFormat::Hjson
+            ConfigFormat::from_path(Path::new("BabelNAR-launch")) => ConfigFormat::Hjson
+        }
+        // 格式错误
+        assert!(ConfigFormat::Toml.parse("translators = ").is_err());
+        assert!(ConfigFormat::Yaml.parse("command: [").is_err());
+    }
+
     /// 测试/解析转换失败
     /// * 🎯JSON/HJSON的解析转换逻辑
     /// * 🚩对「精度检验」仅用于「转换到运行时参数」的逻辑
@@ -905,6 +1641,12 @@ pub mod tests {
             r#"{
                 "shortFloatEpoch": -Infinity,
             }"#
+            // 无效的管道输入宽限期 | 必选项齐全，只因宽限期失败
+            r#"{
+                "translators": "opennars",
+                "command": { "cmd": "java" },
+                "pipeGracePeriod": -1,
+            }"#
         }
         /*
         "file": "root/path/to/file"
@@ -931,4 +1673,226 @@ pub mod tests {
             launch_config.command.unwrap().current_dir => Some(expected_path)
         }
     }
+
+    /// 测试/配置继承
+    /// * 🎯自身 > 第一个被继承者 > 第二个被继承者
+    /// * 🎯继承链中的相对路径仍基于各自的配置文件
+    #[test]
+    fn test_read_extends() {
+        let config =
+            read_config_extern(Path::new(config_paths::EXTENDS_TEST)).expect("配置读取失败");
+        let base =
+            read_config_extern(Path::new(config_paths::ARG_PARSE_TEST)).expect("配置读取失败");
+        asserts! {
+            // 自身的配置优先
+            config.websocket => Some(LaunchConfigWebsocket {
+                host: "127.0.0.1".into(),
+                port: 3040,
+            })
+            config.strict_mode => Some(true)
+            // 被继承者的配置补充进来
+            config.translators => base.translators
+            config.command => base.command
+            // 继承关系已展开
+            config.extends => None
+        }
+        // 配置层：自身在前，被继承者依次在后
+        let layers =
+            read_config_layers(Path::new(config_paths::EXTENDS_TEST)).expect("配置读取失败");
+        assert_eq!(layers.len(), 3);
+        assert!(layers[0].path.ends_with("_extends_test.hjson"));
+        assert!(layers[1].path.ends_with("_arg_parse_test.opennars.hjson"));
+        assert!(layers[2].path.ends_with("websocket.hjson"));
+    }
+
+    /// 测试/循环继承
+    /// * 🎯应报错，且错误信息中包含继承链
+    #[test]
+    fn test_read_extends_cycle() {
+        let e = read_config_extern(Path::new(config_paths::EXTENDS_CYCLE))
+            .expect_err("循环继承应该报错");
+        let message = format!("{e:#}");
+        dbg!(&message);
+        assert!(message.contains("循环继承"));
+        assert!(message.contains("_extends_cycle_a.hjson"));
+        assert!(message.contains("_extends_cycle_b.hjson"));
+    }
+
+    /// 测试/命名档案
+    /// * 🎯档案 > 顶层配置
+    /// * 🎯档案中的相对路径同样基于配置文件自身
+    #[test]
+    fn test_profiles() {
+        let config =
+            read_config_extern(Path::new(config_paths::PROFILES_TEST)).expect("配置读取失败");
+        let root = Path::new(config_paths::PROFILES_TEST).parent().unwrap();
+        let rebased = |relative: &str| {
+            let mut path = PathBuf::from(relative);
+            LaunchConfig::rebase_relative_path(root, &mut path).expect("路径变基失败");
+            path
+        };
+        asserts! {
+            config.profile_names().collect::<Vec<_>>() => ["serve", "strict"]
+        }
+        // 严格测试：仅附加字段，描述被覆盖
+        let strict = config.with_profile("strict").expect("档案选中失败");
+        asserts! {
+            strict.description => Some("严格测试".into())
+            strict.strict_mode => Some(true)
+            strict.user_input => Some(false)
+            strict.translators => config.translators.clone()
+            strict.command => config.command.clone()
+            strict.profiles => None
+        }
+        // Websocket服务：启动命令递归合并，描述沿用顶层
+        let serve = config.with_profile("serve").expect("档案选中失败");
+        let command = serve.command.clone().expect("缺少启动命令");
+        asserts! {
+            serve.description => config.description.clone()
+            serve.websocket.map(|w| w.port) => Some(8765)
+            command.cmd => "java"
+            command.cmd_args => Some(vec!["-Xmx1024m".into(), "-jar".into(), "nars.jar".into()])
+            command.current_dir => Some(rebased("./root/nars/serve"))
+        }
+        // 不存在的档案⇒报错，并列出可用的档案
+        let e = config
+            .with_profile("nonexistent")
+            .expect_err("不存在的档案应该报错");
+        assert!(e.to_string().contains("serve, strict"));
+    }
+
+    /// 测试/界面语言与多语言描述
+    /// * 🎯描述可为单个字符串或按语言区分的映射
+    /// * 🎯界面语言参与合并，自身优先
+    #[test]
+    fn test_localized() {
+        let config = LaunchConfig::from_json_str(
+            r#"{
+                lang: en
+                description: { zh: "默认", en: "Default" }
+                profiles: { strict: { description: "Strict" } }
+            }"#,
+        )
+        .expect("配置解析失败");
+        let description = config.description.clone().expect("缺少描述");
+        asserts! {
+            config.lang => Some(Locale::En)
+            description.get_in(Locale::Zh) => "默认"
+            description.get_in(Locale::En) => "Default"
+        }
+        let strict = config.with_profile("strict").expect("档案选中失败");
+        asserts! {
+            strict.lang => Some(Locale::En)
+            strict.description => Some("Strict".into())
+        }
+        // 界面语言：自身优先
+        let mut zh = LaunchConfig {
+            lang: Some(Locale::Zh),
+            ..Default::default()
+        };
+        zh.merge_from(&config);
+        assert_eq!(zh.lang, Some(Locale::Zh));
+    }
+
+    /// 测试/合并环境变量
+    /// * 🎯按变量名逐个合并，自身优先
+    /// * 🎯自身要移除的变量不被被合并者重新设置，反之亦然
+    #[test]
+    fn test_merge_env() {
+        let env = |pairs: &[(&str, &str)]| {
+            Some(
+                pairs
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect::<BTreeMap<_, _>>(),
+            )
+        };
+        let mut command = LaunchConfigCommand {
+            cmd: "java".into(),
+            env: env(&[("JAVA_OPTS", "-Xmx2048m"), ("NO_PROXY", "localhost")]),
+            env_remove: Some(vec!["PYTHONPATH".into()]),
+            ..Default::default()
+        };
+        command.merge_from(&LaunchConfigCommand {
+            cmd: "python".into(),
+            env: env(&[
+                ("JAVA_OPTS", "-Xmx1024m"),
+                ("LANG", "C"),
+                ("PYTHONPATH", "a"),
+            ]),
+            env_remove: Some(vec!["http_proxy".into(), "NO_PROXY".into()]),
+            clear_env: Some(true),
+            ..Default::default()
+        });
+        asserts! {
+            command.cmd => "java"
+            command.env => env(&[("JAVA_OPTS", "-Xmx2048m"), ("LANG", "C"), ("NO_PROXY", "localhost")])
+            command.env_remove => Some(vec!["PYTHONPATH".into(), "http_proxy".into()])
+            command.clear_env => Some(true)
+        }
+    }
+
+    /// 测试/多个预置NAL来源
+    /// * 🎯追加模式：被继承者的来源在前
+    /// * 🎯通配符同样基于配置文件自身，且展开后按路径排序
+    #[test]
+    fn test_prelude_nal_list() {
+        let config =
+            read_config_extern(Path::new(config_paths::PRELUDE_LIST_TEST)).expect("配置读取失败");
+        let sources = config.prelude_nal.expect("缺少预置NAL").into_sources();
+        let nal_dir = Path::new("./src/tests/nal").canonicalize().unwrap();
+        let nal_file = |name: &str| LaunchConfigPreludeNAL::File(nal_dir.join(name));
+        asserts! {
+            sources.len() => 3
+            sources[0] => nal_file("test_simple_deduction.nal")
+            sources[1] => LaunchConfigPreludeNAL::Text("'/VOL 0".into())
+        }
+        // 展开通配符
+        let expanded = sources[2].expand().expect("通配符展开失败");
+        assert_eq!(
+            expanded,
+            [
+                nal_file("test_higher_deduction.nal"),
+                nal_file("test_simple_deduction.nal"),
+            ]
+        );
+        // 未匹配到文件⇒报错；其它来源⇒原样返回
+        let unmatched =
+            LaunchConfigPreludeNAL::Glob(nal_dir.join("*.none").to_string_lossy().into());
+        assert!(unmatched.expand().is_err());
+        assert_eq!(sources[1].expand().unwrap(), [sources[1].clone()]);
+        // 替换模式（默认）：自身的来源覆盖被合并者
+        let mut replaced = LaunchConfig {
+            prelude_nal: Some(sources[1].clone().into()),
+            ..Default::default()
+        };
+        replaced.merge_from(&LaunchConfig {
+            prelude_nal: Some(sources[0].clone().into()),
+            ..Default::default()
+        });
+        assert_eq!(replaced.prelude_nal, Some(sources[1].clone().into()));
+        // 运行时配置中展平为列表
+        let runtime = RuntimeConfig::try_from(LaunchConfig {
+            translators: Some(LaunchConfigTranslators::Same("opennars".into())),
+            command: Some(LaunchConfigCommand {
+                cmd: "java".into(),
+                ..Default::default()
+            }),
+            ..replaced
+        })
+        .expect("运行时配置转换失败");
+        assert_eq!(runtime.prelude_nal, [sources[1].clone()]);
+    }
+
+    /// 测试/读取不同格式的配置文件
+    /// * 🎯内容相同的HJSON、TOML、YAML文件应读取出相同的配置
+    #[test]
+    fn test_read_formats() {
+        let read = |path: &str| read_config_extern(Path::new(path)).expect("配置读取失败");
+        let expected = read(config_paths::ARG_PARSE_TEST);
+        asserts! {
+            read(config_paths::ARG_PARSE_TEST_TOML) => expected.clone()
+            read(config_paths::ARG_PARSE_TEST_YAML) => expected
+        }
+    }
 }
@@ -3,7 +3,7 @@
 //! * 🎯实现专有的Websocket服务端逻辑
 
 use crate::{
-    cli::{LaunchConfigWebsocket, RuntimeConfig, RuntimeManager},
+    cli::{messages, LaunchConfigWebsocket, RuntimeManager, SharedConfig},
     eprintln_cli, if_let_err_eprintln_cli, println_cli,
     support::{
         error_handling_boost::error_anyhow,
@@ -15,10 +15,7 @@ use crate::{
 };
 use anyhow::Result;
 use navm::{output::Output, vm::VmRuntime};
-use std::{
-    sync::Arc,
-    thread::{self, JoinHandle},
-};
+use std::thread::{self, JoinHandle};
 use ws::{Factory, Handler, Sender};
 
 /// 工具宏：尝试执行，如果失败则上抛错误
@@ -64,10 +61,11 @@ where
     // 提取并合并地址
     let LaunchConfigWebsocket { host, port } = manager
         .config
+        .get()
         .websocket
-        .as_ref()
+        .clone()
         .expect("尝试在无配置时启动Websocket服务器");
-    let address = to_address(host, *port);
+    let address = to_address(&host, port);
 
     // 获取服务端「处理者工厂」
     // * 🚩拷贝[`Arc`]
@@ -104,13 +102,13 @@ where
         });
         (handle, sender)
     };
-    println_cli!([Info] "Websocket服务器已在 {:?} 启动", address);
+    println_cli!([Info] "{}", messages::ws_server_started(&address));
 
     // 向（服务端自身）「输出缓存」添加侦听器
     if_let_err_eprintln_cli! {
         // ! 此处需要可变的`manager`
         register_listener(&mut manager.output_cache, sender)
-        => e => [Error] "无法为服务端注册侦听器：{e}"
+        => e => [Error] "{}", messages::ws_register_listener_failed(&e)
     }
 
     // 返回线程句柄
@@ -128,7 +126,7 @@ where
     pub(crate) runtime: ArcMutex<R>,
 
     /// 所涉及的运行时配置
-    pub(crate) config: Arc<RuntimeConfig>,
+    pub(crate) config: SharedConfig,
 
     /// 所涉及的运行时
     pub(crate) output_cache: ArcMutex<OutputCache>,
@@ -144,22 +142,23 @@ where
     R: VmRuntime + Send + Sync + 'static,
 {
     fn on_shutdown(&mut self) {
-        println_cli!([Info] "Websocket连接已关停")
+        println_cli!([Info] "{}", messages::ws_shutdown())
     }
 
     fn on_open(&mut self, shake: ws::Handshake) -> ws::Result<()> {
         if let Some(addr) = shake.remote_addr()? {
-            println_cli!([Info] "Websocket连接已打开：{addr}")
+            println_cli!([Info] "{}", messages::ws_opened(&addr))
         }
         Ok(())
     }
 
     fn on_message(&mut self, msg: ws::Message) -> ws::Result<()> {
-        println_cli!([Debug] "Websocket收到消息：{msg}");
+        println_cli!([Debug] "{}", messages::ws_message(&msg));
         // 获取所需的参数信息 | 在此时独占锁
-        let runtime = &mut *try_or_return_err!(self.runtime.lock(); poison => "在Websocket连接中获取运行时失败：{poison}");
-        let config = &self.config;
-        let output_cache = &mut *try_or_return_err!(self.output_cache.lock(); err => "在Websocket连接中获取输出缓存失败：{err}");
+        let runtime = &mut *try_or_return_err!(self.runtime.lock(); poison => "{}", messages::ws_runtime_lock_failed(&poison));
+        let config = self.config.get();
+        let config = &*config;
+        let output_cache = &mut *try_or_return_err!(self.output_cache.lock(); err => "{}", messages::ws_output_cache_lock_failed(&err));
 
         // 输入信息，并监控缓存的新输出
         // * 📝【2024-04-08 22:10:17】现在查明「Websocket线程阻塞」问题在Websocket「回传发送者」的`send`调用中
@@ -171,14 +170,14 @@ where
                 output_cache,
                 &config.config_path
             )
-            => err => [Error] "在Websocket连接中输入「{msg}」时发生错误：{err}"
+            => err => [Error] "{}", messages::ws_input_failed(&msg, &err)
         }
 
         Ok(())
     }
 
     fn on_close(&mut self, code: ws::CloseCode, reason: &str) {
-        println_cli!([Info] "Websocket连接关闭（退出码：{code:?}；原因：「{reason}」）");
+        println_cli!([Info] "{}", messages::ws_closed(&code, reason));
     }
 
     fn on_error(&mut self, err: ws::Error) {
@@ -190,11 +189,11 @@ where
             }
         }
 
-        println_cli!([Error] "连接发生错误：{err:?}");
+        println_cli!([Error] "{}", messages::ws_connection_error(&err));
     }
 
     fn on_timeout(&mut self, event: ws::util::Token) -> ws::Result<()> {
-        println_cli!([Warn] "连接超时：{:?}", event);
+        println_cli!([Warn] "{}", messages::ws_timeout(&event));
         Ok(())
     }
 
@@ -213,7 +212,7 @@ where
     pub(crate) runtime: ArcMutex<R>,
 
     /// 所涉及的虚拟机配置
-    pub(crate) config: Arc<RuntimeConfig>,
+    pub(crate) config: SharedConfig,
 
     /// 所涉及的输出缓存
     pub(crate) output_cache: ArcMutex<OutputCache>,
@@ -232,7 +231,7 @@ pub(crate) fn broadcast_to_senders(
     // * 通过一个`broadcaster`直接向所有连接广播消息
     if_let_err_eprintln_cli! {
         broadcaster.send(output_str.to_string())
-        => e => [Error] "广播消息失败：{e}"
+        => e => [Error] "{}", messages::ws_broadcast_failed(&e)
     };
 
     // println_cli!([Debug] "✅向接收者回传消息完成：\n{output_str}");
@@ -253,7 +252,7 @@ pub(crate) fn register_listener(
         // 广播
         if_let_err_eprintln_cli! {
             broadcast_to_senders(&mut broadcaster, &output)
-            => e => [Error] "Websocket回传广播到发送者时出现错误：{:?}", e
+            => e => [Error] "{}", messages::ws_broadcast_to_senders_failed(&e)
         }
         // 返回
         Some(output)
@@ -269,7 +268,7 @@ where
 
     fn connection_made(&mut self, sender: Sender) -> Connection<R> {
         let id = sender.connection_id();
-        println_cli!([Info] "Websocket连接已在id {id} 处建立");
+        println_cli!([Info] "{}", messages::ws_connection_established(&id));
         // 返回连接
         Connection {
             runtime: self.runtime.clone(),
@@ -281,11 +280,11 @@ where
 
     fn on_shutdown(&mut self) {
         // 打印消息
-        println_cli!([Info] "Websocket服务器已关停")
+        println_cli!([Info] "{}", messages::ws_server_shutdown())
     }
 
     fn connection_lost(&mut self, handler: Self::Handler) {
-        eprintln_cli!([Error] "与id为 {} 的客户端断开连接！", handler.id);
+        eprintln_cli!([Error] "{}", messages::ws_client_disconnected(&handler.id));
     }
 }
 
@@ -0,0 +1,123 @@
+//! CIN种类注册表
+//! * 🎯统一管理所有内置的「CIN路径构建器」
+//!   * ✨不同CIN的搜索结果可以合并、一同排序
+//!   * ✨通过[`DynVmLauncher`]以同一条代码路径启动
+//! * 🚩[`CinKind`]：类型擦除的[`CinPathBuilder`]，对所有路径构建器自动实现
+//! * 🚩[`CIN_KINDS`]：按名称登记的所有CIN种类
+
+use crate::support::cin_search::{
+    dyn_vm::DynVmLauncher,
+    impls_path_builder::{
+        path_builder_cxin_js::PathBuilderCXinJS,
+        path_builder_nars_python::PathBuilderNARSPython,
+        path_builder_native::{PathBuilderNARust, PathBuilderNativeIL},
+        path_builder_ona::PathBuilderONA,
+        path_builder_openjunars::PathBuilderOpenJunars,
+        path_builder_opennars::PathBuilderOpenNARS,
+        path_builder_pynars::PathBuilderPyNARS,
+    },
+    path_builder::{CinLaunchCommand, CinPathBuilder},
+};
+use std::path::Path;
+
+/// CIN种类
+/// * 🎯以特征对象的形式使用[`CinPathBuilder`]
+///   * 📌[`CinPathBuilder`]带有关联类型，无法直接作为特征对象
+/// * 🚩启动器统一转换为[`DynVmLauncher`]
+pub trait CinKind: Sync {
+    /// CIN名称
+    /// * 🔗参见[`CinPathBuilder::name`]
+    fn name(&self) -> &'static str;
+
+    /// 所用内置转译器的名称
+    /// * 🔗参见[`CinPathBuilder::translators`]
+    fn translators(&self) -> &'static str;
+
+    /// 路径匹配
+    /// * 🔗参见[`CinPathBuilder::match_path`]
+    fn match_path(&self, path: &Path) -> usize;
+
+    /// 从某个路径生成启动命令
+    /// * 🔗参见[`CinPathBuilder::command_from_path`]
+    fn command_from_path(&self, path: &Path) -> CinLaunchCommand;
+
+    /// 从某个路径构建类型擦除的启动器
+    /// * 🔗参见[`CinPathBuilder::construct_from_path`]
+    fn launcher_from_path(&self, path: &Path) -> DynVmLauncher;
+}
+
+impl<B: CinPathBuilder + Sync> CinKind for B {
+    fn name(&self) -> &'static str {
+        CinPathBuilder::name(self)
+    }
+
+    fn translators(&self) -> &'static str {
+        CinPathBuilder::translators(self)
+    }
+
+    fn match_path(&self, path: &Path) -> usize {
+        CinPathBuilder::match_path(self, path)
+    }
+
+    fn command_from_path(&self, path: &Path) -> CinLaunchCommand {
+        CinPathBuilder::command_from_path(self, path)
+    }
+
+    fn launcher_from_path(&self, path: &Path) -> DynVmLauncher {
+        DynVmLauncher::new(self.construct_from_path(path))
+    }
+}
+
+/// 所有内置的CIN种类
+/// * 🚩新增路径构建器后，须在此登记
+/// * ⚠️名称须唯一：作为查找的依据
+pub const CIN_KINDS: &[&dyn CinKind] = &[
+    &PathBuilderOpenNARS,
+    &PathBuilderONA,
+    &PathBuilderPyNARS,
+    &PathBuilderCXinJS,
+    &PathBuilderOpenJunars,
+    &PathBuilderNARSPython,
+    &PathBuilderNARust,
+    &PathBuilderNativeIL,
+];
+
+/// 按名称查找CIN种类
+/// * 🚩忽略大小写
+pub fn cin_kind(name: &str) -> Option<&'static dyn CinKind> {
+    CIN_KINDS
+        .iter()
+        .copied()
+        .find(|kind| kind.name().eq_ignore_ascii_case(name))
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::TRANSLATOR_DICT;
+    use nar_dev_utils::asserts;
+    use std::collections::HashSet;
+
+    /// 测试/注册表
+    /// * 🎯名称唯一，且所用转译器均为内置转译器
+    #[test]
+    fn test_registry() {
+        let names = CIN_KINDS.iter().map(|kind| kind.name()).collect::<HashSet<_>>();
+        asserts! {
+            names.len() => CIN_KINDS.len()
+            cin_kind("ona").map(|kind| kind.name()) => Some("ONA")
+            cin_kind("narust-158").map(|kind| kind.translators()) => Some("Native")
+            cin_kind("NARS").is_none() => true
+        }
+        for kind in CIN_KINDS {
+            assert!(
+                TRANSLATOR_DICT
+                    .iter()
+                    .any(|(name, ..)| *name == kind.translators()),
+                "未知的转译器：{}",
+                kind.translators()
+            );
+        }
+    }
+}
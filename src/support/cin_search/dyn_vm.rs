@@ -0,0 +1,195 @@
+//! 类型擦除的NAVM启动器、运行时
+//! * 🎯让不同CIN的启动器可以放在同一个集合中，并通过同一条代码路径启动
+//!   * 📄ONA的[`ONA`](babel_nar::cin_implements::ona::ONA)与OpenNARS的[`OpenNARS`](babel_nar::cin_implements::opennars::OpenNARS)
+//! * 📝难点：[`VmLauncher::launch`]按值消耗`self`，无法直接在特征对象上调用
+//!   * 🚩解决：内部特征[`BoxedVmLauncher`]改以`self: Box<Self>`消耗自身，并为所有启动器自动实现
+//!   * 🔗技术参考：<https://stackoverflow.com/questions/46620790/how-to-call-a-method-that-consumes-self-on-a-boxed-trait-object>
+//! * 📌[`VmRuntime`]的方法均只借用自身，可直接装箱为特征对象
+
+use anyhow::Result;
+use babel_nar::runtimes::CommandVmRuntime;
+use navm::{
+    cmd::Cmd,
+    output::Output,
+    vm::{VmLauncher, VmRuntime, VmStatus},
+};
+
+/// 类型擦除的NAVM运行时
+/// * 🚩装箱任意满足[`Send`] + [`Sync`]的运行时
+///   * 📌以便交给[`RuntimeManager`](crate::cli::RuntimeManager)跨线程管理
+pub struct DynVmRuntime {
+    /// 被装箱的运行时
+    inner: Box<dyn VmRuntime + Send + Sync>,
+}
+
+impl DynVmRuntime {
+    /// 构造函数
+    pub fn new(runtime: impl VmRuntime + Send + Sync + 'static) -> Self {
+        Self {
+            inner: Box::new(runtime),
+        }
+    }
+}
+
+/// 从命令行虚拟机运行时装箱
+/// * 🎯自动重启时，以「命令行虚拟机」替换类型擦除的运行时
+impl From<CommandVmRuntime> for DynVmRuntime {
+    fn from(runtime: CommandVmRuntime) -> Self {
+        Self::new(runtime)
+    }
+}
+
+impl VmRuntime for DynVmRuntime {
+    fn input_cmd(&mut self, cmd: Cmd) -> Result<()> {
+        self.inner.input_cmd(cmd)
+    }
+
+    fn fetch_output(&mut self) -> Result<Output> {
+        self.inner.fetch_output()
+    }
+
+    fn try_fetch_output(&mut self) -> Result<Option<Output>> {
+        self.inner.try_fetch_output()
+    }
+
+    fn status(&self) -> &VmStatus {
+        self.inner.status()
+    }
+
+    fn terminate(&mut self) -> Result<()> {
+        self.inner.terminate()
+    }
+}
+
+/// 可在装箱后启动的启动器
+/// * 🎯绕过[`VmLauncher::launch`]的「按值消耗」
+/// * 🚩对所有「运行时满足[`Send`] + [`Sync`]」的启动器自动实现
+trait BoxedVmLauncher {
+    /// 消耗装箱的自身，启动为类型擦除的运行时
+    fn launch_boxed(self: Box<Self>) -> Result<DynVmRuntime>;
+}
+
+impl<L> BoxedVmLauncher for L
+where
+    L: VmLauncher,
+    L::Runtime: Send + Sync + 'static,
+{
+    fn launch_boxed(self: Box<Self>) -> Result<DynVmRuntime> {
+        Ok(DynVmRuntime::new((*self).launch()?))
+    }
+}
+
+/// 类型擦除的NAVM启动器
+/// * 🚩装箱任意启动器，启动后得到[`DynVmRuntime`]
+pub struct DynVmLauncher {
+    /// 被装箱的启动器
+    inner: Box<dyn BoxedVmLauncher>,
+}
+
+impl DynVmLauncher {
+    /// 构造函数
+    pub fn new<L>(launcher: L) -> Self
+    where
+        L: VmLauncher + 'static,
+        L::Runtime: Send + Sync + 'static,
+    {
+        Self {
+            inner: Box::new(launcher),
+        }
+    }
+}
+
+impl VmLauncher for DynVmLauncher {
+    type Runtime = DynVmRuntime;
+
+    fn launch(self) -> Result<DynVmRuntime> {
+        self.inner.launch_boxed()
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+    use std::collections::VecDeque;
+
+    /// 测试用运行时：将输入的指令原样作为`INFO`输出
+    struct EchoRuntime {
+        prefix: &'static str,
+        outputs: VecDeque<Output>,
+        status: VmStatus,
+    }
+
+    impl VmRuntime for EchoRuntime {
+        fn input_cmd(&mut self, cmd: Cmd) -> Result<()> {
+            self.outputs.push_back(Output::INFO {
+                message: format!("{}{cmd}", self.prefix),
+            });
+            Ok(())
+        }
+
+        fn fetch_output(&mut self) -> Result<Output> {
+            self.outputs
+                .pop_front()
+                .ok_or_else(|| anyhow::anyhow!("没有输出"))
+        }
+
+        fn try_fetch_output(&mut self) -> Result<Option<Output>> {
+            Ok(self.outputs.pop_front())
+        }
+
+        fn status(&self) -> &VmStatus {
+            &self.status
+        }
+
+        fn terminate(&mut self) -> Result<()> {
+            self.status = VmStatus::Terminated(Ok(()));
+            Ok(())
+        }
+    }
+
+    /// 两种不同类型的测试用启动器
+    struct LauncherA;
+    struct LauncherB(&'static str);
+
+    impl VmLauncher for LauncherA {
+        type Runtime = EchoRuntime;
+        fn launch(self) -> Result<EchoRuntime> {
+            Ok(EchoRuntime {
+                prefix: "A: ",
+                outputs: VecDeque::new(),
+                status: VmStatus::Running,
+            })
+        }
+    }
+
+    impl VmLauncher for LauncherB {
+        type Runtime = EchoRuntime;
+        fn launch(self) -> Result<EchoRuntime> {
+            Ok(EchoRuntime {
+                prefix: self.0,
+                outputs: VecDeque::new(),
+                status: VmStatus::Running,
+            })
+        }
+    }
+
+    /// 测试/不同类型的启动器放在同一集合中，并以同一方式启动
+    #[test]
+    fn test_dyn_launchers() {
+        let launchers = vec![DynVmLauncher::new(LauncherA), DynVmLauncher::new(LauncherB("B: "))];
+        let mut messages = vec![];
+        for launcher in launchers {
+            let mut runtime = launcher.launch().expect("启动失败");
+            runtime.input_cmd(Cmd::CYC(1)).expect("输入失败");
+            let output = runtime.try_fetch_output().expect("拉取失败").expect("没有输出");
+            messages.push(output.get_content().to_string());
+            runtime.terminate().expect("终止失败");
+            assert!(runtime.is_terminated());
+        }
+        asserts! {
+            messages => vec!["A: CYC 1".to_string(), "B: CYC 1".to_string()]
+        }
+    }
+}
@@ -1,21 +1,35 @@
 //! 存储各CIN的「路径构建器」
 //! * ✅OpenNARS
 //! * ✅ONA
-//! TODO: PyNARS
-//! TODO: CXinNARS
-//! * 🚩【2024-03-31 01:27:09】其它接口完成度不高的CIN，暂时弃了
+//! * ✅PyNARS
+//! * ✅CXinNARS.js
+//! * ✅OpenJunars
+//! * ✅NARS-Python
+//! * ✅NARust-158、Native IL（NAVM原生）
 
 use crate::support::cin_search::{
-    name_match::is_name_match, path_builder::CinPathBuilder, path_walker::PathWalker,
+    cin_registry::{CinKind, CIN_KINDS},
+    name_match::is_name_match,
+    path_builder::{CinLaunchCommand, CinLauncher, CinPathBuilder},
+    path_walker::PathWalker,
 };
-use navm::vm::{VmLauncher, VmRuntime};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 nar_dev_utils::mods! {
     // OpenNARS
     use pub path_builder_opennars;
     // ONA
     use pub path_builder_ona;
+    // PyNARS
+    use pub path_builder_pynars;
+    // CXinNARS.js
+    use pub path_builder_cxin_js;
+    // OpenJunars
+    use pub path_builder_openjunars;
+    // NARS-Python
+    use pub path_builder_nars_python;
+    // NAVM原生：NARust-158、Native IL
+    use pub path_builder_native;
 }
 
 // 深入条件
@@ -27,12 +41,19 @@ pub fn file_name_matches(path: &Path, name: &str) -> bool {
     })
 }
 
+/// 判断文件名是否像可执行文件
+/// * 🚩Windows风格的`.exe`，或类Unix系统中常见的无扩展名
+/// * ⚠️只看文件名，不检查文件权限
+pub fn is_executable_file_name(path: &Path) -> bool {
+    path.extension().is_none_or(|ex| ex == "exe")
+}
+
 /// 从遍历者中找到匹配的所有启动器
 /// * 🎯仅搜索出「可能有效，故构建好」的启动器
-pub fn launchers_from_walker<R: VmRuntime, L: VmLauncher>(
+pub fn launchers_from_walker(
     path_walker: impl PathWalker,
-    path_builder: impl CinPathBuilder<Launcher = L, Runtime = R>,
-) -> Vec<(L, usize)> {
+    path_builder: impl CinPathBuilder,
+) -> Vec<(CinLauncher, usize)> {
     path_walker
         .to_iter_fn()
         .filter_map(Result::ok)
@@ -41,10 +62,10 @@ pub fn launchers_from_walker<R: VmRuntime, L: VmLauncher>(
 }
 
 /// 类似[`launchers_from_walker`]，但根据返回的「匹配度」从高到底排序
-pub fn launchers_from_walker_sorted<R: VmRuntime, L: VmLauncher>(
+pub fn launchers_from_walker_sorted(
     path_walker: impl PathWalker,
-    path_builder: impl CinPathBuilder<Launcher = L, Runtime = R>,
-) -> Vec<L> {
+    path_builder: impl CinPathBuilder,
+) -> Vec<CinLauncher> {
     // 获取 & 排序
     let mut launchers = launchers_from_walker(path_walker, path_builder);
     launchers.sort_by(|(_, a), (_, b)| b.cmp(a)); // ←此处是倒序
@@ -52,23 +73,110 @@ pub fn launchers_from_walker_sorted<R: VmRuntime, L: VmLauncher>(
     launchers.into_iter().map(|(l, _)| l).collect::<Vec<_>>()
 }
 
+/// CIN搜索结果
+/// * 🎯记录「哪个路径可作为哪种CIN启动」，而不实际构建启动器
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CinSearchResult {
+    /// CIN名称
+    /// * 🔗参见[`CinPathBuilder::name`]
+    pub name: &'static str,
+    /// 所用内置转译器的名称
+    /// * 🔗参见[`CinPathBuilder::translators`]
+    pub translators: &'static str,
+    /// 匹配的路径
+    pub path: PathBuf,
+    /// 匹配度
+    pub score: usize,
+    /// 启动命令
+    pub command: CinLaunchCommand,
+}
+
+impl CinSearchResult {
+    /// 从「CIN种类 + 路径 + 匹配度」构造
+    /// * 🚩启动命令由CIN种类生成
+    pub fn new(kind: &dyn CinKind, path: &Path, score: usize) -> Self {
+        Self {
+            name: kind.name(),
+            translators: kind.translators(),
+            path: path.to_owned(),
+            score,
+            command: kind.command_from_path(path),
+        }
+    }
+
+    /// 构建启动器
+    /// * 🚩由搜索结果中的启动命令派生，参见[`CinPathBuilder::construct_from_path`]
+    pub fn launcher(&self) -> CinLauncher {
+        CinLauncher {
+            command: self.command.clone(),
+            translators: self.translators,
+        }
+    }
+}
+
+/// 从一批路径中找出能被某种CIN识别的
+pub fn search_results_from_paths<'a>(
+    paths: impl IntoIterator<Item = &'a PathBuf>,
+    kind: &dyn CinKind,
+) -> Vec<CinSearchResult> {
+    paths
+        .into_iter()
+        .filter_map(|path| match kind.match_path(path) {
+            0 => None,
+            score => Some(CinSearchResult::new(kind, path, score)),
+        })
+        .collect()
+}
+
+/// 用注册表中的所有CIN种类识别一批路径
+/// * 🚩合并各种类的结果，根据「匹配度」从高到低排序；同匹配度时保持原有顺序
+pub fn search_results_sorted(paths: &[PathBuf]) -> Vec<CinSearchResult> {
+    let mut results = CIN_KINDS
+        .iter()
+        .flat_map(|kind| search_results_from_paths(paths, *kind))
+        .collect::<Vec<_>>();
+    results.sort_by_key(|result| std::cmp::Reverse(result.score)); // ←此处是倒序
+    results
+}
+
 /// 单元测试
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::support::cin_search::path_walker::PathWalkerV1;
+    use crate::{
+        support::cin_search::path_walker::PathWalkerV1,
+        tests::fixture::{nars_criterion, FixtureDir},
+    };
     use std::env::current_dir;
 
     #[test]
     fn test() {
-        let path_walker = PathWalkerV1::new(&current_dir().unwrap(), |path| {
-            file_name_matches(path, "nars")
-        })
-        .unwrap();
+        let path_walker = PathWalkerV1::new(&current_dir().unwrap(), nars_criterion).unwrap();
 
         dbg!(launchers_from_walker_sorted(
             path_walker,
             PathBuilderOpenNARS
         ));
     }
+
+    /// 测试/识别一批路径
+    /// * 🎯不同CIN的结果按匹配度合并排序，且附带启动命令
+    #[test]
+    fn test_search_results_sorted() {
+        let files = ["NAR.exe", "opennars.jar", "README.md", "narust_158_batch.exe"];
+        let dir = FixtureDir::with_empty_files("search_results", &files);
+        let paths = files.map(|name| dir.join(name)).to_vec();
+        let results = search_results_sorted(&paths);
+        let find = |name| results.iter().find(|result| result.name == name).unwrap();
+        nar_dev_utils::asserts! {
+            results.len() => 3
+            results[0].name => "ONA" // `NAR.exe`匹配度最高
+            results[0].command.cmd_args => vec!["shell".to_string()]
+            find("OpenNARS").command.cmd => "java"
+            find("OpenNARS").command.current_dir => Some(dir.to_path_buf())
+            find("NARust-158").translators => "Native"
+            // 启动器由启动命令派生
+            results[0].launcher().command => results[0].command.clone()
+        }
+    }
 }
@@ -0,0 +1,93 @@
+//! 用于CXinNARS.js的路径构建器
+
+use crate::support::cin_search::{
+    name_match::{name_match, name_match_only_contains},
+    path_builder::{CinLaunchCommand, CinPathBuilder},
+};
+use nar_dev_utils::{if_return, OptionBoost};
+use std::path::Path;
+
+/// CXinNARS.js路径构建器
+/// * 🎯判别路径并构建CXinNARS.js启动器
+/// * 🚩匹配`.js`入口文件
+///   * 📄`cxin-nars-shell.js`
+pub struct PathBuilderCXinJS;
+
+impl PathBuilderCXinJS {
+    // 匹配文件名
+    #[inline(always)]
+    fn match_name(name: &str) -> usize {
+        // 常用的`cxin-nars-shell.js`
+        (if name == "cxin-nars-shell.js" { 10 } else { 0 })
+        // 须含`cxin`；含`shell`时更高
+            + match name_match("cxin", name) {
+                0 => 0,
+                n => n + name_match_only_contains("shell", name),
+            }
+    }
+
+    /// 检查文件匹配度
+    fn valid_js(path: &Path) -> usize {
+        // ! 不一定是本地存在的文件
+        if_return! { path.extension().is_none_or(|ex| ex != "js") => 0}
+        // 名称匹配`cxin`
+        path.file_name().map_unwrap_or(
+            |name_os| name_os.to_str().map_unwrap_or(Self::match_name, 0),
+            0,
+        )
+    }
+}
+
+impl CinPathBuilder for PathBuilderCXinJS {
+    fn name(&self) -> &'static str {
+        "CXinJS"
+    }
+
+    fn match_path(&self, path: &Path) -> usize {
+        // ! 与本地文件系统有关
+        // 不是本地的文件⇒0
+        if_return! { !path.is_file() => 0 }
+        // 否则⇒查看js匹配度
+        Self::valid_js(path)
+    }
+
+    fn command_from_path(&self, path: &Path) -> CinLaunchCommand {
+        // 📄`node [.js文件路径] shell`
+        CinLaunchCommand {
+            cmd: "node".into(),
+            cmd_args: vec![path.to_string_lossy().into_owned(), "shell".into()],
+            current_dir: path.parent().map(Path::to_owned),
+        }
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture::FixtureDir;
+    use nar_dev_utils::asserts;
+
+    /// 测试/文件匹配
+    #[test]
+    fn test_match() {
+        let root = FixtureDir::with_empty_files(
+            "cxin_js",
+            &[
+                "cxin-nars-shell.js",
+                "src/cxin-nars.js",
+                "src/cxin-nars.ts",
+                "src/nars-shell.js",
+            ],
+        );
+        let score = |path: &str| PathBuilderCXinJS.match_path(&root.join(path));
+        asserts! {
+            score("cxin-nars-shell.js") > score("src/cxin-nars.js") => true
+            score("src/cxin-nars.js") > 0 => true
+            // 无效扩展名、无效名称、不存在的文件
+            score("src/cxin-nars.ts") => 0
+            score("src/nars-shell.js") => 0
+            score("cxin.js") => 0
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! 用于NARS-Python的路径构建器
+
+use crate::support::cin_search::{
+    impls_path_builder::is_executable_file_name,
+    name_match::name_match_only_contains,
+    path_builder::{CinLaunchCommand, CinPathBuilder},
+};
+use nar_dev_utils::{if_return, OptionBoost};
+use std::path::Path;
+
+/// NARS-Python路径构建器
+/// * 🎯判别路径并构建NARS-Python启动器
+/// * 🚩匹配打包后的可执行文件
+///   * 📄`NARS-Python.exe`、`nars_python`
+pub struct PathBuilderNARSPython;
+
+impl PathBuilderNARSPython {
+    // 匹配文件名
+    #[inline(always)]
+    fn match_name(name: &str) -> usize {
+        // 只需「均不满足⇒0」即可
+        name_match_only_contains("nars-python", name)
+            + name_match_only_contains("nars_python", name)
+            + name_match_only_contains("narspython", name)
+    }
+
+    /// 检查文件匹配度
+    fn valid_exe(path: &Path) -> usize {
+        // ! 不一定是本地存在的文件
+        if_return! { !is_executable_file_name(path) => 0 }
+        // 名称匹配`nars-python`
+        path.file_name().map_unwrap_or(
+            |name_os| name_os.to_str().map_unwrap_or(Self::match_name, 0),
+            0,
+        )
+    }
+}
+
+impl CinPathBuilder for PathBuilderNARSPython {
+    fn name(&self) -> &'static str {
+        "NARS-Python"
+    }
+
+    fn match_path(&self, path: &Path) -> usize {
+        // ! 与本地文件系统有关
+        // 不是本地的文件⇒0
+        if_return! { !path.is_file() => 0 }
+        // 否则⇒查看exe匹配度
+        Self::valid_exe(path)
+    }
+
+    fn command_from_path(&self, path: &Path) -> CinLaunchCommand {
+        // 📄`[exe文件路径]`
+        CinLaunchCommand {
+            cmd: path.to_string_lossy().into_owned(),
+            cmd_args: vec![],
+            current_dir: path.parent().map(Path::to_owned),
+        }
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture::FixtureDir;
+    use nar_dev_utils::asserts;
+
+    /// 测试/文件匹配
+    #[test]
+    fn test_match() {
+        let root = FixtureDir::with_empty_files(
+            "nars_python",
+            &[
+                "NARS-Python.exe",
+                "dist/nars_python",
+                "NARS-Python.py",
+                "pynars.exe",
+            ],
+        );
+        let score = |path: &str| PathBuilderNARSPython.match_path(&root.join(path));
+        asserts! {
+            score("NARS-Python.exe") > 0 => true
+            score("dist/nars_python") > 0 => true
+            // 源码文件、无效名称、目录
+            score("NARS-Python.py") => 0
+            score("pynars.exe") => 0
+            score("dist") => 0
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! 用于NAVM原生CIN的路径构建器
+//! * 📌这些CIN直接以NAVM指令交互，统一使用`Native`转译器
+//!   * ✅NARust-158
+//!   * ✅Native IL
+
+use crate::support::cin_search::{
+    impls_path_builder::is_executable_file_name,
+    name_match::name_match_only_contains,
+    path_builder::{CinLaunchCommand, CinPathBuilder},
+};
+use nar_dev_utils::{if_return, OptionBoost};
+use std::path::Path;
+
+/// 检查可执行文件匹配度
+/// * 🚩可执行文件 + 按名称匹配
+fn valid_exe(path: &Path, match_name: impl Fn(&str) -> usize) -> usize {
+    // ! 不一定是本地存在的文件
+    if_return! { !is_executable_file_name(path) => 0 }
+    path.file_name().map_unwrap_or(
+        |name_os| name_os.to_str().map_unwrap_or(match_name, 0),
+        0,
+    )
+}
+
+/// 原生CIN的启动命令
+/// * 📄`[exe文件路径]`
+fn native_command(path: &Path) -> CinLaunchCommand {
+    CinLaunchCommand {
+        cmd: path.to_string_lossy().into_owned(),
+        cmd_args: vec![],
+        current_dir: path.parent().map(Path::to_owned),
+    }
+}
+
+/// NARust-158路径构建器
+/// * 🎯判别路径并构建NARust-158启动器
+/// * 🚩匹配编译后的可执行文件
+///   * 📄`narust_158_batch.exe`、`narust-158`
+pub struct PathBuilderNARust;
+
+impl PathBuilderNARust {
+    // 匹配文件名
+    #[inline(always)]
+    fn match_name(name: &str) -> usize {
+        // 须含`narust`；含`158`时更高
+        match name_match_only_contains("narust", name) {
+            0 => 0,
+            n => n + name_match_only_contains("158", name),
+        }
+    }
+}
+
+impl CinPathBuilder for PathBuilderNARust {
+    fn name(&self) -> &'static str {
+        "NARust-158"
+    }
+
+    fn translators(&self) -> &'static str {
+        "Native"
+    }
+
+    fn match_path(&self, path: &Path) -> usize {
+        // ! 与本地文件系统有关
+        // 不是本地的文件⇒0
+        if_return! { !path.is_file() => 0 }
+        // 否则⇒查看exe匹配度
+        valid_exe(path, Self::match_name)
+    }
+
+    fn command_from_path(&self, path: &Path) -> CinLaunchCommand {
+        native_command(path)
+    }
+}
+
+/// Native IL路径构建器
+/// * 🎯判别路径并构建Native IL启动器
+/// * 🚩匹配编译后的可执行文件
+///   * 📄`native-IL-1.exe`
+pub struct PathBuilderNativeIL;
+
+impl PathBuilderNativeIL {
+    // 匹配文件名
+    #[inline(always)]
+    fn match_name(name: &str) -> usize {
+        // 只需「均不满足⇒0」即可
+        name_match_only_contains("native-il", name) + name_match_only_contains("native_il", name)
+    }
+}
+
+impl CinPathBuilder for PathBuilderNativeIL {
+    fn name(&self) -> &'static str {
+        "Native IL"
+    }
+
+    fn translators(&self) -> &'static str {
+        "Native"
+    }
+
+    fn match_path(&self, path: &Path) -> usize {
+        // ! 与本地文件系统有关
+        // 不是本地的文件⇒0
+        if_return! { !path.is_file() => 0 }
+        // 否则⇒查看exe匹配度
+        valid_exe(path, Self::match_name)
+    }
+
+    fn command_from_path(&self, path: &Path) -> CinLaunchCommand {
+        native_command(path)
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture::FixtureDir;
+    use nar_dev_utils::asserts;
+
+    /// 测试/文件匹配
+    /// * 🎯两种原生CIN互不混淆
+    #[test]
+    fn test_match() {
+        let root = FixtureDir::with_empty_files(
+            "native",
+            &[
+                "narust_158_batch.exe",
+                "target/release/narust_batch",
+                "native-IL-1.exe",
+                "src/narust.rs",
+                "narust.dll",
+            ],
+        );
+        let narust = |path: &str| PathBuilderNARust.match_path(&root.join(path));
+        let native_il = |path: &str| PathBuilderNativeIL.match_path(&root.join(path));
+        asserts! {
+            narust("narust_158_batch.exe") > narust("target/release/narust_batch") => true
+            narust("target/release/narust_batch") > 0 => true
+            native_il("native-IL-1.exe") > 0 => true
+            // 互不混淆
+            narust("native-IL-1.exe") => 0
+            native_il("narust_158_batch.exe") => 0
+            // 源码文件、动态库、目录
+            narust("src/narust.rs") => 0
+            narust("narust.dll") => 0
+            narust("target") => 0
+            // 使用原生转译器
+            PathBuilderNARust.translators() => "Native"
+            PathBuilderNativeIL.translators() => "Native"
+        }
+    }
+}
@@ -2,9 +2,8 @@
 
 use crate::support::cin_search::{
     name_match::{name_match, name_match_only_contains},
-    path_builder::CinPathBuilder,
+    path_builder::{CinLaunchCommand, CinPathBuilder},
 };
-use babel_nar::{cin_implements::ona::ONA, runtimes::CommandVmRuntime};
 use nar_dev_utils::{if_return, OptionBoost};
 use std::path::Path;
 
@@ -27,7 +26,7 @@ impl PathBuilderONA {
     /// 检查文件匹配度
     fn valid_exe(path: &Path) -> usize {
         // ! 不一定是本地存在的文件
-        if_return! { !path.extension().is_some_and(|ex| ex == "exe") => 0}
+        if_return! { path.extension().is_none_or(|ex| ex != "exe") => 0}
         // 名称匹配`ona`
         path.file_name().map_unwrap_or(
             |name_os| name_os.to_str().map_unwrap_or(Self::match_name, 0),
@@ -37,8 +36,9 @@ impl PathBuilderONA {
 }
 
 impl CinPathBuilder for PathBuilderONA {
-    type Runtime = CommandVmRuntime;
-    type Launcher = ONA;
+    fn name(&self) -> &'static str {
+        "ONA"
+    }
 
     fn match_path(&self, path: &Path) -> usize {
         // ! 与本地文件系统有关
@@ -48,8 +48,13 @@ impl CinPathBuilder for PathBuilderONA {
         Self::valid_exe(path)
     }
 
-    fn construct_from_path(&self, path: &Path) -> Self::Launcher {
-        ONA::new(path)
+    fn command_from_path(&self, path: &Path) -> CinLaunchCommand {
+        // 📄`[.exe文件路径] shell`
+        CinLaunchCommand {
+            cmd: path.to_string_lossy().into_owned(),
+            cmd_args: vec!["shell".into()],
+            current_dir: path.parent().map(Path::to_owned),
+        }
     }
 }
 
@@ -0,0 +1,99 @@
+//! 用于OpenJunars的路径构建器
+
+use crate::support::cin_search::{
+    name_match::name_match,
+    path_builder::{CinLaunchCommand, CinPathBuilder},
+};
+use nar_dev_utils::{if_return, OptionBoost};
+use std::path::{Path, PathBuf};
+
+/// OpenJunars的启动脚本
+/// * 📄`OpenJunars/launch.jl`
+const SCRIPT_OPENJUNARS: &str = "launch.jl";
+
+/// OpenJunars路径构建器
+/// * 🎯判别路径并构建OpenJunars启动器
+/// * 🚩匹配「Julia项目目录」：其下有`Project.toml`与启动脚本
+///   * 📄`OpenJunars/Project.toml`、`OpenJunars/launch.jl`
+pub struct PathBuilderOpenJunars;
+
+impl PathBuilderOpenJunars {
+    /// 项目目录中的启动脚本
+    fn script_path(path: &Path) -> PathBuf {
+        path.join(SCRIPT_OPENJUNARS)
+    }
+
+    /// 检查目录匹配度
+    /// * 🚩须为含启动脚本的Julia项目；目录名须匹配`junars`
+    fn valid_project(path: &Path) -> usize {
+        // 不是Julia项目、没有启动脚本⇒0
+        if_return! {
+            !path.join("Project.toml").is_file() => 0
+            !Self::script_path(path).is_file() => 0
+        }
+        // 名称匹配`junars`
+        path.file_name().map_unwrap_or(
+            |name_os| name_os.to_str().map_unwrap_or(|name| name_match("junars", name), 0),
+            0,
+        )
+    }
+}
+
+impl CinPathBuilder for PathBuilderOpenJunars {
+    fn name(&self) -> &'static str {
+        "OpenJunars"
+    }
+
+    fn match_path(&self, path: &Path) -> usize {
+        // ! 与本地文件系统有关
+        // 不是本地的文件夹⇒0
+        if_return! { !path.is_dir() => 0 }
+        // 否则⇒查看项目匹配度
+        Self::valid_project(path)
+    }
+
+    fn command_from_path(&self, path: &Path) -> CinLaunchCommand {
+        // 📄`julia [.jl脚本路径]`
+        CinLaunchCommand {
+            cmd: "julia".into(),
+            cmd_args: vec![Self::script_path(path).to_string_lossy().into_owned()],
+            current_dir: Some(path.to_owned()),
+        }
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture::FixtureDir;
+    use nar_dev_utils::asserts;
+
+    /// 测试/目录匹配
+    #[test]
+    fn test_match() {
+        let root = FixtureDir::with_empty_files(
+            "openjunars",
+            &[
+                "OpenJunars/Project.toml",
+                "OpenJunars/launch.jl",
+                "Junars-no-script/Project.toml",
+                "OtherProject/Project.toml",
+                "OtherProject/launch.jl",
+            ],
+        );
+        let score = |path: &str| PathBuilderOpenJunars.match_path(&root.join(path));
+        asserts! {
+            score("OpenJunars") > 0 => true
+            // 没有启动脚本、名称不匹配、不是目录
+            score("Junars-no-script") => 0
+            score("OtherProject") => 0
+            score("OpenJunars/launch.jl") => 0
+        }
+        let command = PathBuilderOpenJunars.command_from_path(&root.join("OpenJunars"));
+        asserts! {
+            command.cmd => "julia"
+            command.cmd_args => vec![root.join("OpenJunars/launch.jl").to_string_lossy().into_owned()]
+        }
+    }
+}
@@ -1,7 +1,9 @@
 //! 用于OpenNARS的路径构建器
 
-use crate::support::cin_search::{name_match::name_match, path_builder::CinPathBuilder};
-use babel_nar::{cin_implements::opennars::OpenNARS, runtimes::CommandVmRuntime};
+use crate::support::cin_search::{
+    name_match::name_match,
+    path_builder::{CinLaunchCommand, CinPathBuilder},
+};
 use nar_dev_utils::{if_return, OptionBoost};
 use std::path::Path;
 
@@ -20,7 +22,7 @@ impl PathBuilderOpenNARS {
     /// 检查文件匹配度
     fn valid_jar(path: &Path) -> usize {
         // ! 不一定是本地存在的文件
-        if_return! { !path.extension().is_some_and(|ex| ex == "jar") => 0}
+        if_return! { path.extension().is_none_or(|ex| ex != "jar") => 0}
         // 名称匹配`opennars`
         path.file_name().map_unwrap_or(
             |name_os| name_os.to_str().map_unwrap_or(Self::match_name, 0),
@@ -30,8 +32,9 @@ impl PathBuilderOpenNARS {
 }
 
 impl CinPathBuilder for PathBuilderOpenNARS {
-    type Runtime = CommandVmRuntime;
-    type Launcher = OpenNARS;
+    fn name(&self) -> &'static str {
+        "OpenNARS"
+    }
 
     fn match_path(&self, path: &Path) -> usize {
         // ! 与本地文件系统有关
@@ -41,8 +44,13 @@ impl CinPathBuilder for PathBuilderOpenNARS {
         Self::valid_jar(path)
     }
 
-    fn construct_from_path(&self, path: &Path) -> Self::Launcher {
-        OpenNARS::new(path)
+    fn command_from_path(&self, path: &Path) -> CinLaunchCommand {
+        // 📄`java -jar [.jar文件路径]`
+        CinLaunchCommand {
+            cmd: "java".into(),
+            cmd_args: vec!["-jar".into(), path.to_string_lossy().into_owned()],
+            current_dir: path.parent().map(Path::to_owned),
+        }
     }
 }
 
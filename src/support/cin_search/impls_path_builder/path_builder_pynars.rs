@@ -0,0 +1,93 @@
+//! 用于PyNARS的路径构建器
+
+use crate::support::cin_search::{
+    name_match::name_match,
+    path_builder::{CinLaunchCommand, CinPathBuilder},
+};
+use nar_dev_utils::{if_return, OptionBoost};
+use std::path::Path;
+
+/// PyNARS启动的Python模块
+/// * 🎯默认预置指令：`python -m pynars.ConsolePlus`（工作目录为模块根目录）
+const MODULE_PYNARS: &str = "pynars.ConsolePlus";
+
+/// PyNARS路径构建器
+/// * 🎯判别路径并构建PyNARS启动器
+/// * 🚩匹配「Python模块根目录」：其下有`pynars`包
+///   * 📄`PyNARS/pynars/__init__.py`
+pub struct PathBuilderPyNARS;
+
+impl PathBuilderPyNARS {
+    /// 检查目录匹配度
+    /// * 🚩须含`pynars`包；含`ConsolePlus.py`、目录名匹配`pynars`时更高
+    fn valid_module_root(path: &Path) -> usize {
+        let package = path.join("pynars");
+        // 不是Python包⇒0
+        if_return! { !package.join("__init__.py").is_file() => 0 }
+        // 基础匹配度 + 入口模块 + 目录名匹配
+        1 + (if package.join("ConsolePlus.py").is_file() { 4 } else { 0 })
+            + path.file_name().map_unwrap_or(
+                |name_os| name_os.to_str().map_unwrap_or(|name| name_match("pynars", name), 0),
+                0,
+            )
+    }
+}
+
+impl CinPathBuilder for PathBuilderPyNARS {
+    fn name(&self) -> &'static str {
+        "PyNARS"
+    }
+
+    fn match_path(&self, path: &Path) -> usize {
+        // ! 与本地文件系统有关
+        // 不是本地的文件夹⇒0
+        if_return! { !path.is_dir() => 0 }
+        // 否则⇒查看模块匹配度
+        Self::valid_module_root(path)
+    }
+
+    fn command_from_path(&self, path: &Path) -> CinLaunchCommand {
+        // 📄`python -m pynars.ConsolePlus`
+        CinLaunchCommand {
+            cmd: "python".into(),
+            cmd_args: vec!["-m".into(), MODULE_PYNARS.into()],
+            current_dir: Some(path.to_owned()),
+        }
+    }
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::fixture::FixtureDir;
+    use nar_dev_utils::asserts;
+
+    /// 测试/目录匹配
+    #[test]
+    fn test_match() {
+        let root = FixtureDir::with_empty_files(
+            "pynars",
+            &[
+                "PyNARS/pynars/__init__.py",
+                "PyNARS/pynars/ConsolePlus.py",
+                "lib/pynars/__init__.py",
+                "not-a-module/pynars/README.md",
+            ],
+        );
+        let score = |path: &str| PathBuilderPyNARS.match_path(&root.join(path));
+        asserts! {
+            // 完整的PyNARS源码目录最高
+            score("PyNARS") > score("lib") => true
+            score("lib") > 0 => true
+            // 不是Python包、不是目录
+            score("not-a-module") => 0
+            score("PyNARS/pynars/ConsolePlus.py") => 0
+        }
+        let command = PathBuilderPyNARS.command_from_path(&root.join("PyNARS"));
+        asserts! {
+            command.cmd_args => vec!["-m".to_string(), MODULE_PYNARS.to_string()]
+            command.current_dir => Some(root.join("PyNARS"))
+        }
+    }
+}
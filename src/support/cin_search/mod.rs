@@ -3,11 +3,10 @@
 //!  * 🚩输入：搜索起点（一般是编译后exe所在文件夹）
 //!  * 🚩输出：NAVM启动器列表
 //! * ❓【2024-03-30 19:12:29】是否要考虑返回更细化的「CIN实例位置」而非「CIN启动器」，以避免额外的性能开销？
+//!   * ✅现在搜索结果只记录路径与启动命令，需要时再经由注册表构建类型擦除的启动器
 
 // 导出模块
 nar_dev_utils::mods! {
-    // anyhow | 弃用
-    // anyhow_vm;
     // 名称匹配
     pub name_match;
 
@@ -19,4 +18,10 @@ nar_dev_utils::mods! {
 
     // 路径构建器的各CIN实现
     pub impls_path_builder;
+
+    // 类型擦除的启动器、运行时
+    pub dyn_vm;
+
+    // CIN种类注册表
+    pub cin_registry;
 }
@@ -77,3 +77,56 @@ pub fn name_match_only_contains(name: &str, target: &str) -> usize {
 pub fn is_name_match(name: &str, target: &str) -> bool {
     name_match(name, target) > 0
 }
+
+/// 规范化名称
+/// * 🎯忽略大小写与分隔符的差异
+///   * 📄"NARS-Python"、"nars_python"、"NARSPython"均规范化为"narspython"
+/// * 🚩只保留字母与数字，并转为小写
+pub fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// 名称间的编辑距离
+/// * 🎯用于「你是否想要……」的提示
+/// * 🚩规范化（参见[`normalize_name`]）后计算Levenshtein距离
+///   * 📄"opnenars"与"OpenNARS"的距离为2
+pub fn name_distance(a: &str, b: &str) -> usize {
+    let a = normalize_name(a).chars().collect::<Vec<_>>();
+    let b = normalize_name(b).chars().collect::<Vec<_>>();
+    // 滚动数组：`row[j]`为`a[..i]`与`b[..j]`的距离
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    /// 测试/规范化与编辑距离
+    #[test]
+    fn test_name_distance() {
+        asserts! {
+            normalize_name("NARS-Python") => "narspython"
+            normalize_name("cxin_js") => "cxinjs"
+            name_distance("opnenars", "OpenNARS") => 2
+            name_distance("ona", "ONA") => 0
+            name_distance("pynars", "PyNARS 3") => 1
+            name_distance("", "ONA") => 3
+            name_distance("Native", "") => 6
+        }
+    }
+}
@@ -1,26 +1,66 @@
 //! 统一的「路径构建器」逻辑
 
-use navm::vm::{VmLauncher, VmRuntime};
-use std::path::Path;
+use crate::cli::{command_launch_config, relaunch_by_runtime_config, RuntimeConfig};
+use anyhow::Result;
+use babel_nar::runtimes::CommandVmRuntime;
+use navm::vm::VmLauncher;
+use std::path::{Path, PathBuf};
+
+/// CIN启动命令
+/// * 🎯在不构建启动器的情况下，描述「如何从某路径启动CIN」
+///   * 📄用于生成启动配置
+/// * 🚩字段与启动配置中的`command`对应
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CinLaunchCommand {
+    /// 命令
+    pub cmd: String,
+    /// 命令的参数
+    pub cmd_args: Vec<String>,
+    /// 工作目录（可选）
+    pub current_dir: Option<PathBuf>,
+}
+
+/// CIN启动器
+/// * 🎯从「启动命令 + 转译器名」启动CIN
+/// * 🚩经由启动配置启动：与「生成启动配置后再启动」的行为一致
+///   * 📌工作目录设置在启动命令上，不切换整个进程的工作目录
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CinLauncher {
+    /// 启动命令
+    pub command: CinLaunchCommand,
+    /// 所用内置转译器的名称
+    pub translators: &'static str,
+}
+
+impl VmLauncher for CinLauncher {
+    type Runtime = CommandVmRuntime;
+
+    fn launch(self) -> Result<CommandVmRuntime> {
+        let config = RuntimeConfig::try_from(command_launch_config(
+            &self.command,
+            self.translators,
+        ))?;
+        relaunch_by_runtime_config(&config)
+    }
+}
 
 /// CIN路径构建器
 /// * 🚩本身不承担「遍历路径」的任务，只负责
 ///   * 📌判断是否「可以用于构建NAVM运行时」
-///   * 📌从某路径构建「NAVM启动器」
-/// * ❌【2024-03-30 19:05:48】放弃「通用启动器/通用运行时」的适配尝试
-///   * 📝目前[`CinSearch::Launcher`]总是要带上[`CinSearch::Runtime`]作类型参数
-///   * 📌堵点：难以定义一个使用`Box<dyn VmLauncher<?>>`封装的`AnyhowVmLauncher`类型
-///     * 📍问题领域：特征对象及其转换
-///     * ❓一个可能的参考：[`anyhow`]对「错误类型」的统一
-///     * ❌【2024-03-30 21:24:10】尝试仍然失败：有关`Box<Self>`的所有权转换问题
-///     * 🔗技术参考1：<https://stackoverflow.com/questions/46620790/how-to-call-a-method-that-consumes-self-on-a-boxed-trait-object>
+///   * 📌从某路径生成「启动命令」，并由此构建「NAVM启动器」
+/// * ✅以特征对象使用时，参见[`super::cin_registry::CinKind`]
 pub trait CinPathBuilder {
-    /// 搜索结果的启动器类型
-    /// * 📌启动后变为[`CinSearch::Runtime`]运行时类型
-    type Launcher: VmLauncher;
+    /// CIN名称
+    /// * 🎯在搜索结果中标识CIN
+    ///   * 📄`OpenNARS`、`ONA`、`NARust-158`
+    fn name(&self) -> &'static str;
 
-    /// 搜索结果的运行时类型
-    type Runtime: VmRuntime;
+    /// 所用内置转译器的名称
+    /// * 🚩默认与CIN名称一致
+    ///   * 📄`NARust-158`等基于NAVM的CIN使用`Native`
+    fn translators(&self) -> &'static str {
+        self.name()
+    }
 
     /// 路径匹配
     /// * 🎯匹配某路径（可能是文件夹，也可能是文件）是否可用于「构建NAVM启动器」
@@ -39,23 +79,35 @@ pub trait CinPathBuilder {
         self.match_path(path) > 0
     }
 
+    /// 从某个路径生成启动命令
+    /// * 🎯用于生成启动配置、构建启动器
+    ///   * ✅除路径以外，其它参数可作默认
+    ///
+    /// # Panics
+    ///
+    /// ⚠️需要保证[`is_path_matched`]为真
+    fn command_from_path(&self, path: &Path) -> CinLaunchCommand;
+
     /// 路径构建
     /// * 🎯从某个路径构建出一个NAVM启动器
-    ///   * ✅除路径以外，其它参数可作默认
-    ///     * 📄OpenNARS的「Java最大堆大小」
+    /// * 🚩由[`Self::command_from_path`]所生成的启动命令派生：二者不会不一致
     ///
     /// # Panics
     ///
     /// ⚠️需要保证[`is_path_matched`]为真
-    /// * 为假时可能`panic`
-    fn construct_from_path(&self, path: &Path) -> Self::Launcher;
+    fn construct_from_path(&self, path: &Path) -> CinLauncher {
+        CinLauncher {
+            command: self.command_from_path(path),
+            translators: self.translators(),
+        }
+    }
 
     /// 尝试路径构建
     /// * 🚩返回一个[`Option`]
     ///   * 能构建⇒返回构建后的结果 `Some((启动器, 匹配度))`
     ///   * 无法构建⇒返回[`None`]
     #[inline]
-    fn try_construct_from_path(&self, path: &Path) -> Option<(Self::Launcher, usize)> {
+    fn try_construct_from_path(&self, path: &Path) -> Option<(CinLauncher, usize)> {
         match self.match_path(path) {
             // 不匹配⇒无
             0 => None,
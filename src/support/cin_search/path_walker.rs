@@ -2,8 +2,16 @@
 //! * 🎯用于分离「路径查找」与「CIN识别」两功能
 //!   * 📌「路径遍历器」负责「提供路径，并有选择地 深入/跳出 路径」
 
-use anyhow::{Error, Result};
-use std::path::{Path, PathBuf};
+use crate::cli::messages;
+use anyhow::{anyhow, Error, Result};
+use glob::Pattern;
+use ignore::{gitignore::Gitignore, Match};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 /// 抽象的「路径遍历」特征
 /// * ✨允许「迭代出下一个路径」
@@ -38,6 +46,108 @@ pub trait PathWalker {
     }
 }
 
+/// 路径遍历选项
+/// * 🎯限制遍历的范围与开销，避免在大型目录树中失控
+/// * 📌缺省：不限深度与开销，遵循`.gitignore`，不跟随符号链接
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathWalkerOptions {
+    /// 最大深入层数
+    /// * 📌相对于「起始目录」或所扫描的「祖先目录」
+    /// * 🚩`0`⇒只遍历其直接包含的文件/文件夹
+    pub max_depth: Option<usize>,
+
+    /// 最多向上扫描的祖先目录数
+    /// * 🚩`0`⇒只扫描起始目录
+    pub max_ancestors: Option<usize>,
+
+    /// 包含模式
+    /// * 🚩非空时，只返回匹配其一的路径（仍会深入不匹配的文件夹）
+    /// * 📌匹配完整路径或文件名，参见[`pattern_matches`]
+    pub include: Vec<Pattern>,
+
+    /// 排除模式
+    /// * 🚩匹配其一的路径既不返回，也不深入
+    pub exclude: Vec<Pattern>,
+
+    /// 是否遵循`.gitignore`
+    /// * 🚩从所在目录向上，直到Git仓库根目录（含`.git`）为止
+    pub respect_gitignore: bool,
+
+    /// 是否深入符号链接所指的文件夹
+    /// * ⚠️无论是否跟随，每个文件夹都只会深入一次：避免符号链接成环
+    pub follow_symlinks: bool,
+
+    /// 最多检查的路径数
+    pub max_entries: Option<usize>,
+
+    /// 最长遍历时间
+    pub time_limit: Option<Duration>,
+}
+
+impl Default for PathWalkerOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: None,
+            max_ancestors: None,
+            include: vec![],
+            exclude: vec![],
+            respect_gitignore: true,
+            follow_symlinks: false,
+            max_entries: None,
+            time_limit: None,
+        }
+    }
+}
+
+/// 判断路径是否匹配模式
+/// * 🚩匹配完整路径，或仅匹配文件名
+///   * 📄`*.hjson`、`*target*`
+pub fn pattern_matches(pattern: &Pattern, path: &Path) -> bool {
+    pattern.matches_path(path)
+        || path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| pattern.matches(name))
+}
+
+/// 一系列`.gitignore`规则
+/// * 📌从远到近排列：越靠后的规则优先级越高
+type GitignoreChain = Rc<Vec<Gitignore>>;
+
+/// 读取某目录下的`.gitignore`
+/// * 🚩没有文件⇒[`None`]；部分规则有误⇒忽略这些规则
+fn read_gitignore(dir: &Path) -> Option<Gitignore> {
+    let path = dir.join(".gitignore");
+    match path.is_file() {
+        true => Some(Gitignore::new(path).0),
+        false => None,
+    }
+}
+
+/// 计算某目录所适用的所有`.gitignore`
+/// * 🚩从该目录向上，直到Git仓库根目录（含`.git`）为止
+fn gitignore_chain(dir: &Path) -> GitignoreChain {
+    let mut chain = vec![];
+    for ancestor in dir.ancestors() {
+        chain.extend(read_gitignore(ancestor));
+        if ancestor.join(".git").exists() {
+            break;
+        }
+    }
+    chain.reverse();
+    Rc::new(chain)
+}
+
+/// 待遍历的目录
+struct DirFrame {
+    /// 目录路径
+    path: PathBuf,
+    /// 相对于「起始目录」或「祖先目录」的深度
+    depth: usize,
+    /// 所适用的`.gitignore`
+    gitignores: GitignoreChain,
+}
+
 /// 初代路径遍历器
 /// * ✨使用「渐近回退性扫描」机制，总体为「深度优先」
 ///   * 📌「起始目录」一般为exe所在目录
@@ -47,30 +157,68 @@ pub trait PathWalker {
 ///     * 遍历其【直接包含】的文件/文件夹
 ///     * 若有满足特定「可深入条件」的文件夹，则深入扫描该文件夹（仍然是「条件扫描」）
 ///   * 🚩父目录扫描完毕后，继续扫描父目录
+/// * ✨遍历范围与开销可由[`PathWalkerOptions`]限制
+///   * 📌每个文件夹（按规范化路径计）只深入一次：不会因符号链接成环而陷入死循环
+///   * 📌超出开销预算时，遍历提前结束，参见[`Self::is_exhausted`]
 pub struct PathWalkerV1<'a> {
     // 父目录堆栈
     ancestors_stack: Vec<PathBuf>,
 
     /// 待遍历目录的堆栈
-    to_visit_stack: Vec<PathBuf>,
+    to_visit_stack: Vec<DirFrame>,
 
     /// 可深入条件
     deep_criterion: Box<dyn Fn(&Path) -> bool + Send + Sync + 'a>,
 
     /// 当前在遍历目录的迭代器
     current_dir_iter: Box<dyn Iterator<Item = Result<PathBuf>>>,
+
+    /// 当前在遍历目录的深度
+    current_depth: usize,
+
+    /// 当前在遍历目录所适用的`.gitignore`
+    current_gitignores: GitignoreChain,
+
+    /// 已深入过的目录（规范化路径）
+    visited: HashSet<PathBuf>,
+
+    /// 遍历选项
+    options: PathWalkerOptions,
+
+    /// 开始遍历的时间
+    start_time: Instant,
+
+    /// 已检查的路径数
+    n_entries: usize,
+
+    /// 是否因超出开销预算而提前结束
+    exhausted: bool,
 }
 
 impl<'a> PathWalkerV1<'a> {
+    /// 以缺省选项构造
     pub fn new(
         start: &Path,
         deep_criterion: impl Fn(&Path) -> bool + Send + Sync + 'a,
+    ) -> Result<Self> {
+        Self::with_options(start, deep_criterion, PathWalkerOptions::default())
+    }
+
+    /// 以指定选项构造
+    /// * ⚠️起始路径及其所有祖先均不是文件夹⇒返回错误
+    pub fn with_options(
+        start: &Path,
+        deep_criterion: impl Fn(&Path) -> bool + Send + Sync + 'a,
+        options: PathWalkerOptions,
     ) -> Result<Self> {
         // 计算根目录
         // * 🚩不是文件夹⇒向上寻找根目录
         let mut root = start;
         while !root.is_dir() {
-            root = root.parent().unwrap();
+            root = match root.parent() {
+                Some(parent) => parent,
+                None => return Err(anyhow!(messages::invalid_start_dir(start))),
+            };
         }
         // 构造路径堆栈
         let mut ancestors_stack = root.ancestors().map(Path::to_owned).collect::<Vec<_>>();
@@ -78,18 +226,40 @@ impl<'a> PathWalkerV1<'a> {
                                    // 拿出目录
         let root = match ancestors_stack.pop() {
             Some(path) => path,
-            None => return Err(Error::msg("起始目录无效")),
+            None => return Err(anyhow!(messages::invalid_start_dir(start))),
         };
+        // 限制向上扫描的层数 | 只保留最近的若干个祖先
+        if let Some(max_ancestors) = options.max_ancestors {
+            let n_dropped = ancestors_stack.len().saturating_sub(max_ancestors);
+            ancestors_stack.drain(..n_dropped);
+        }
         let deep_criterion = Box::new(deep_criterion);
         let current_dir_iter = Box::new(Self::new_path_iter(&root)?);
+        let current_gitignores = match options.respect_gitignore {
+            true => gitignore_chain(&root),
+            false => GitignoreChain::default(),
+        };
+        let visited = HashSet::from_iter(root.canonicalize().ok());
         Ok(Self {
             ancestors_stack,
             to_visit_stack: vec![], // 空栈初始化
             deep_criterion,
             current_dir_iter,
+            current_depth: 0,
+            current_gitignores,
+            visited,
+            options,
+            start_time: Instant::now(),
+            n_entries: 0,
+            exhausted: false,
         })
     }
 
+    /// 是否因超出开销预算（路径数、时间）而提前结束
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
     /// ✨构造路径迭代器
     /// * 🎯尽可能让异常变得可处理：避免`unwrap`
     fn new_path_iter(path: &Path) -> Result<impl Iterator<Item = Result<PathBuf>>> {
@@ -99,20 +269,125 @@ impl<'a> PathWalkerV1<'a> {
         }))
     }
 
+    /// 检查是否超出开销预算
+    fn is_over_budget(&self) -> bool {
+        let PathWalkerOptions {
+            max_entries,
+            time_limit,
+            ..
+        } = self.options;
+        max_entries.is_some_and(|max| self.n_entries >= max)
+            || time_limit.is_some_and(|limit| self.start_time.elapsed() >= limit)
+    }
+
+    /// 检查路径是否被排除
+    /// * 🚩匹配排除模式，或被`.gitignore`忽略
+    fn is_excluded(&self, path: &Path, is_dir: bool) -> bool {
+        if self
+            .options
+            .exclude
+            .iter()
+            .any(|pattern| pattern_matches(pattern, path))
+        {
+            return true;
+        }
+        // 越近的`.gitignore`优先级越高
+        for gitignore in self.current_gitignores.iter().rev() {
+            match gitignore.matched(path, is_dir) {
+                Match::Ignore(..) => return true,
+                Match::Whitelist(..) => return false,
+                Match::None => {}
+            }
+        }
+        false
+    }
+
+    /// 检查路径是否被包含
+    /// * 🚩未指定包含模式⇒全部包含
+    fn is_included(&self, path: &Path) -> bool {
+        self.options.include.is_empty()
+            || self
+                .options
+                .include
+                .iter()
+                .any(|pattern| pattern_matches(pattern, path))
+    }
+
+    /// 检查文件夹是否值得深入
+    /// * 🚩深度、符号链接、可深入条件、是否已深入过
+    fn should_visit(&mut self, path: &Path) -> bool {
+        if self
+            .options
+            .max_depth
+            .is_some_and(|max| self.current_depth >= max)
+        {
+            return false;
+        }
+        if !self.options.follow_symlinks && path.is_symlink() {
+            return false;
+        }
+        if !(self.deep_criterion)(path) {
+            return false;
+        }
+        // 规范化失败⇒不深入
+        match path.canonicalize() {
+            Ok(canonical) => self.visited.insert(canonical),
+            Err(..) => false,
+        }
+    }
+
+    /// 构造子目录的遍历信息
+    /// * 🚩`.gitignore`：承继当前目录，再追加子目录自身的
+    ///   * 📌子目录是另一个Git仓库⇒重新开始
+    fn child_frame(&self, path: PathBuf) -> DirFrame {
+        let gitignores = match self.options.respect_gitignore {
+            false => GitignoreChain::default(),
+            true => match (read_gitignore(&path), path.join(".git").exists()) {
+                (None, false) => self.current_gitignores.clone(),
+                (own, true) => Rc::new(own.into_iter().collect()),
+                (Some(own), false) => {
+                    let mut chain = self.current_gitignores.to_vec();
+                    chain.push(own);
+                    Rc::new(chain)
+                }
+            },
+        };
+        DirFrame {
+            path,
+            depth: self.current_depth + 1,
+            gitignores,
+        }
+    }
+
     /// 可能返回[`None`]的[`Self::next`]
     /// * 🎯应对「切换到父目录的迭代器后，首个迭代结果还是[`None`]」的情况
     ///   * 🚩解决方案：再次[`Self::poll_path`]
     fn poll_path(&mut self) -> PathPollResult {
+        // 超出预算⇒提前结束
+        if self.is_over_budget() {
+            self.exhausted = true;
+            return PathPollResult::Ended;
+        }
         // ! ❌【2024-03-30 22:34:04】目前没法稳定地使用`?`
         match self.current_dir_iter.next() {
             // 正常情况
             Some(Ok(path)) => {
+                self.n_entries += 1;
+                let is_dir = path.is_dir();
+                // 被排除⇒既不返回，也不深入
+                if self.is_excluded(&path, is_dir) {
+                    return PathPollResult::None;
+                }
                 // 如果「值得深入」⇒预备在后续深入
-                if path.is_dir() && (self.deep_criterion)(&path) {
-                    self.to_visit_stack.push(path.clone())
+                if is_dir && self.should_visit(&path) {
+                    let frame = self.child_frame(path.clone());
+                    self.to_visit_stack.push(frame)
                 }
                 // 返回
-                PathPollResult::Some(path)
+                match self.is_included(&path) {
+                    true => PathPollResult::Some(path),
+                    false => PathPollResult::None,
+                }
             }
             // 中途报错情况
             Some(Err(e)) => PathPollResult::Err(e),
@@ -126,26 +401,39 @@ impl<'a> PathWalkerV1<'a> {
     fn try_switch_current_path(&mut self) -> PathPollResult {
         match self.to_visit_stack.pop() {
             // 「待检查路径」有⇒尝试pop一个，构造并切换到新的迭代器
-            Some(path) => match self.change_current_path(&path) {
+            Some(frame) => match self.change_current_path(frame) {
                 Ok(()) => PathPollResult::None, // 构造了就收手，无需立马查看里边有无路径
                 Err(e) => PathPollResult::Err(e),
             },
             // 「待检查路径」没有⇒尝试从「祖先路径」中尝试pop一个
             None => match self.ancestors_stack.pop() {
                 // 「祖先路径」有⇒尝试pop一个，构造并切换到新的迭代器
-                Some(path) => match self.change_current_path(&path) {
-                    Ok(()) => PathPollResult::None, // 构造了就收手，无需立马查看里边有无路径
-                    Err(e) => PathPollResult::Err(e),
-                }, // 「祖先路径」没有⇒终止
+                Some(path) => {
+                    let gitignores = match self.options.respect_gitignore {
+                        true => gitignore_chain(&path),
+                        false => GitignoreChain::default(),
+                    };
+                    let frame = DirFrame {
+                        path,
+                        depth: 0,
+                        gitignores,
+                    };
+                    match self.change_current_path(frame) {
+                        Ok(()) => PathPollResult::None, // 构造了就收手，无需立马查看里边有无路径
+                        Err(e) => PathPollResult::Err(e),
+                    }
+                } // 「祖先路径」没有⇒终止
                 None => PathPollResult::Ended,
             },
         }
     }
 
     /// 尝试更改到某个目录（的迭代器）
-    fn change_current_path(&mut self, path: &Path) -> Result<()> {
-        let iter = Self::new_path_iter(path)?;
+    fn change_current_path(&mut self, frame: DirFrame) -> Result<()> {
+        let iter = Self::new_path_iter(&frame.path)?;
         self.current_dir_iter = Box::new(iter);
+        self.current_depth = frame.depth;
+        self.current_gitignores = frame.gitignores;
         Ok(())
     }
 }
@@ -211,19 +499,15 @@ impl Iterator for PathWalkerV1<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::support::cin_search::name_match::is_name_match;
+    use crate::tests::fixture::{nars_criterion, FixtureDir};
+    use nar_dev_utils::asserts;
     use std::env::current_dir;
 
     fn _test_path_walker_v1(start: impl Into<PathBuf>) {
         // 起始目录
         let start = &start.into();
-        // 深入条件
-        fn deep_criterion(path: &Path) -> bool {
-            path.file_name()
-                .is_some_and(|name| name.to_str().is_some_and(|s| is_name_match("nars", s)))
-        }
-        // 构建遍历者，加上条件
-        let walker = PathWalkerV1::new(start, deep_criterion).unwrap();
+        // 构建遍历者，加上条件 | 深入名称匹配`nars`的文件夹
+        let walker = PathWalkerV1::new(start, nars_criterion).unwrap();
         // 打印遍历者的「祖先列表」
         println!("{:?}", walker.ancestors_stack);
         // 遍历
@@ -237,4 +521,126 @@ mod tests {
         // 测试当前路径
         _test_path_walker_v1(current_dir().unwrap());
     }
+
+    /// 工具/以指定选项遍历，返回相对路径
+    /// * 🚩只扫描起始目录，不向上扫描
+    fn walk(root: &Path, options: PathWalkerOptions) -> (Vec<String>, bool) {
+        let options = PathWalkerOptions {
+            max_ancestors: Some(0),
+            ..options
+        };
+        let mut walker = PathWalkerV1::with_options(root, nars_criterion, options).unwrap();
+        let paths = walker
+            .by_ref()
+            .map(|path| {
+                let path = path.unwrap();
+                let relative = path.strip_prefix(root).unwrap().to_owned();
+                relative.to_string_lossy().replace('\\', "/")
+            })
+            .collect();
+        (paths, walker.is_exhausted())
+    }
+
+    /// 测试/遍历选项
+    /// * 🎯深度、包含/排除、`.gitignore`、路径数预算
+    #[test]
+    fn test_options() {
+        let root = FixtureDir::with_files(
+            "path_walker_options",
+            &[
+                (".gitignore", "nars_ignored/\n"),
+                ("a.txt", ""),
+                ("nars_1/b.txt", ""),
+                ("nars_1/nars_2/c.txt", ""),
+                ("nars_1/nars_2/nars_3/d.txt", ""),
+                ("nars_ignored/e.txt", ""),
+                ("other/f.txt", ""),
+            ],
+        );
+        let (default, exhausted) = walk(&root, PathWalkerOptions::default());
+        let (shallow, _) = walk(
+            &root,
+            PathWalkerOptions {
+                max_depth: Some(1),
+                ..Default::default()
+            },
+        );
+        let (excluded, _) = walk(
+            &root,
+            PathWalkerOptions {
+                exclude: vec![Pattern::new("nars_2").unwrap()],
+                ..Default::default()
+            },
+        );
+        let (included, _) = walk(
+            &root,
+            PathWalkerOptions {
+                include: vec![Pattern::new("*.txt").unwrap()],
+                ..Default::default()
+            },
+        );
+        let (no_gitignore, _) = walk(
+            &root,
+            PathWalkerOptions {
+                respect_gitignore: false,
+                ..Default::default()
+            },
+        );
+        let (limited, limited_exhausted) = walk(
+            &root,
+            PathWalkerOptions {
+                max_entries: Some(3),
+                ..Default::default()
+            },
+        );
+        let has = |paths: &[String], path: &str| paths.iter().any(|p| p == path);
+        asserts! {
+            // 缺省：深入所有满足条件的文件夹，遵循`.gitignore`
+            has(&default, "a.txt") => true
+            has(&default, "nars_1/nars_2/nars_3/d.txt") => true
+            has(&default, "other/f.txt") => false
+            has(&default, "nars_ignored") => false
+            has(&default, "nars_ignored/e.txt") => false
+            exhausted => false
+            // 最大深度
+            has(&shallow, "nars_1/b.txt") => true
+            has(&shallow, "nars_1/nars_2") => true
+            has(&shallow, "nars_1/nars_2/c.txt") => false
+            // 排除：既不返回，也不深入
+            has(&excluded, "nars_1/nars_2") => false
+            has(&excluded, "nars_1/nars_2/c.txt") => false
+            has(&excluded, "nars_1/b.txt") => true
+            // 包含：只返回匹配的路径，但仍会深入
+            included.iter().all(|p| p.ends_with(".txt")) => true
+            has(&included, "nars_1/nars_2/nars_3/d.txt") => true
+            // 不遵循`.gitignore`
+            has(&no_gitignore, "nars_ignored/e.txt") => true
+            // 路径数预算
+            limited.len() <= 3 => true
+            limited_exhausted => true
+        }
+    }
+
+    /// 测试/符号链接成环
+    /// * 🎯跟随符号链接时，每个文件夹仍只深入一次
+    #[test]
+    #[cfg(unix)]
+    fn test_symlink_loop() {
+        let root = FixtureDir::with_empty_files("symlink_loop", &["nars_1/a.txt"]);
+        std::os::unix::fs::symlink(&root, root.join("nars_1/nars_loop")).unwrap();
+        let (followed, _) = walk(
+            &root,
+            PathWalkerOptions {
+                follow_symlinks: true,
+                ..Default::default()
+            },
+        );
+        let (not_followed, _) = walk(&root, PathWalkerOptions::default());
+        let count = |paths: &[String]| paths.iter().filter(|p| p.ends_with("a.txt")).count();
+        asserts! {
+            count(&followed) => 1
+            count(&not_followed) => 1
+            followed.iter().any(|p| p == "nars_1/nars_loop") => true
+        }
+    }
 }
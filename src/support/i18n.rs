@@ -0,0 +1,256 @@
+//! 国际化支持
+//! * 🎯让CLI的提示、日志、报错信息可以不同语言呈现
+//! * 🚩全局维护「当前语言」，由消息目录在生成消息时读取
+//!   * 📌未显式设置时为中文（与原先的硬编码消息一致）
+//! * 🚩消息目录通过[`message_catalog`]宏定义：每条消息一个函数，各语言一个模板
+//!   * ✅所有语言的模板集中在一处，新增语言时漏写会直接编译报错
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    env::var,
+    fmt::{Display, Formatter},
+    str::FromStr,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// 支持的语言
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Locale {
+    /// 中文
+    /// * 📜默认值
+    #[default]
+    Zh,
+    /// 英文
+    En,
+}
+
+impl Locale {
+    /// 所有支持的语言
+    pub const ALL: [Self; 2] = [Self::Zh, Self::En];
+
+    /// 语言代码
+    /// * 📄`zh`、`en`
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Zh => "zh",
+            Self::En => "en",
+        }
+    }
+
+    /// 从环境变量`LANG`中识别语言
+    /// * 🚩未设置/无法识别（如`C`、`POSIX`）⇒[`None`]
+    pub fn from_env() -> Option<Self> {
+        var("LANG").ok()?.parse().ok()
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// 从语言标签解析
+/// * 🚩只看主语言部分，忽略大小写、地区与编码
+///   * 📄`zh`、`zh-CN`、`zh_TW.UTF-8` ⇒ 中文
+///   * 📄`en`、`en_US.UTF-8` ⇒ 英文
+impl FromStr for Locale {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s
+            .split(['-', '_', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.code() == language)
+            .ok_or_else(|| {
+                let supported = Self::ALL.map(Self::code).join(", ");
+                format!("unsupported language {s:?}, expected one of: {supported}")
+            })
+    }
+}
+
+/// 当前语言
+/// * 🚩以[`Locale::ALL`]中的索引存储
+static CURRENT_LOCALE: AtomicU8 = AtomicU8::new(0);
+
+/// 获取当前语言
+pub fn current_locale() -> Locale {
+    Locale::ALL
+        .get(CURRENT_LOCALE.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// 设置当前语言
+pub fn set_locale(locale: Locale) {
+    let index = Locale::ALL.iter().position(|l| *l == locale).unwrap_or(0);
+    CURRENT_LOCALE.store(index as u8, Ordering::Relaxed);
+}
+
+/// 多语言文本
+/// * 🎯用于配置中面向用户的文本，如启动配置的描述
+/// * 🚩可以是单个字符串，也可以是「语言代码 ⇒ 文本」的映射
+///   * 📄`"OpenNARS 3.0.4"`
+///   * 📄`{ zh: "严格测试", en: "Strict test" }`
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocalizedText {
+    /// 🚩单个字符串：所有语言通用
+    Plain(String),
+
+    /// 🚩按语言代码区分的文本
+    Localized(BTreeMap<String, String>),
+}
+
+impl LocalizedText {
+    /// 以指定语言获取文本
+    /// * 🚩回退顺序：指定语言 ⇒ 中文 ⇒ 英文 ⇒ 任意一个
+    pub fn get_in(&self, locale: Locale) -> &str {
+        match self {
+            Self::Plain(text) => text,
+            Self::Localized(texts) => [locale, Locale::Zh, Locale::En]
+                .into_iter()
+                .find_map(|locale| texts.get(locale.code()))
+                .or_else(|| texts.values().next())
+                .map(String::as_str)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// 以当前语言获取文本
+    pub fn get(&self) -> &str {
+        self.get_in(current_locale())
+    }
+
+    /// 所有语言的文本（可变）
+    /// * 🎯统一处理各语言的文本，如变量插值
+    pub fn texts_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            Self::Plain(text) => vec![text],
+            Self::Localized(texts) => texts.values_mut().collect(),
+        }
+    }
+}
+
+/// 以当前语言展示
+impl Display for LocalizedText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.get())
+    }
+}
+
+impl From<&str> for LocalizedText {
+    fn from(text: &str) -> Self {
+        Self::Plain(text.into())
+    }
+}
+
+impl From<String> for LocalizedText {
+    fn from(text: String) -> Self {
+        Self::Plain(text)
+    }
+}
+
+/// 定义消息目录
+/// * 🚩每条消息生成一个函数：按当前语言选择模板，并返回格式化后的字符串
+///   * 📌模板中可直接内联参数，如`{name}`、`{path:?}`
+/// * ⚠️每条消息都须提供所有语言的模板
+///
+/// ## 用例
+///
+/// ```rust
+/// use babel_nar_cli::message_catalog;
+/// message_catalog! {
+///     /// 已选中档案
+///     profile_selected(name: &str) {
+///         zh: "已选中档案「{name}」",
+///         en: "Selected profile \"{name}\"",
+///     }
+/// }
+/// assert_eq!(profile_selected("strict"), "已选中档案「strict」");
+/// // 切换语言
+/// use babel_nar_cli::support::i18n::{set_locale, Locale};
+/// set_locale(Locale::En);
+/// assert_eq!(profile_selected("strict"), "Selected profile \"strict\"");
+/// ```
+#[macro_export]
+macro_rules! message_catalog {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident ( $($arg:ident : $ty:ty),* $(,)? ) {
+            zh: $zh:literal,
+            en: $en:literal $(,)?
+        }
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[allow(unused_variables)]
+            pub fn $name($($arg: $ty),*) -> String {
+                match $crate::support::i18n::current_locale() {
+                    $crate::support::i18n::Locale::Zh => format!($zh),
+                    $crate::support::i18n::Locale::En => format!($en),
+                }
+            }
+        )*
+    };
+}
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    /// 测试/解析语言标签
+    #[test]
+    fn test_parse_locale() {
+        asserts! {
+            "zh".parse() => Ok(Locale::Zh)
+            "zh-CN".parse() => Ok(Locale::Zh)
+            "zh_TW.UTF-8".parse() => Ok(Locale::Zh)
+            "EN".parse() => Ok(Locale::En)
+            "en_US.UTF-8".parse() => Ok(Locale::En)
+            "C".parse::<Locale>().is_err() => true
+            "".parse::<Locale>().is_err() => true
+        }
+    }
+
+    /// 测试/多语言文本
+    /// * 🎯回退顺序：指定语言 ⇒ 中文 ⇒ 英文 ⇒ 任意一个
+    #[test]
+    fn test_localized_text() {
+        let localized = |pairs: &[(&str, &str)]| {
+            LocalizedText::Localized(
+                pairs
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            )
+        };
+        let both = localized(&[("zh", "严格测试"), ("en", "Strict test")]);
+        let en_only = localized(&[("en", "Strict test")]);
+        let other = localized(&[("fr", "Test strict")]);
+        asserts! {
+            both.get_in(Locale::Zh) => "严格测试"
+            both.get_in(Locale::En) => "Strict test"
+            en_only.get_in(Locale::Zh) => "Strict test"
+            other.get_in(Locale::En) => "Test strict"
+            LocalizedText::from("OpenNARS").get_in(Locale::En) => "OpenNARS"
+        }
+        // 反序列化：字符串与映射均可
+        let parsed: LocalizedText =
+            serde_json::from_str(r#"{"zh": "严格测试", "en": "Strict test"}"#).unwrap();
+        assert_eq!(parsed, both);
+        let parsed: LocalizedText = serde_json::from_str(r#""OpenNARS""#).unwrap();
+        assert_eq!(parsed, "OpenNARS".into());
+    }
+}
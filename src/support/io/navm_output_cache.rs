@@ -96,7 +96,10 @@ impl VmOutputCache for OutputCache {
             // 通过⇒静默加入输出
             HandleResult::Passed(output) => self.put_silent(output),
             // 被消耗⇒提示
-            HandleResult::Consumed(index) => Ok(println!("NAVM输出在[{index}]位置被拦截。")),
+            HandleResult::Consumed(index) => {
+                println!("NAVM输出在[{index}]位置被拦截。");
+                Ok(())
+            }
         }
     }
 
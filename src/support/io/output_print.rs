@@ -39,11 +39,102 @@
 //! * 最后更新：【2024-04-02 15:54:23】
 //! * 参考链接：<https://github.com/ARCJ137442/BabelNAR_Implements/blob/master/scripts/console.jl#L160>
 
+use crate::cli::messages;
 use colored::Colorize;
 use nar_dev_utils::manipulate;
 use narsese::conversion::string::impl_lexical::format_instances::FORMAT_ASCII;
 use navm::output::Output;
-use std::fmt::Display;
+use serde_json::{Map, Value};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// CLI输出格式
+/// * 🎯让外部程序（如`jq`）无需从彩色文本中提取信息
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// 彩色文本：`[类型] 内容`
+    /// * 📜默认值
+    #[default]
+    Text,
+    /// JSON Lines：每条输出一行JSON
+    /// * 🚩NAVM输出⇒标准输出，复用[`Output::to_json_string`]并附加时间戳
+    /// * 🚩CLI日志⇒标准错误，附带`level`字段
+    ///   * 📌标准输出只剩NAVM输出，可直接交给其它程序逐行解析
+    Jsonl,
+}
+
+impl OutputFormat {
+    /// 格式名称
+    /// * 📄`text`、`jsonl`
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Jsonl => "jsonl",
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "jsonl" | "json" => Ok(Self::Jsonl),
+            _ => Err(messages::output_format_unsupported(s)),
+        }
+    }
+}
+
+/// 当前输出格式是否为JSON Lines
+static OUTPUT_JSONL: AtomicBool = AtomicBool::new(false);
+
+/// 获取当前输出格式
+pub fn current_output_format() -> OutputFormat {
+    match OUTPUT_JSONL.load(Ordering::Relaxed) {
+        true => OutputFormat::Jsonl,
+        false => OutputFormat::Text,
+    }
+}
+
+/// 设置当前输出格式
+pub fn set_output_format(format: OutputFormat) {
+    OUTPUT_JSONL.store(format == OutputFormat::Jsonl, Ordering::Relaxed);
+}
+
+/// 标准输出是否保留给命令的结果
+/// * 🎯如基准测试报告：重定向到文件时，不混入日志
+/// * 🚩保留⇒所有CLI输出（含NAVM输出）转至标准错误
+static STDOUT_RESERVED: AtomicBool = AtomicBool::new(false);
+
+/// 将标准输出保留给命令的结果
+/// * 🚩此后的CLI输出均转至标准错误，参见[`STDOUT_RESERVED`]
+pub fn reserve_stdout() {
+    STDOUT_RESERVED.store(true, Ordering::Relaxed);
+}
+
+/// 标准输出是否已保留给命令的结果
+fn stdout_reserved() -> bool {
+    STDOUT_RESERVED.load(Ordering::Relaxed)
+}
+
+/// 当前时间戳
+/// * 🚩自UNIX纪元起的毫秒数
+fn timestamp_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
 
 /// 统一的「CLI输出类型」
 #[derive(Debug, Clone, Copy)]
@@ -162,6 +253,28 @@ impl OutputType<'_> {
         *out_message += out.get_content().trim();
     }
 
+    /// 格式化CLI输出为一行JSON
+    /// * 🎯[`OutputFormat::Jsonl`]
+    /// * 📄`{"level":"LOG","message":"...","timestamp":1700000000000}`
+    pub fn format_json_line(&self, msg: &str) -> String {
+        let mut json = Map::new();
+        json.insert("level".into(), self.as_str().into());
+        json.insert("message".into(), msg.into());
+        json.insert("timestamp".into(), timestamp_millis().into());
+        Value::Object(json).to_string()
+    }
+
+    /// 格式化NAVM输出为一行JSON
+    /// * 🎯[`OutputFormat::Jsonl`]
+    /// * 🚩在[`Output::to_json_string`]的基础上附加时间戳
+    /// * 📄`{"content":"...","timestamp":1700000000000,"type":"ANSWER",...}`
+    pub fn format_navm_output_json(out: &Output) -> String {
+        let mut json =
+            serde_json::from_str::<Map<String, Value>>(&out.to_json_string()).unwrap_or_default();
+        json.insert("timestamp".into(), timestamp_millis().into());
+        Value::Object(json).to_string()
+    }
+
     /// 基于[`colored`]的输出美化
     /// * 🎯用于CLI的彩色输出
     /// * 🔗参考Julia版本<https://github.com/ARCJ137442/BabelNAR_Implements/blob/master/scripts/console.jl#L160>
@@ -194,38 +307,66 @@ impl OutputType<'_> {
 
     /// ✨格式化打印CLI输出
     /// * 🎯BabelNAR CLI
+    /// * 🚩JSON Lines模式⇒转至标准错误，避免混入NAVM输出
+    /// * 🚩标准输出已保留⇒转至标准错误
     #[inline]
     pub fn print_line(&self, message: &str) {
-        println!("{}", self.format_line(message));
+        if stdout_reserved() {
+            return self.eprint_line(message);
+        }
+        match current_output_format() {
+            OutputFormat::Text => println!("{}", self.format_line(message)),
+            OutputFormat::Jsonl => eprintln!("{}", self.format_json_line(message)),
+        }
     }
 
     /// ✨格式化打印NAVM输出
     /// * 🎯BabelNAR CLI
+    /// * 🚩标准输出已保留⇒转至标准错误
     #[inline]
     pub fn print_navm_output(out: &Output) {
-        println!("{}", Self::format_navm_output(out));
+        if stdout_reserved() {
+            return Self::eprint_navm_output(out);
+        }
+        match current_output_format() {
+            OutputFormat::Text => println!("{}", Self::format_navm_output(out)),
+            OutputFormat::Jsonl => println!("{}", Self::format_navm_output_json(out)),
+        }
     }
 
     /// ✨格式化打印NAVM输出（详细）
     /// * 🎯BabelNAR CLI
     /// * 🎯附带debug效果（检验「输出转译是否成功达到预期」）
+    /// * 🚩标准输出已保留⇒转至标准错误
     #[inline]
     pub fn print_navm_output_verbose(out: &Output) {
-        println!("{}", Self::format_from_navm_output_verbose(out));
+        if stdout_reserved() {
+            return Self::eprint_navm_output_verbose(out);
+        }
+        match current_output_format() {
+            OutputFormat::Text => println!("{}", Self::format_from_navm_output_verbose(out)),
+            OutputFormat::Jsonl => println!("{}", Self::format_navm_output_json(out)),
+        }
     }
 
     /// ✨格式化打印CLI输出（标准错误）
     /// * 🎯BabelNAR CLI
     #[inline]
     pub fn eprint_line(&self, message: &str) {
-        eprintln!("{}", self.format_line(message));
+        match current_output_format() {
+            OutputFormat::Text => eprintln!("{}", self.format_line(message)),
+            OutputFormat::Jsonl => eprintln!("{}", self.format_json_line(message)),
+        }
     }
 
     /// ✨格式化打印NAVM输出（标准错误）
     /// * 🎯BabelNAR CLI
     #[inline]
     pub fn eprint_navm_output(out: &Output) {
-        eprintln!("{}", Self::format_navm_output(out));
+        match current_output_format() {
+            OutputFormat::Text => eprintln!("{}", Self::format_navm_output(out)),
+            OutputFormat::Jsonl => eprintln!("{}", Self::format_navm_output_json(out)),
+        }
     }
 
     /// ✨格式化打印NAVM输出（标准错误）（详细）
@@ -233,7 +374,10 @@ impl OutputType<'_> {
     /// * 🎯附带debug效果（检验「输出转译是否成功达到预期」）
     #[inline]
     pub fn eprint_navm_output_verbose(out: &Output) {
-        eprintln!("{}", Self::format_from_navm_output_verbose(out));
+        match current_output_format() {
+            OutputFormat::Text => eprintln!("{}", Self::format_from_navm_output_verbose(out)),
+            OutputFormat::Jsonl => eprintln!("{}", Self::format_navm_output_json(out)),
+        }
     }
 }
 
@@ -292,3 +436,46 @@ impl<'a> From<&'a Output> for OutputType<'a> {
         OutputType::Vm(out.type_name())
     }
 }
+
+/// 单元测试
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nar_dev_utils::asserts;
+
+    /// 测试/输出格式的解析
+    #[test]
+    fn test_output_format() {
+        asserts! {
+            "text".parse::<OutputFormat>() => Ok(OutputFormat::Text)
+            "JSONL".parse::<OutputFormat>() => Ok(OutputFormat::Jsonl)
+            "xml".parse::<OutputFormat>() => Err(messages::output_format_unsupported("xml"))
+            OutputFormat::Jsonl.to_string() => "jsonl"
+        }
+    }
+
+    /// 测试/JSON Lines格式化
+    /// * 🎯每条输出单独一行，且可被重新解析
+    #[test]
+    fn test_format_json() {
+        let out = Output::ANSWER {
+            content_raw: "Answer: <A --> C>.\n".into(),
+            narsese: None,
+        };
+        let line = OutputType::format_navm_output_json(&out);
+        let json = serde_json::from_str::<Value>(&line).expect("JSON解析失败");
+        asserts! {
+            line.contains('\n') => false
+            json["type"] => "ANSWER"
+            json["content"] => "Answer: <A --> C>.\n"
+            json["timestamp"].is_u64() => true
+        }
+        let line = OutputType::Warn.format_json_line("警告");
+        let json = serde_json::from_str::<Value>(&line).expect("JSON解析失败");
+        asserts! {
+            json["level"] => "WARN"
+            json["message"] => "警告"
+            json["timestamp"].is_u64() => true
+        }
+    }
+}
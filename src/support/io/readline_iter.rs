@@ -11,6 +11,7 @@ use std::io::{stdin, stdout, Result as IoResult, Stdin, Write};
 /// * ❌无法在【不复制字符串】的情况下实现「迭代出所输入内容」的功能
 ///   * ❌【2024-04-02 03:49:56】无论如何都无法实现：迭代器物件中引入就必须碰生命周期
 /// * 🚩最终仍需复制字符串：调用处方便使用
+/// * 🚩读到EOF（如管道输入结束）⇒迭代结束
 /// * ❓是否需要支持提示词
 #[derive(Debug)]
 pub struct ReadlineIter {
@@ -45,15 +46,20 @@ impl Iterator for ReadlineIter {
     fn next(&mut self) -> Option<Self::Item> {
         // 清空缓冲区
         self.buffer.clear();
-        // 打印提示词
-        print!("{}", self.prompt);
-        if let Err(e) = stdout().flush() {
-            OutputType::Warn.print_line(&format!("无法冲洗输出: {e}"));
+        // 打印提示词 | 空提示词⇒不打印
+        if !self.prompt.is_empty() {
+            print!("{}", self.prompt);
+            if let Err(e) = stdout().flush() {
+                OutputType::Warn.print_line(&format!("无法冲洗输出: {e}"));
+            }
         }
         // 读取一行
         // * 📝`stdin()`是懒加载的，只会获取一次，随后返回的都是引用对象
-        if let Err(e) = self.stdin.read_line(&mut self.buffer) {
-            return Some(Err(e));
+        match self.stdin.read_line(&mut self.buffer) {
+            Err(e) => return Some(Err(e)),
+            // * 📝读到0字节即为EOF
+            Ok(0) => return None,
+            Ok(..) => {}
         }
         // 返回
         Some(IoResult::Ok(self.buffer.clone()))
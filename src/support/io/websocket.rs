@@ -75,6 +75,7 @@ mod tests {
 
     #[test]
     #[ignore = "【2024-09-12 18:02:52】涉及网络IO的不进行测试，会导致测试进程阻塞"]
+    #[allow(clippy::result_large_err)] // * 📌[`ws::Error`]的体积由[`ws`]决定
     fn main() {
         let t = spawn_on("127.0.0.1:3012", |sender| {
             println!("Websocket启动成功");
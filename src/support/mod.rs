@@ -13,3 +13,6 @@ pub mod io;
 
 // 错误处理增强
 pub mod error_handling_boost;
+
+// 国际化
+pub mod i18n;
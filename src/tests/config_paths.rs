@@ -16,6 +16,30 @@ nar_dev_utils::macro_once! {
     /// 用于「启动参数解析」的测试环境
     ARG_PARSE_TEST =
         "./src/tests/cli/config/_arg_parse_test.opennars.hjson"
+    /// 用于「启动参数解析」的测试环境（TOML版本）
+    ARG_PARSE_TEST_TOML =
+        "./src/tests/cli/config/_arg_parse_test.opennars.toml"
+    /// 用于「启动参数解析」的测试环境（YAML版本）
+    ARG_PARSE_TEST_YAML =
+        "./src/tests/cli/config/_arg_parse_test.opennars.yaml"
+    /// 用于「配置继承」的测试环境
+    EXTENDS_TEST =
+        "./src/tests/cli/config/_extends_test.hjson"
+    /// 用于「循环继承检测」的测试环境
+    EXTENDS_CYCLE =
+        "./src/tests/cli/config/_extends_cycle_a.hjson"
+    /// 用于「变量插值」的测试环境
+    INTERPOLATE_TEST =
+        "./src/tests/cli/config/_interpolate_test.hjson"
+    /// 用于「命名档案」的测试环境
+    PROFILES_TEST =
+        "./src/tests/cli/config/_profiles_test.hjson"
+    /// 用于「多个预置NAL来源」的测试环境
+    PRELUDE_LIST_TEST =
+        "./src/tests/cli/config/_prelude_list_test.hjson"
+    /// 用于「配置校验」的测试环境（含有意为之的问题）
+    VALIDATE_TEST =
+        "./src/tests/cli/config/_validate_test.hjson"
 
     /// OpenNARS
     OPENNARS = "./src/tests/cli/config/cin_opennars.hjson"
@@ -49,6 +73,9 @@ nar_dev_utils::macro_once! {
     /// NAL/真值通配
     NAL_TRUTH_WILDCARD = "./src/tests/cli/config/nal_truth_wildcard.hjson"
 
+    /// NAL测试文件所在目录
+    NAL_DIR = "./src/tests/nal"
+
     /// Websocket
     WEBSOCKET = "./src/tests/cli/config/websocket.hjson"
     /// Matriangle服务器
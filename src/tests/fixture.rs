@@ -0,0 +1,89 @@
+//! 测试用的临时目录
+//! * 🎯在各模块的单元测试间复用：构造测试用的目录树，并在测试结束后自动清理
+
+use crate::support::cin_search::impls_path_builder::file_name_matches;
+use std::{
+    env::temp_dir,
+    fs,
+    ops::Deref,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// 本进程已创建的临时目录数
+/// * 🎯区分同一进程中同名的临时目录
+static N_CREATED: AtomicUsize = AtomicUsize::new(0);
+
+/// 临时目录
+/// * 🚩目录名含「名称、进程ID、序号」：并行的测试、同时运行的测试进程互不冲突
+/// * 🚩离开作用域时自动删除：断言失败时也不会遗留
+/// * 📌可直接当作[`Path`]使用
+#[derive(Debug)]
+pub struct FixtureDir {
+    root: PathBuf,
+}
+
+impl FixtureDir {
+    /// 在系统临时目录中创建一个空目录
+    pub fn new(name: &str) -> Self {
+        let n = N_CREATED.fetch_add(1, Ordering::Relaxed);
+        let root = temp_dir().join(format!("babelnar_cli_{name}_{}_{n}", process::id()));
+        // * 🚩清除同名的遗留目录（如进程ID被复用）
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        Self { root }
+    }
+
+    /// 创建临时目录，并按「相对路径、内容」创建文件（及其所在目录）
+    pub fn with_files(name: &str, files: &[(&str, &str)]) -> Self {
+        let dir = Self::new(name);
+        for (file, content) in files {
+            dir.write(file, content);
+        }
+        dir
+    }
+
+    /// 创建临时目录，并按相对路径创建空文件（及其所在目录）
+    pub fn with_empty_files(name: &str, files: &[&str]) -> Self {
+        let dir = Self::new(name);
+        for file in files {
+            dir.write(file, "");
+        }
+        dir
+    }
+
+    /// 在临时目录中写入文件，返回其完整路径
+    /// * 🚩自动创建所在目录
+    pub fn write(&self, file: impl AsRef<Path>, content: &str) -> PathBuf {
+        let path = self.root.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        path
+    }
+}
+
+impl Deref for FixtureDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl AsRef<Path> for FixtureDir {
+    fn as_ref(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl Drop for FixtureDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+/// 深入条件：文件（夹）名匹配`nars`
+pub fn nars_criterion(path: &Path) -> bool {
+    file_name_matches(path, "nars")
+}
@@ -16,6 +16,10 @@ use std::env;
 pub mod config_paths;
 use config_paths::*;
 
+/// 测试用的临时目录
+/// * 🎯各模块的单元测试共用：目录名唯一，并自动清理
+pub mod fixture;
+
 /// 测试用宏/找不到路径即退出
 /// * 🚩输入一个`&str`，构建`&Path`并在其不存在时退出程序，或返回该`&Path`对象
 #[macro_export]
@@ -69,6 +73,7 @@ pub fn main_configs(cin_config_path: &str, other_config_paths: &[&str]) -> Resul
 }
 
 /// 批量生成「预引入NAL」
+/// * 💡如需一次性对比多个CIN的结果，亦可使用`matrix`子命令
 macro_rules! cin_tests {
 (
     $(#[$attr_root:meta])*